@@ -328,7 +328,7 @@ LANGUAGE_PROVIDER_MAP={"en-US": "groq", "ne-NP": "gemini", "hi-IN": "gemini"}
 # ============================================
 # Server Settings
 # ============================================
-SERVER_HOST=0.0.0.0
+SERVER_HOST=127.0.0.1
 PORT=8000
 LOG_LEVEL=info
 RELOAD=True
@@ -347,6 +347,8 @@ AUTO_SAVE_CONVERSATIONS=True
     
     $envContent | Out-File -FilePath ".env" -Encoding UTF8 -Force
     Write-Host "✅ .env file created successfully!" -ForegroundColor Green
+    Write-Host "   🔐 The FRIDAY desktop app moves the API keys into its encrypted" -ForegroundColor White
+    Write-Host "      vault on first launch and removes them from .env" -ForegroundColor White
 }
 Write-Host ""
 
@@ -18,11 +18,13 @@ router = APIRouter()
 class TimerRequest(BaseModel):
     duration: int  # seconds
     name: Optional[str] = "Timer"
+    conversation_id: Optional[str] = None
 
 
 class AlarmRequest(BaseModel):
     time: str  # e.g., "7:30 AM"
     name: Optional[str] = "Alarm"
+    conversation_id: Optional[str] = None
 
 
 class TodoRequest(BaseModel):
@@ -56,7 +58,7 @@ class QuickQueryRequest(BaseModel):
 async def set_timer(request: TimerRequest):
     """Set a timer"""
     try:
-        result = alexa_features.set_timer(request.duration, request.name)
+        result = alexa_features.set_timer(request.duration, request.name, request.conversation_id)
         return result
     except Exception as e:
         raise HTTPException(status_code=500, detail=str(e))
@@ -86,7 +88,7 @@ async def get_timers():
 async def set_alarm(request: AlarmRequest):
     """Set an alarm"""
     try:
-        result = alexa_features.set_alarm(request.time, request.name)
+        result = alexa_features.set_alarm(request.time, request.name, request.conversation_id)
         return result
     except Exception as e:
         raise HTTPException(status_code=500, detail=str(e))
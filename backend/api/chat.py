@@ -2,16 +2,19 @@
 Chat API endpoints with streaming support
 """
 import json
+import uuid
 from typing import AsyncGenerator
 from fastapi import APIRouter, HTTPException
 from fastapi.responses import StreamingResponse
 from loguru import logger
 
-from models.schemas import ChatRequest, ChatResponse, StreamChunk
+from models.schemas import ChatRequest, ChatResponse, Conversation, StreamChunk
 from core.llm_engine import llm_engine
 from core.memory import memory_manager
 from core.learning_system import learning_system
 from core.tool_manager import tool_manager
+from core.tool_approval import tool_approvals
+from core.tool_journal import tool_journal
 from core.model_router import model_router
 from core.context_tracker import context_tracker
 from core.context_resolver import context_resolver
@@ -155,7 +158,12 @@ async def stream_generator(request: ChatRequest) -> AsyncGenerator[str, None]:
                 try:
                     result = await tool_manager.execute_tool(
                         tool_name=tool_spec['tool'],
-                        arguments={**tool_spec['arguments'], 'operation': tool_spec['operation']}
+                        arguments={**tool_spec['arguments'], 'operation': tool_spec['operation']},
+                        context={
+                            "source": "auto",
+                            "conversation_id": conversation.id,
+                            "project_id": request.project_id
+                        }
                     )
                     tool_results.append({
                         'tool': tool_spec['tool'],
@@ -245,24 +253,58 @@ async def stream_generator(request: ChatRequest) -> AsyncGenerator[str, None]:
             elif chunk_type == "tool_call":
                 # Handle tool call
                 tool_data = chunk.get("data", {})
+                if tool_approvals.enabled:
+                    tool_data = {**tool_data, "call_id": str(uuid.uuid4()), "approval": "pending"}
                 tool_calls.append(tool_data)
                 
                 # Send tool call notification
                 stream_chunk = StreamChunk(type="tool_call", data=tool_data)
                 yield f"data: {stream_chunk.model_dump_json()}\n\n"
                 
-                # Execute tool
-                logger.info(f"Executing tool: {tool_data.get('name')}")
-                tool_result = await tool_manager.execute_tool(
-                    tool_name=tool_data.get("name"),
-                    arguments=tool_data.get("arguments", {})
+                # Wait for the desktop app to approve or deny it
+                approval = await tool_approvals.request(
+                    tool=tool_data.get("name"),
+                    arguments=tool_data.get("arguments", {}),
+                    project_id=request.project_id,
+                    conversation_id=conversation.id,
+                    call_id=tool_data.get("call_id")
                 )
                 
+                # Execute tool
+                tool_context = {
+                    "source": "model",
+                    "call_id": tool_data.get("call_id"),
+                    "conversation_id": conversation.id,
+                    "project_id": request.project_id,
+                    "approval": approval
+                }
+                if approval["approved"]:
+                    logger.info(f"Executing tool: {tool_data.get('name')}")
+                    tool_result = await tool_manager.execute_tool(
+                        tool_name=tool_data.get("name"),
+                        arguments=tool_data.get("arguments", {}),
+                        context=tool_context
+                    )
+                else:
+                    logger.warning(f"Tool {tool_data.get('name')} denied: {approval['reason']}")
+                    tool_result = {
+                        "success": False,
+                        "error": f"Denied: {approval['reason']}" if approval["reason"] else "Denied"
+                    }
+                    tool_journal.record(
+                        tool_data.get("name"),
+                        tool_data.get("arguments", {}),
+                        tool_result,
+                        tool_context
+                    )
+                
                 # Send tool result
                 stream_chunk = StreamChunk(
                     type="tool_result",
                     data={
                         "tool": tool_data.get("name"),
+                        "call_id": tool_data.get("call_id"),
+                        "approved": approval["approved"],
                         "result": tool_result
                     }
                 )
@@ -482,6 +524,14 @@ async def get_conversation(conversation_id: str):
     return conversation
 
 
+@router.put("/conversations/{conversation_id}")
+async def adopt_conversation(conversation_id: str, conversation: Conversation):
+    """Replace the conversation with the desktop store's copy"""
+    if conversation.id != conversation_id:
+        raise HTTPException(status_code=400, detail="Conversation id does not match the path")
+    return await memory_manager.adopt_conversation(conversation)
+
+
 @router.delete("/conversations/{conversation_id}")
 async def delete_conversation(conversation_id: str):
     """Delete conversation"""
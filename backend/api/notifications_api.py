@@ -0,0 +1,101 @@
+"""
+NOTIFICATIONS API
+Due reminders, finished timers and ringing alarms for the desktop shell,
+which raises them as native OS notifications and reports the user's choice back.
+"""
+
+from fastapi import APIRouter, HTTPException
+from pydantic import BaseModel
+from typing import Optional, List, Dict, Literal
+from loguru import logger
+
+from core.tool_manager import tool_manager
+from tools.alexa_features import alexa_features
+
+router = APIRouter(prefix="/api/notifications", tags=["Notifications"])
+
+Kind = Literal["reminder", "timer", "alarm"]
+
+
+class DueEvent(BaseModel):
+    kind: Kind
+    id: str
+    title: str
+    body: str = ""
+    conversation_id: Optional[str] = None
+
+
+class SnoozeRequest(BaseModel):
+    kind: Kind
+    id: str
+    title: str = ""
+    minutes: int = 10
+    conversation_id: Optional[str] = None
+
+
+class DismissRequest(BaseModel):
+    kind: Kind
+    id: str
+
+
+def _reminders():
+    if not tool_manager.initialized:
+        tool_manager.initialize()
+    return tool_manager.tools.get("reminders")
+
+
+def _reminder_event(reminder: Dict) -> DueEvent:
+    return DueEvent(
+        kind="reminder",
+        id=str(reminder["id"]),
+        title=reminder["title"],
+        body=reminder.get("description") or "Reminder due",
+        conversation_id=reminder.get("conversation_id")
+    )
+
+
+@router.get("/due", response_model=List[DueEvent])
+async def get_due():
+    """
+    Everything that became due since the last call. Each event is returned once;
+    snoozing re-arms it.
+    """
+    events = [DueEvent(**event) for event in alexa_features.pop_due_events()]
+
+    reminders = _reminders()
+    if reminders:
+        events.extend(_reminder_event(r) for r in reminders.pop_due_notifications())
+
+    return events
+
+
+@router.post("/snooze")
+async def snooze(request: SnoozeRequest):
+    """Fire the notification again in `minutes`"""
+    if request.minutes <= 0:
+        raise HTTPException(status_code=400, detail="minutes must be positive")
+
+    if request.kind == "reminder":
+        reminders = _reminders()
+        if not reminders:
+            raise HTTPException(status_code=503, detail="Reminders tool unavailable")
+        result = await reminders.snooze_reminder(int(request.id), request.minutes)
+        if not result["success"]:
+            raise HTTPException(status_code=404, detail=result["error"])
+        return result
+
+    # Finished timers are gone and alarms repeat daily, so snooze as a one-off timer
+    name = request.title or request.kind.capitalize()
+    return alexa_features.snooze(name, request.minutes, request.conversation_id)
+
+
+@router.post("/dismiss")
+async def dismiss(request: DismissRequest):
+    """Acknowledge a notification; dismissing a reminder completes it"""
+    if request.kind == "reminder":
+        reminders = _reminders()
+        if reminders:
+            return await reminders.mark_complete(int(request.id))
+
+    logger.debug(f"Notification dismissed: {request.kind} {request.id}")
+    return {"success": True}
@@ -0,0 +1,60 @@
+"""
+SETTINGS API
+Lets the desktop shell apply setting changes without a restart. Only settings
+read at call time can change live; the rest are reported back and the shell
+restarts the backend with the new environment.
+"""
+
+from fastapi import APIRouter, Body
+from pydantic import BaseModel, TypeAdapter, ValidationError
+from typing import Any, Dict, List
+from loguru import logger
+import json
+
+from config import settings, Settings
+from core.llm_engine import llm_engine
+
+router = APIRouter(prefix="/api/settings", tags=["Settings"])
+
+# Read from `settings` on every use (or refreshed below), so assigning is enough
+HOT_SETTINGS = {
+    "default_model",
+    "language_provider_map",
+    "code_execution_timeout",
+    "web_search_provider",
+    "max_conversation_history",
+    "debug_mode",
+    "auto_save_conversations",
+}
+
+
+class ApplyResult(BaseModel):
+    applied: List[str]
+    restart_required: List[str]
+
+
+@router.post("/apply", response_model=ApplyResult)
+async def apply_settings(values: Dict[str, Any] = Body(...)):
+    """Apply what can change live; everything else is listed in `restart_required`"""
+    applied, restart_required = [], []
+
+    for name, value in values.items():
+        field = Settings.model_fields.get(name)
+        if name not in HOT_SETTINGS or field is None:
+            restart_required.append(name)
+            continue
+        try:
+            value = TypeAdapter(field.annotation).validate_python(value)
+            if name == "language_provider_map":
+                llm_engine.language_provider_map = json.loads(value)
+        except (ValidationError, ValueError) as e:
+            logger.warning(f"Rejected live update of {name}: {e}")
+            restart_required.append(name)
+            continue
+
+        setattr(settings, name, value)
+        applied.append(name)
+
+    if applied:
+        logger.info(f"Settings applied live: {', '.join(applied)}")
+    return ApplyResult(applied=applied, restart_required=restart_required)
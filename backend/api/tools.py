@@ -7,6 +7,8 @@ from typing import Dict, Any, Optional
 from loguru import logger
 
 from core.tool_manager import tool_manager
+from core.tool_approval import tool_approvals
+from core.tool_journal import tool_journal
 
 router = APIRouter(prefix="/api/tools", tags=["tools"])
 
@@ -17,6 +19,12 @@ class ToolExecutionRequest(BaseModel):
     arguments: Dict[str, Any] = Field(default_factory=dict, description="Tool arguments")
 
 
+class ApprovalDecision(BaseModel):
+    approved: bool
+    reason: str = ""
+    decided_by: Optional[str] = None
+
+
 class ToolExecutionResponse(BaseModel):
     success: bool
     tool_name: str
@@ -42,7 +50,7 @@ async def execute_tool(request: ToolExecutionRequest):
             args["operation"] = request.operation
         
         # Execute tool
-        result = await tool_manager.execute_tool(request.tool_name, args)
+        result = await tool_manager.execute_tool(request.tool_name, args, context={"source": "api"})
         
         if result.get("success", False):
             logger.success(f"✓ Tool {request.tool_name} executed successfully")
@@ -64,6 +72,35 @@ async def execute_tool(request: ToolExecutionRequest):
         raise HTTPException(status_code=500, detail=str(e))
 
 
+@router.get("/approvals/pending")
+async def pending_approvals(wait: float = 0):
+    """
+    Tool calls waiting for approval; with `wait`, long-poll up to that many
+    seconds for one to arrive
+    """
+    return await tool_approvals.wait_pending(min(max(wait, 0), 60))
+
+
+@router.post("/approvals/{call_id}")
+async def decide_approval(call_id: str, decision: ApprovalDecision):
+    """
+    Approve or deny a pending tool call
+    """
+    if not tool_approvals.decide(call_id, decision.approved, decision.reason, decision.decided_by):
+        raise HTTPException(status_code=404, detail=f"No pending approval {call_id}")
+    return {"success": True, "call_id": call_id, "approved": decision.approved}
+
+
+@router.get("/executions")
+async def tool_executions(after: int = 0, wait: float = 0):
+    """
+    Tool executions with a sequence number above `after`, for the desktop
+    app's audit log; with `wait`, long-poll up to that many seconds
+    """
+    executions = await tool_journal.wait_since(after, min(max(wait, 0), 60))
+    return {"boot_id": tool_journal.boot_id, "executions": executions}
+
+
 @router.get("/list")
 async def list_tools():
     """
@@ -8,6 +8,8 @@ from pydantic import BaseModel
 from typing import Optional, Dict, Any, List
 from loguru import logger
 
+from core import tool_context
+
 router = APIRouter(prefix="/api/voice", tags=["voice_commands"])
 
 
@@ -37,7 +39,9 @@ async def execute_voice_command(request: VoiceCommandRequest):
     try:
         from tools.voice_command_executor import tool_instance
         
-        result = await tool_instance.execute(command=request.command)
+        # `context` may carry the conversation a timer set here belongs to
+        with tool_context.use(request.context):
+            result = await tool_instance.execute(command=request.command)
         
         return {"success": True, **result}
         
@@ -3,6 +3,7 @@ JARVIS Configuration Module
 Handles all configuration settings with environment variables
 """
 import os
+import secrets
 from pathlib import Path
 from typing import List, Dict, Any
 from pydantic_settings import BaseSettings
@@ -75,7 +76,7 @@ class Settings(BaseSettings):
     # ============================================
     # Server Settings
     # ============================================
-    server_host: str = Field(default="0.0.0.0", env="SERVER_HOST")
+    server_host: str = Field(default="127.0.0.1", env="SERVER_HOST")  # Loopback only unless deliberately exposed
     server_port: int = Field(default=8000, env="PORT")  # Use PORT for Railway/Render compatibility
     reload: bool = Field(default=True, env="RELOAD")
     log_level: str = Field(default="info", env="LOG_LEVEL")
@@ -83,8 +84,9 @@ class Settings(BaseSettings):
     # ============================================
     # Security
     # ============================================
-    secret_key: str = Field(default="change-this-secret-key", env="SECRET_KEY")
+    secret_key: str = Field(default_factory=lambda: secrets.token_urlsafe(32), env="SECRET_KEY")
     cors_origins: str = Field(default='["http://localhost:1420", "http://localhost:3000"]', env="CORS_ORIGINS")
+    # The desktop app sets both, with a fresh token for every launch
     api_key_required: bool = Field(default=False, env="API_KEY_REQUIRED")
     api_key: str = Field(default="", env="API_KEY")
     
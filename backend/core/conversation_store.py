@@ -2,12 +2,13 @@
 Simple Conversation Store - Single Source of Truth
 Replaces all the complex memory systems
 """
-import json
 from pathlib import Path
 from typing import Dict, List, Optional
 from datetime import datetime
 from loguru import logger
 
+from core.secure_storage import read_json, write_json
+
 
 class ConversationStore:
     """
@@ -27,8 +28,7 @@ class ConversationStore:
             count = 0
             for file_path in self.data_dir.glob("*.json"):
                 try:
-                    with open(file_path, 'r', encoding='utf-8') as f:
-                        data = json.load(f)
+                    data = read_json(file_path)
                     self.conversations[data['id']] = data['messages']
                     count += 1
                 except Exception as e:
@@ -45,12 +45,11 @@ class ConversationStore:
         
         try:
             file_path = self.data_dir / f"{conversation_id}.json"
-            with open(file_path, 'w', encoding='utf-8') as f:
-                json.dump({
-                    'id': conversation_id,
-                    'messages': self.conversations[conversation_id],
-                    'updated_at': datetime.now().isoformat()
-                }, f, indent=2, ensure_ascii=False)
+            write_json(file_path, {
+                'id': conversation_id,
+                'messages': self.conversations[conversation_id],
+                'updated_at': datetime.now().isoformat()
+            }, indent=2, ensure_ascii=False)
         except Exception as e:
             logger.error(f"Save failed: {e}")
     
@@ -2,13 +2,14 @@
 Self-Learning System
 Learn from user interactions, adapt behavior, remember preferences
 """
-import json
 from datetime import datetime
 from pathlib import Path
 from typing import Dict, Any, List, Optional
 from collections import defaultdict, Counter
 from loguru import logger
 
+from core.secure_storage import read_json, write_json
+
 
 class LearningSystem:
     """Self-learning system that adapts to user behavior"""
@@ -30,17 +31,9 @@ class LearningSystem:
     def load_data(self):
         """Load learning data from disk"""
         try:
-            if self.preferences_file.exists():
-                with open(self.preferences_file, 'r', encoding='utf-8') as f:
-                    self.user_preferences = json.load(f)
-            
-            if self.commands_file.exists():
-                with open(self.commands_file, 'r', encoding='utf-8') as f:
-                    self.command_patterns = json.load(f)
-            
-            if self.feedback_file.exists():
-                with open(self.feedback_file, 'r', encoding='utf-8') as f:
-                    self.feedback_log = json.load(f)
+            self.user_preferences = read_json(self.preferences_file, {})
+            self.command_patterns = read_json(self.commands_file, {})
+            self.feedback_log = read_json(self.feedback_file, [])
             
             logger.info(f"Learning system loaded: {len(self.user_preferences)} preferences")
         except Exception as e:
@@ -49,14 +42,9 @@ class LearningSystem:
     def save_data(self):
         """Save learning data to disk"""
         try:
-            with open(self.preferences_file, 'w', encoding='utf-8') as f:
-                json.dump(self.user_preferences, f, indent=2)
-            
-            with open(self.commands_file, 'w', encoding='utf-8') as f:
-                json.dump(self.command_patterns, f, indent=2)
-            
-            with open(self.feedback_file, 'w', encoding='utf-8') as f:
-                json.dump(self.feedback_log[-1000:], f, indent=2)  # Keep last 1000
+            write_json(self.preferences_file, self.user_preferences, indent=2)
+            write_json(self.commands_file, self.command_patterns, indent=2)
+            write_json(self.feedback_file, self.feedback_log[-1000:], indent=2)  # Keep last 1000
             
             logger.debug("Learning data saved")
         except Exception as e:
@@ -2,7 +2,6 @@
 Memory Manager - Conversation history and vector store
 Supports persistent context and semantic search
 """
-import json
 import uuid
 from pathlib import Path
 from typing import List, Dict, Any, Optional
@@ -11,6 +10,7 @@ from loguru import logger
 
 from models.schemas import Conversation, Message, Project
 from config import settings
+from core.secure_storage import read_json, vector_client, write_json
 
 # Import Firestore storage if available
 try:
@@ -49,13 +49,10 @@ class MemoryManager:
         # Initialize vector store if enabled
         if settings.enable_vector_memory:
             try:
-                import chromadb
                 from sentence_transformers import SentenceTransformer
                 
-                # Initialize ChromaDB
-                self.vector_store = chromadb.PersistentClient(
-                    path=settings.vector_db_path
-                )
+                # Initialize ChromaDB (in memory only when data is encrypted)
+                self.vector_store = vector_client(settings.vector_db_path)
                 
                 # Get or create collection
                 self.collection = self.vector_store.get_or_create_collection(
@@ -80,10 +77,9 @@ class MemoryManager:
         if conv_dir.exists():
             for file_path in conv_dir.glob("*.json"):
                 try:
-                    with open(file_path, 'r', encoding='utf-8') as f:
-                        data = json.load(f)
-                        conversation = Conversation(**data)
-                        self.conversations[conversation.id] = conversation
+                    data = read_json(file_path)
+                    conversation = Conversation(**data)
+                    self.conversations[conversation.id] = conversation
                 except Exception as e:
                     logger.warning(f"Failed to load conversation {file_path}: {e}")
         
@@ -92,10 +88,9 @@ class MemoryManager:
         if proj_dir.exists():
             for file_path in proj_dir.glob("*.json"):
                 try:
-                    with open(file_path, 'r', encoding='utf-8') as f:
-                        data = json.load(f)
-                        project = Project(**data)
-                        self.projects[project.id] = project
+                    data = read_json(file_path)
+                    project = Project(**data)
+                    self.projects[project.id] = project
                 except Exception as e:
                     logger.warning(f"Failed to load project {file_path}: {e}")
         
@@ -112,8 +107,7 @@ class MemoryManager:
         conv_dir.mkdir(parents=True, exist_ok=True)
         
         file_path = conv_dir / f"{conversation.id}.json"
-        with open(file_path, 'w', encoding='utf-8') as f:
-            json.dump(conversation.model_dump(), f, indent=2, default=str)
+        write_json(file_path, conversation.model_dump(), indent=2, default=str)
     
     async def _save_project(self, project: Project):
         """Save project to storage (Firestore or local disk)"""
@@ -126,8 +120,7 @@ class MemoryManager:
         proj_dir.mkdir(parents=True, exist_ok=True)
         
         file_path = proj_dir / f"{project.id}.json"
-        with open(file_path, 'w', encoding='utf-8') as f:
-            json.dump(project.model_dump(), f, indent=2, default=str)
+        write_json(file_path, project.model_dump(), indent=2, default=str)
     
     async def get_or_create_conversation(
         self,
@@ -337,6 +330,21 @@ class MemoryManager:
         logger.info(f"Renamed conversation {conversation_id} to: {name}")
         return True
     
+    async def adopt_conversation(self, conversation: Conversation) -> Conversation:
+        """Take over a full conversation from the desktop store (e.g. an import),
+        replacing any copy held here so new messages continue its history"""
+        self.conversations[conversation.id] = conversation
+        await self._save_conversation(conversation)
+        
+        # Link to project like get_or_create_conversation does
+        project = self.projects.get(conversation.project_id) if conversation.project_id else None
+        if project and conversation.id not in project.conversation_ids:
+            project.conversation_ids.append(conversation.id)
+            await self._save_project(project)
+        
+        logger.info(f"Adopted conversation {conversation.id} ({len(conversation.messages)} messages)")
+        return conversation
+    
     async def generate_conversation_name(self, conversation_id: str) -> Optional[str]:
         """
         Auto-generate smart conversation name from actual content
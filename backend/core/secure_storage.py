@@ -0,0 +1,86 @@
+"""
+Secure Storage
+Reads and writes JSON data files encrypted with the key the desktop shell
+passes in FRIDAY_DATA_KEY (hex, AES-256-GCM). Without the variable - running
+the backend on its own - files are written as plaintext JSON as before.
+
+Encrypted layout: MAGIC | 12-byte nonce | ciphertext + tag. Must stay in step
+with frontend/tauri-app/src-tauri/src/encryption.rs.
+
+Chroma cannot encrypt its files, so with a data key vector stores are kept in
+memory only and any plaintext store left on disk is removed.
+"""
+import json
+import os
+import shutil
+from pathlib import Path
+from typing import Any
+
+from loguru import logger
+
+MAGIC = b"FRIDAYENC1"
+NONCE_SIZE = 12
+
+
+def _load_key():
+    # Pop it so tools and subprocesses started by the backend never inherit it
+    value = os.environ.pop("FRIDAY_DATA_KEY", None)
+    if not value:
+        return None
+    try:
+        from cryptography.hazmat.primitives.ciphers.aead import AESGCM
+        return AESGCM(bytes.fromhex(value))
+    except Exception as e:
+        logger.error(f"Invalid FRIDAY_DATA_KEY, data will not be readable: {e}")
+        return None
+
+
+_cipher = _load_key()
+
+
+def encryption_enabled() -> bool:
+    return _cipher is not None
+
+
+def read_json(path: Path, default: Any = None) -> Any:
+    """Load a JSON file, decrypting it if it was written encrypted"""
+    path = Path(path)
+    if not path.exists():
+        return default
+
+    raw = path.read_bytes()
+    if raw.startswith(MAGIC):
+        if _cipher is None:
+            raise ValueError(f"{path.name} is encrypted and no data key was provided")
+        nonce = raw[len(MAGIC):len(MAGIC) + NONCE_SIZE]
+        raw = _cipher.decrypt(nonce, raw[len(MAGIC) + NONCE_SIZE:], None)
+    return json.loads(raw.decode("utf-8"))
+
+
+def write_json(path: Path, data: Any, **dump_kwargs) -> None:
+    """Write a JSON file, encrypted when a data key is set. Replaces atomically."""
+    path = Path(path)
+    raw = json.dumps(data, **dump_kwargs).encode("utf-8")
+    if _cipher is not None:
+        nonce = os.urandom(NONCE_SIZE)
+        raw = MAGIC + nonce + _cipher.encrypt(nonce, raw, None)
+
+    tmp_path = path.with_name(path.name + ".tmp")
+    tmp_path.write_bytes(raw)
+    os.replace(tmp_path, path)
+
+
+def vector_client(path: Path):
+    """Chroma client for `path`; in memory only while encryption is on"""
+    import chromadb
+
+    if _cipher is None:
+        return chromadb.PersistentClient(path=str(path))
+
+    path = Path(path)
+    if path.exists():
+        # Written before encryption was turned on; the messages in it are
+        # already in the encrypted conversation files
+        shutil.rmtree(path, ignore_errors=True)
+        logger.info(f"Removed plaintext vector store {path}")
+    return chromadb.EphemeralClient()
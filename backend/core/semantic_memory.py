@@ -41,12 +41,10 @@ class SemanticMemory:
             
             # Initialize ChromaDB for vector storage (NEW CLIENT)
             try:
-                import chromadb
+                from core.secure_storage import vector_client
                 
-                # Use new persistent client method
-                self.chroma_client = chromadb.PersistentClient(
-                    path=str(self.data_dir)
-                )
+                # Persistent client, or in memory only when data is encrypted
+                self.chroma_client = vector_client(self.data_dir)
                 
                 # Get or create collection
                 self.collection = self.chroma_client.get_or_create_collection(
@@ -0,0 +1,96 @@
+"""
+Tool Approval - Pending approvals for model-requested tool calls
+When FRIDAY runs under the desktop app (FRIDAY_TOOL_APPROVAL=1), every tool
+the model asks for waits here until the app applies the user's policy or
+asks them, then posts the decision back.
+"""
+import asyncio
+import os
+import time
+import uuid
+from typing import Dict, Any, List, Optional
+from loguru import logger
+
+APPROVAL_ENV = "FRIDAY_TOOL_APPROVAL"
+# Unanswered requests are denied after this long
+APPROVAL_TIMEOUT = 120
+
+
+class ToolApprovals:
+    """Registry of tool calls waiting for a decision"""
+
+    def __init__(self):
+        self.pending: Dict[str, Dict[str, Any]] = {}
+        self._decisions: Dict[str, asyncio.Future] = {}
+        self._changed = asyncio.Event()
+
+    @property
+    def enabled(self) -> bool:
+        return os.environ.get(APPROVAL_ENV) == "1"
+
+    async def request(
+        self,
+        tool: str,
+        arguments: Dict[str, Any],
+        project_id: Optional[str] = None,
+        conversation_id: Optional[str] = None,
+        call_id: Optional[str] = None
+    ) -> Dict[str, Any]:
+        """Wait for a decision; returns {"approved", "reason", "decided_by"}"""
+        if not self.enabled:
+            return {"approved": True, "reason": "approval not required", "decided_by": None}
+
+        call_id = call_id or str(uuid.uuid4())
+        future = asyncio.get_running_loop().create_future()
+        self._decisions[call_id] = future
+        self.pending[call_id] = {
+            "call_id": call_id,
+            "tool": tool,
+            "arguments": arguments,
+            "project_id": project_id,
+            "conversation_id": conversation_id,
+            "requested_at": time.time()
+        }
+        self._changed.set()
+        logger.info(f"⏳ Tool {tool} waiting for approval ({call_id})")
+
+        try:
+            return await asyncio.wait_for(future, timeout=APPROVAL_TIMEOUT)
+        except asyncio.TimeoutError:
+            logger.warning(f"Tool {tool} was not approved in time ({call_id})")
+            return {
+                "approved": False,
+                "reason": "no decision before the approval timeout",
+                "decided_by": "timeout"
+            }
+        finally:
+            self.pending.pop(call_id, None)
+            self._decisions.pop(call_id, None)
+
+    async def wait_pending(self, timeout: float) -> List[Dict[str, Any]]:
+        """Long-poll: return pending requests as soon as there are any"""
+        if not self.pending:
+            self._changed.clear()
+            try:
+                await asyncio.wait_for(self._changed.wait(), timeout=timeout)
+            except asyncio.TimeoutError:
+                pass
+        return list(self.pending.values())
+
+    def decide(
+        self,
+        call_id: str,
+        approved: bool,
+        reason: str = "",
+        decided_by: Optional[str] = None
+    ) -> bool:
+        """Resolve a pending request; False if it is unknown or already decided"""
+        future = self._decisions.get(call_id)
+        if future is None or future.done():
+            return False
+        future.set_result({"approved": approved, "reason": reason, "decided_by": decided_by})
+        return True
+
+
+# Global instance
+tool_approvals = ToolApprovals()
@@ -0,0 +1,26 @@
+"""
+Tool Context - Who asked for the tool call being executed
+Set by the tool manager (and the voice command API) around each call, so
+tools can record where a reminder or timer came from without every caller
+passing it through.
+"""
+from contextlib import contextmanager
+from contextvars import ContextVar
+from typing import Dict, Any, Optional
+
+_current: ContextVar[Optional[Dict[str, Any]]] = ContextVar("tool_context", default=None)
+
+
+@contextmanager
+def use(context: Optional[Dict[str, Any]]):
+    """Make `context` current for the duration of the block"""
+    token = _current.set(context or {})
+    try:
+        yield
+    finally:
+        _current.reset(token)
+
+
+def conversation_id() -> Optional[str]:
+    """Conversation the current tool call was made from, if any"""
+    return (_current.get() or {}).get("conversation_id")
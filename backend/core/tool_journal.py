@@ -0,0 +1,65 @@
+"""
+Tool Journal - Recent tool executions for the desktop app's audit log
+Every tool run (or denied) is kept here with its arguments, approval and
+result until the app picks it up from /api/tools/executions and appends it
+to its hash-chained audit log.
+"""
+import asyncio
+import time
+import uuid
+from collections import deque
+from typing import Dict, Any, List, Optional
+
+# Executions kept for a slow or restarting reader
+MAX_ENTRIES = 1000
+
+
+class ToolJournal:
+    """In-memory, sequence-numbered record of tool executions"""
+
+    def __init__(self):
+        # Changes on every backend start so readers know sequence numbers reset
+        self.boot_id = str(uuid.uuid4())
+        self.entries: deque = deque(maxlen=MAX_ENTRIES)
+        self._seq = 0
+        self._changed = asyncio.Event()
+
+    def record(
+        self,
+        tool: str,
+        arguments: Dict[str, Any],
+        result: Dict[str, Any],
+        context: Optional[Dict[str, Any]] = None
+    ) -> None:
+        context = context or {}
+        self._seq += 1
+        self.entries.append({
+            "seq": self._seq,
+            "tool": tool,
+            "arguments": arguments,
+            "source": context.get("source"),
+            "call_id": context.get("call_id"),
+            "conversation_id": context.get("conversation_id"),
+            "project_id": context.get("project_id"),
+            "approval": context.get("approval"),
+            "success": bool(result.get("success")),
+            "result": result.get("result"),
+            "error": result.get("error"),
+            "execution_time": result.get("execution_time"),
+            "finished_at": time.time()
+        })
+        self._changed.set()
+
+    async def wait_since(self, after: int, timeout: float) -> List[Dict[str, Any]]:
+        """Long-poll: entries with seq > after, waiting up to timeout for one"""
+        if self._seq <= after:
+            self._changed.clear()
+            try:
+                await asyncio.wait_for(self._changed.wait(), timeout=timeout)
+            except asyncio.TimeoutError:
+                pass
+        return [entry for entry in self.entries if entry["seq"] > after]
+
+
+# Global instance
+tool_journal = ToolJournal()
@@ -3,10 +3,13 @@ Tool Manager - Central tool execution system
 Manages and executes all available tools
 """
 import sys
+import time
 from typing import Dict, Any, List, Optional
 from loguru import logger
 
 from config import settings, TOOLS_CONFIG
+from core import tool_context
+from core.tool_journal import tool_journal
 
 
 class BaseTool:
@@ -98,11 +101,28 @@ class ToolManager:
         logger.debug(f"Registered tool: {tool.name}")
     
     async def execute_tool(
+        self,
+        tool_name: str,
+        arguments: Dict[str, Any],
+        context: Optional[Dict[str, Any]] = None
+    ) -> Dict[str, Any]:
+        """
+        Execute a tool by name and record it in the tool journal.
+        `context` says who asked (source, call_id, conversation_id,
+        project_id, approval) for the audit log.
+        """
+        started = time.perf_counter()
+        with tool_context.use(context):
+            result = await self._execute_tool(tool_name, arguments)
+        result["execution_time"] = round(time.perf_counter() - started, 4)
+        tool_journal.record(tool_name, arguments, result, context)
+        return result
+    
+    async def _execute_tool(
         self,
         tool_name: str,
         arguments: Dict[str, Any]
     ) -> Dict[str, Any]:
-        """Execute a tool by name"""
         if not self.initialized:
             self.initialize()
         
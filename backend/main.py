@@ -5,6 +5,7 @@ FastAPI backend server with streaming support
 """
 import sys
 import asyncio
+import secrets
 from pathlib import Path
 
 # Add backend to path
@@ -49,6 +50,25 @@ app.add_middleware(
 )
 
 
+# ============================================
+# Access Token
+# ============================================
+@app.middleware("http")
+async def require_api_key(request: Request, call_next):
+    """Reject requests without `Authorization: Bearer <API_KEY>` when API_KEY_REQUIRED is set"""
+    # CORS preflights never carry credentials
+    if settings.api_key_required and request.method != "OPTIONS":
+        scheme, _, token = request.headers.get("authorization", "").partition(" ")
+        valid = (
+            bool(settings.api_key)
+            and scheme.lower() == "bearer"
+            and secrets.compare_digest(token.encode(), settings.api_key.encode())
+        )
+        if not valid:
+            return JSONResponse(status_code=401, content={"detail": "Missing or invalid access token"})
+    return await call_next(request)
+
+
 # ============================================
 # Exception Handlers
 # ============================================
@@ -125,6 +145,7 @@ async def startup_event():
     logger.info("Running system health check...")
     from core.health_check import print_health_report
     health = print_health_report()
+    app.state.system_status = health["status"]
     
     if health["status"] == "unhealthy":
         logger.error("⚠️  FRIDAY started with errors - some features may not work")
@@ -132,6 +153,10 @@ async def startup_event():
         logger.warning("⚠️  FRIDAY started with warnings - some optional features disabled")
     else:
         logger.success("✅ FRIDAY is online and fully operational! 🎀")
+    if settings.api_key_required and not settings.api_key:
+        logger.error("⚠️  API_KEY_REQUIRED is set without an API_KEY - every request will be rejected")
+    elif not settings.api_key_required and settings.server_host not in ("127.0.0.1", "localhost", "::1"):
+        logger.warning(f"⚠️  Listening on {settings.server_host} without an access token - anyone who can reach this port can control this PC")
     logger.info(f"📡 Server: http://{settings.server_host}:{settings.server_port}")
     logger.info(f"📚 API Docs: http://{settings.server_host}:{settings.server_port}/docs")
     logger.info(f"🤖 Provider: Groq AI")
@@ -170,6 +195,7 @@ async def health_check():
     """Health check endpoint"""
     return {
         "status": "healthy",
+        "system_status": getattr(app.state, "system_status", "unknown"),
         "provider": "groq",
         "model": settings.default_model,
         "api_configured": bool(settings.groq_api_key),
@@ -183,6 +209,18 @@ async def health_check():
     }
 
 
+@app.post("/shutdown")
+async def shutdown(request: Request):
+    """Graceful shutdown requested by the desktop shell's backend supervisor"""
+    if request.client is None or request.client.host not in ("127.0.0.1", "::1"):
+        return JSONResponse(status_code=403, content={"error": "Shutdown is only allowed from localhost"})
+    if server is None:
+        return JSONResponse(status_code=409, content={"error": "Server was started with reload enabled"})
+    
+    server.should_exit = True
+    return {"success": True}
+
+
 # ============================================
 # Include Routers
 # ============================================
@@ -208,6 +246,10 @@ from api import windows_api
 app.include_router(windows_api.router, tags=["Windows Control"])
 from api import keyboard_api
 app.include_router(keyboard_api.router, tags=["Keyboard"])
+from api import notifications_api
+app.include_router(notifications_api.router, tags=["Notifications"])
+from api import settings_api
+app.include_router(settings_api.router, tags=["Settings"])
 
 # TTS endpoint
 from fastapi import Body
@@ -247,12 +289,24 @@ async def text_to_speech_local(
 # ============================================
 # Main Entry Point
 # ============================================
+# Set when running without reload so /shutdown can stop the server cleanly
+server: uvicorn.Server = None
+
 if __name__ == "__main__":
-    uvicorn.run(
-        "main:app",
-        host=settings.server_host,
-        port=settings.server_port,
-        reload=settings.reload,
-        log_level=settings.log_level
-    )
+    if settings.reload:
+        uvicorn.run(
+            "main:app",
+            host=settings.server_host,
+            port=settings.server_port,
+            reload=True,
+            log_level=settings.log_level
+        )
+    else:
+        server = uvicorn.Server(uvicorn.Config(
+            app,
+            host=settings.server_host,
+            port=settings.server_port,
+            log_level=settings.log_level
+        ))
+        server.run()
 
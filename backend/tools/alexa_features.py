@@ -12,6 +12,8 @@ from typing import Dict, List, Any, Optional
 import threading
 from loguru import logger
 
+from core import tool_context
+
 
 class AlexaFeatures:
     """
@@ -30,12 +32,17 @@ class AlexaFeatures:
         self.data_dir.mkdir(parents=True, exist_ok=True)
         
         self.timers = []
-        self.alarms = []
+        self.alarms = self._load_json("alarms.json", [])
         self.todo_lists = self._load_json("todo_lists.json", {})
         self.shopping_lists = self._load_json("shopping_lists.json", {})
         self.routines = self._load_json("routines.json", self._default_routines())
         self.context = {}
         
+        # Finished timers / ringing alarms waiting for the desktop shell to notify
+        self.due_events = []
+        self._due_lock = threading.Lock()
+        self._alarms_fired = {}  # alarm id -> date it last rang
+        
         # Start background timer checker
         self.timer_thread = threading.Thread(target=self._check_timers_loop, daemon=True)
         self.timer_thread.start()
@@ -111,12 +118,14 @@ class AlexaFeatures:
     # TIMERS AND ALARMS
     # ============================================
     
-    def set_timer(self, duration: int, name: str = "Timer") -> Dict:
+    def set_timer(self, duration: int, name: str = "Timer", conversation_id: Optional[str] = None) -> Dict:
         """
         Set a timer (like Alexa)
         Args:
             duration: Duration in seconds
             name: Timer name
+            conversation_id: Conversation it was set from; defaults to the
+                current tool call's
         """
         timer_id = f"timer_{int(time.time())}"
         end_time = time.time() + duration
@@ -127,7 +136,8 @@ class AlexaFeatures:
             "duration": duration,
             "end_time": end_time,
             "started_at": time.time(),
-            "active": True
+            "active": True,
+            "conversation_id": conversation_id or tool_context.conversation_id()
         }
         
         self.timers.append(timer)
@@ -178,7 +188,7 @@ class AlexaFeatures:
             "count": len(active_timers)
         }
     
-    def set_alarm(self, time_str: str, name: str = "Alarm") -> Dict:
+    def set_alarm(self, time_str: str, name: str = "Alarm", conversation_id: Optional[str] = None) -> Dict:
         """Set an alarm for specific time"""
         try:
             # Parse time (e.g., "7:30 AM", "19:30")
@@ -189,7 +199,8 @@ class AlexaFeatures:
                 "id": alarm_id,
                 "name": name,
                 "time": alarm_time.strftime("%H:%M"),
-                "enabled": True
+                "enabled": True,
+                "conversation_id": conversation_id or tool_context.conversation_id()
             }
             
             self.alarms.append(alarm)
@@ -213,13 +224,54 @@ class AlexaFeatures:
                         # Timer finished!
                         self.timers.remove(timer)
                         logger.info(f"TIMER FINISHED: {timer['name']}")
-                        # Trigger notification (handled by frontend)
+                        self._push_due_event({
+                            "kind": "timer",
+                            "id": timer["id"],
+                            "title": timer["name"],
+                            "body": f"{self._format_duration(timer['duration'])} timer finished",
+                            "conversation_id": timer.get("conversation_id")
+                        })
                 
+                self._check_alarms()
                 time.sleep(1)
             except Exception as e:
                 logger.error(f"Timer check error: {e}")
                 time.sleep(5)
     
+    def _check_alarms(self):
+        """Ring enabled alarms whose HH:MM has come, once per day"""
+        now = datetime.now()
+        today = now.date().isoformat()
+        current = now.strftime("%H:%M")
+        for alarm in self.alarms:
+            if not alarm.get("enabled", True) or alarm.get("time") != current:
+                continue
+            if self._alarms_fired.get(alarm["id"]) == today:
+                continue
+            self._alarms_fired[alarm["id"]] = today
+            logger.info(f"ALARM: {alarm['name']}")
+            self._push_due_event({
+                "kind": "alarm",
+                "id": alarm["id"],
+                "title": alarm["name"],
+                "body": f"Alarm for {now.strftime('%I:%M %p')}",
+                "conversation_id": alarm.get("conversation_id")
+            })
+    
+    def _push_due_event(self, event: Dict):
+        with self._due_lock:
+            self.due_events.append(event)
+    
+    def pop_due_events(self) -> List[Dict]:
+        """Take all finished timers and ringing alarms not yet delivered"""
+        with self._due_lock:
+            events, self.due_events = self.due_events, []
+        return events
+    
+    def snooze(self, name: str, minutes: int, conversation_id: Optional[str] = None) -> Dict:
+        """Ring a finished timer or alarm again in `minutes` as a one-off timer"""
+        return self.set_timer(minutes * 60, name, conversation_id)
+    
     # ============================================
     # TO-DO LISTS
     # ============================================
@@ -1,8 +1,15 @@
 """
 Code Executor Tool
 Safely execute code in sandboxed environment
+
+When FRIDAY runs under the desktop app, snippets go to the app's sandbox
+runner (FRIDAY_SANDBOX_EXE --sandbox-exec): an isolated child process with
+no network, a scratch dir and CPU/memory/time limits. The in-process paths
+below are only used with SANDBOX_MODE=false.
 """
 import asyncio
+import json
+import os
 import sys
 import io
 import contextlib
@@ -12,6 +19,16 @@ from loguru import logger
 from core.tool_manager import BaseTool
 from config import settings
 
+SANDBOX_EXE_ENV = "FRIDAY_SANDBOX_EXE"
+SANDBOX_LANGUAGES = {
+    "python": "python",
+    "javascript": "javascript",
+    "js": "javascript",
+    "bash": "bash",
+    "shell": "bash",
+    "cmd": "bash"
+}
+
 
 class CodeExecutorTool(BaseTool):
     """Code execution tool with sandbox support"""
@@ -42,6 +59,15 @@ class CodeExecutorTool(BaseTool):
             return {"error": f"Language '{language}' is not enabled"}
         
         try:
+            if os.environ.get(SANDBOX_EXE_ENV):
+                return await self.execute_sandboxed(SANDBOX_LANGUAGES[language], code)
+            if settings.sandbox_mode:
+                return {
+                    "success": False,
+                    "language": language,
+                    "error": "Sandboxed execution needs the FRIDAY desktop app; "
+                             "set SANDBOX_MODE=false to run code unsandboxed"
+                }
             result = await executors[language](code)
             return result
         except Exception as e:
@@ -52,6 +78,53 @@ class CodeExecutorTool(BaseTool):
                 "language": language
             }
     
+    async def execute_sandboxed(self, language: str, code: str) -> Dict[str, Any]:
+        """Execute code through the desktop app's sandbox runner"""
+        request = json.dumps({
+            "language": language,
+            "code": code,
+            "timeout_secs": settings.code_execution_timeout
+        }).encode()
+        process = await asyncio.create_subprocess_exec(
+            os.environ[SANDBOX_EXE_ENV], "--sandbox-exec",
+            stdin=asyncio.subprocess.PIPE,
+            stdout=asyncio.subprocess.PIPE,
+            stderr=asyncio.subprocess.DEVNULL
+        )
+        try:
+            # The runner enforces the timeout itself; this only catches a hung runner
+            stdout, _ = await asyncio.wait_for(
+                process.communicate(request),
+                timeout=settings.code_execution_timeout + 15
+            )
+        except asyncio.TimeoutError:
+            process.kill()
+            return {"success": False, "language": language, "error": "Sandbox did not respond"}
+        
+        result = json.loads(stdout or b"{}")
+        if "error" in result or "stdout" not in result:
+            return {
+                "success": False,
+                "language": language,
+                "error": result.get("error", "Sandbox returned no result")
+            }
+        
+        error = result["stderr"]
+        if result["timed_out"]:
+            error += "\nExecution timeout"
+        if result["truncated"]:
+            error += "\nOutput truncated"
+        return {
+            "success": result["exit_code"] == 0 and not result["timed_out"],
+            "language": language,
+            "output": result["stdout"],
+            "error": error.strip(),
+            "return_code": result["exit_code"],
+            "signal": result["signal"],
+            "duration_ms": result["duration_ms"],
+            "sandbox": result["isolation"]
+        }
+    
     async def execute_python(self, code: str) -> Dict[str, Any]:
         """Execute Python code"""
         # Capture stdout and stderr
@@ -9,6 +9,7 @@ from typing import Dict, Any, List, Optional
 from pathlib import Path
 from loguru import logger
 
+from core import tool_context
 from core.tool_manager import BaseTool
 
 
@@ -90,6 +91,8 @@ class RemindersScheduleTool(BaseTool):
             "due_date": due_date,
             "priority": priority,  # low, medium, high
             "category": category,
+            # Where "Open conversation" on the notification leads
+            "conversation_id": tool_context.conversation_id(),
             "completed": False,
             "created_at": datetime.now().isoformat(),
             "updated_at": datetime.now().isoformat()
@@ -194,6 +197,9 @@ class RemindersScheduleTool(BaseTool):
                 for key, value in updates.items():
                     if key in reminder:
                         reminder[key] = value
+                if "due_date" in updates:
+                    # A rescheduled reminder should notify again
+                    reminder["notified"] = False
                 reminder["updated_at"] = datetime.now().isoformat()
                 self._save_reminders()
                 return {
@@ -239,6 +245,44 @@ class RemindersScheduleTool(BaseTool):
             "total_due_soon": len(due_soon)
         }
     
+    def pop_due_notifications(self) -> List[Dict]:
+        """Reminders that came due and have not been notified yet (marks them notified)"""
+        now = datetime.now()
+        due = []
+        for reminder in self.reminders:
+            if reminder["completed"] or reminder.get("notified") or not reminder["due_date"]:
+                continue
+            try:
+                if datetime.fromisoformat(reminder["due_date"]) <= now:
+                    reminder["notified"] = True
+                    due.append(reminder)
+            except ValueError:
+                continue
+        
+        if due:
+            self._save_reminders()
+        return due
+    
+    async def snooze_reminder(self, reminder_id: int, minutes: int) -> Dict[str, Any]:
+        """Push a reminder's due date `minutes` from now so it fires again"""
+        for reminder in self.reminders:
+            if reminder["id"] == reminder_id:
+                reminder["due_date"] = (datetime.now() + timedelta(minutes=minutes)).isoformat()
+                reminder["notified"] = False
+                reminder["updated_at"] = datetime.now().isoformat()
+                self._save_reminders()
+                return {
+                    "success": True,
+                    "operation": "snooze_reminder",
+                    "reminder": reminder,
+                    "message": f"Reminder '{reminder['title']}' snoozed for {minutes} minutes"
+                }
+        
+        return {
+            "success": False,
+            "error": f"Reminder {reminder_id} not found"
+        }
+    
     def validate_args(self, operation: str = None, **kwargs) -> bool:
         """Validate arguments"""
         return bool(operation)
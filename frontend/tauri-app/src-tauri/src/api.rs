@@ -0,0 +1,469 @@
+// FRIDAY AI Assistant - Backend API bridge
+// The webview has no HTTP access of its own (see the CSP in tauri.conf.json).
+// Each backend endpoint the UI uses is one `BackendCall` variant with typed,
+// validated arguments, forwarded here over the supervisor's client.
+
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine as _;
+use reqwest::multipart::{Form, Part};
+use reqwest::{RequestBuilder, StatusCode};
+use serde::Deserialize;
+use serde_json::{json, Map, Value};
+use tauri::State;
+
+use crate::backend::BackendSupervisor;
+
+const MAX_ID_LEN: usize = 128;
+const MAX_NAME_LEN: usize = 200;
+const MAX_TEXT_LEN: usize = 20_000;
+/// Attachments and recordings cross IPC base64-encoded
+pub(crate) const MAX_UPLOAD_BYTES: usize = 25 * 1024 * 1024;
+const MAX_TIMER_SECS: u64 = 24 * 60 * 60;
+const MAX_GRID: u32 = 12;
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Direction {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+impl Direction {
+    fn as_str(self) -> &'static str {
+        match self {
+            Direction::Left => "left",
+            Direction::Right => "right",
+            Direction::Up => "up",
+            Direction::Down => "down",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Corner {
+    Tl,
+    Tr,
+    Bl,
+    Br,
+}
+
+impl Corner {
+    fn as_str(self) -> &'static str {
+        match self {
+            Corner::Tl => "tl",
+            Corner::Tr => "tr",
+            Corner::Bl => "bl",
+            Corner::Br => "br",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MouseButton {
+    Left,
+    Right,
+    Middle,
+}
+
+impl MouseButton {
+    fn as_str(self) -> &'static str {
+        match self {
+            MouseButton::Left => "left",
+            MouseButton::Right => "right",
+            MouseButton::Middle => "middle",
+        }
+    }
+}
+
+/// One backend request the UI may make, tagged by `call`
+#[derive(Debug, Deserialize)]
+#[serde(tag = "call", rename_all = "snake_case")]
+pub enum BackendCall {
+    Health,
+    SystemInfo,
+
+    GenerateConversationName {
+        id: String,
+    },
+    /// Chat attachment; `data` is base64
+    UploadFile {
+        file_name: String,
+        data: String,
+    },
+
+    /// Recorded speech for offline transcription; `data` is base64
+    Transcribe {
+        data: String,
+        #[serde(default)]
+        language: Option<String>,
+    },
+    Tts {
+        text: String,
+        language_code: String,
+        #[serde(default)]
+        slow: bool,
+    },
+    LocalTts {
+        text: String,
+        language_code: String,
+    },
+    VoiceCommand {
+        command: String,
+        language: String,
+    },
+
+    /// Named system gesture (`music_toggle`, `lock_pc`, ...)
+    Gesture {
+        gesture: String,
+        #[serde(default)]
+        params: Map<String, Value>,
+    },
+    CalibrateGestures {
+        smoothing: Option<f64>,
+        speed: Option<f64>,
+    },
+    /// Cursor position, normalized to 0-1 over the virtual desktop
+    MoveCursor {
+        x: f64,
+        y: f64,
+    },
+    Click {
+        button: MouseButton,
+    },
+    DragStart,
+    Swipe {
+        direction: Direction,
+    },
+    Monitors,
+    SnapWindow {
+        direction: Direction,
+    },
+    SnapCorner {
+        corner: Corner,
+    },
+    SnapGrid {
+        cols: u32,
+        rows: u32,
+        col: u32,
+        row: u32,
+    },
+    ActiveWindow,
+    SendHotkey {
+        combo: String,
+    },
+
+    SetTimer {
+        duration: u64,
+        #[serde(default)]
+        name: Option<String>,
+    },
+    CancelTimer,
+    Timers,
+    AddTodo {
+        item: String,
+        list_name: String,
+    },
+    CompleteTodo {
+        item_text: String,
+        list_name: String,
+    },
+    Todos {
+        list_name: String,
+    },
+    AddShopping {
+        item: String,
+        quantity: String,
+    },
+    Shopping,
+    RunRoutine {
+        routine_name: String,
+    },
+}
+
+impl BackendCall {
+    fn request(self, backend: &BackendSupervisor) -> Result<RequestBuilder, String> {
+        let client = backend.client();
+        let url = |path: &str| format!("{}{path}", backend.base_url());
+
+        let request = match self {
+            BackendCall::Health => client.get(url("/health")),
+            BackendCall::SystemInfo => client.get(url("/api/system/info")),
+
+            BackendCall::GenerateConversationName { id } => client.post(url(&format!(
+                "/api/chat/conversations/{}/generate-name",
+                valid_id(&id)?
+            ))),
+            BackendCall::UploadFile { file_name, data } => {
+                let file_name = text("file name", &file_name, MAX_NAME_LEN)?.to_string();
+                let part = Part::bytes(decode(&data)?).file_name(file_name);
+                client
+                    .post(url("/api/files/upload"))
+                    .multipart(Form::new().part("file", part))
+            }
+
+            BackendCall::Transcribe { data, language } => {
+                let part = Part::bytes(decode(&data)?).file_name("audio.webm");
+                let mut form = Form::new().part("file", part);
+                if let Some(language) = language {
+                    form = form.text("language", language_code(&language)?.to_string());
+                }
+                client.post(url("/api/stt/transcribe")).multipart(form)
+            }
+            BackendCall::Tts {
+                text: speech,
+                language_code: code,
+                slow,
+            } => client.post(url("/api/tts")).json(&json!({
+                "text": text("text", &speech, MAX_TEXT_LEN)?,
+                "language_code": language_code(&code)?,
+                "slow": slow,
+            })),
+            BackendCall::LocalTts {
+                text: speech,
+                language_code: code,
+            } => client.post(url("/api/tts/local")).json(&json!({
+                "text": text("text", &speech, MAX_TEXT_LEN)?,
+                "language_code": language_code(&code)?,
+            })),
+            BackendCall::VoiceCommand { command, language } => {
+                client.post(url("/api/voice/execute")).json(&json!({
+                    "command": text("command", &command, MAX_NAME_LEN)?,
+                    "language": language_code(&language)?,
+                }))
+            }
+
+            BackendCall::Gesture { gesture, params } => {
+                client.post(url("/api/gesture/execute")).json(&json!({
+                    "gesture": identifier("gesture", &gesture)?,
+                    "params": params,
+                }))
+            }
+            BackendCall::CalibrateGestures { smoothing, speed } => {
+                let mut query = Vec::new();
+                if let Some(smoothing) = smoothing {
+                    query.push(("smoothing", finite("smoothing", smoothing)?));
+                }
+                if let Some(speed) = speed {
+                    query.push(("speed", finite("speed", speed)?));
+                }
+                client.post(url("/api/gesture/calibrate")).query(&query)
+            }
+            BackendCall::MoveCursor { x, y } => {
+                client.post(url("/api/gesture/cursor")).json(&json!({
+                    "x": finite("x", x)?.clamp(0.0, 1.0),
+                    "y": finite("y", y)?.clamp(0.0, 1.0),
+                    "smooth": true,
+                }))
+            }
+            BackendCall::Click { button } => client
+                .post(url("/api/gesture/click"))
+                .json(&json!({ "button": button.as_str() })),
+            BackendCall::DragStart => client.post(url("/api/gesture/drag-start")).json(&json!({})),
+            BackendCall::Swipe { direction } => client
+                .post(url("/api/gesture/swipe"))
+                .json(&json!({ "direction": direction.as_str() })),
+            BackendCall::Monitors => client.get(url("/api/display/monitors")),
+            BackendCall::SnapWindow { direction } => client
+                .post(url("/api/windows/snap"))
+                .query(&[("direction", direction.as_str())]),
+            BackendCall::SnapCorner { corner } => client
+                .post(url("/api/windows/snap_corner"))
+                .query(&[("corner", corner.as_str())]),
+            BackendCall::SnapGrid {
+                cols,
+                rows,
+                col,
+                row,
+            } => {
+                if !(1..=MAX_GRID).contains(&cols) || !(1..=MAX_GRID).contains(&rows) {
+                    return Err(format!("grid must be 1-{MAX_GRID} cells each way"));
+                }
+                if col >= cols || row >= rows {
+                    return Err(format!("cell {col},{row} is outside a {cols}x{rows} grid"));
+                }
+                client.post(url("/api/windows/snap_grid")).query(&[
+                    ("cols", cols),
+                    ("rows", rows),
+                    ("col", col),
+                    ("row", row),
+                ])
+            }
+            BackendCall::ActiveWindow => client.get(url("/api/windows/active_window")),
+            BackendCall::SendHotkey { combo } => client
+                .post(url("/api/keyboard/send"))
+                .query(&[("combo", hotkey(&combo)?)]),
+
+            BackendCall::SetTimer { duration, name } => {
+                if !(1..=MAX_TIMER_SECS).contains(&duration) {
+                    return Err(format!("timer must be 1-{MAX_TIMER_SECS} seconds"));
+                }
+                let name = name.as_deref().unwrap_or("Timer");
+                client.post(url("/api/alexa/timer/set")).json(&json!({
+                    "duration": duration,
+                    "name": text("name", name, MAX_NAME_LEN)?,
+                }))
+            }
+            BackendCall::CancelTimer => client.post(url("/api/alexa/timer/cancel")),
+            BackendCall::Timers => client.get(url("/api/alexa/timers")),
+            BackendCall::AddTodo { item, list_name } => {
+                client.post(url("/api/alexa/todo/add")).json(&json!({
+                    "item": text("item", &item, MAX_NAME_LEN)?,
+                    "list_name": text("list name", &list_name, MAX_NAME_LEN)?,
+                }))
+            }
+            BackendCall::CompleteTodo {
+                item_text,
+                list_name,
+            } => client.post(url("/api/alexa/todo/complete")).json(&json!({
+                "item_text": text("item", &item_text, MAX_NAME_LEN)?,
+                "list_name": text("list name", &list_name, MAX_NAME_LEN)?,
+            })),
+            BackendCall::Todos { list_name } => client
+                .get(url("/api/alexa/todos"))
+                .query(&[("list_name", text("list name", &list_name, MAX_NAME_LEN)?)]),
+            BackendCall::AddShopping { item, quantity } => {
+                client.post(url("/api/alexa/shopping/add")).json(&json!({
+                    "item": text("item", &item, MAX_NAME_LEN)?,
+                    "quantity": text("quantity", &quantity, MAX_NAME_LEN)?,
+                }))
+            }
+            BackendCall::Shopping => client.get(url("/api/alexa/shopping")),
+            BackendCall::RunRoutine { routine_name } => {
+                client.post(url("/api/alexa/routine/run")).json(&json!({
+                    "routine_name": text("routine", &routine_name, MAX_NAME_LEN)?,
+                }))
+            }
+        };
+        Ok(request)
+    }
+}
+
+/// Make one backend request for the UI and return its JSON reply. HTTP
+/// errors come back as the backend's `detail` or `error` message.
+#[tauri::command]
+pub async fn backend_call(
+    backend: State<'_, BackendSupervisor>,
+    call: BackendCall,
+) -> Result<Value, String> {
+    let response = call.request(&backend)?.send().await.map_err(|e| {
+        if e.is_connect() {
+            "backend is not running".to_string()
+        } else {
+            e.to_string()
+        }
+    })?;
+
+    let status = response.status();
+    if status.is_success() {
+        return response
+            .json()
+            .await
+            .map_err(|e| format!("unexpected response: {e}"));
+    }
+    let body: Value = response.json().await.unwrap_or_default();
+    Err(error_message(status, &body))
+}
+
+fn error_message(status: StatusCode, body: &Value) -> String {
+    let detail = match [&body["detail"], &body["error"]]
+        .into_iter()
+        .find(|value| !value.is_null())
+    {
+        Some(Value::String(detail)) => detail.clone(),
+        Some(other) => other.to_string(),
+        None => status.to_string(),
+    };
+    format!("backend error ({}): {detail}", status.as_u16())
+}
+
+fn valid_id(id: &str) -> Result<&str, String> {
+    let valid = !id.is_empty()
+        && id.len() <= MAX_ID_LEN
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-'));
+    if valid {
+        Ok(id)
+    } else {
+        Err(format!("invalid id {id:?}"))
+    }
+}
+
+fn text<'a>(what: &str, value: &'a str, max_len: usize) -> Result<&'a str, String> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Err(format!("{what} is empty"));
+    }
+    optional_text(what, value, max_len)
+}
+
+fn optional_text<'a>(what: &str, value: &'a str, max_len: usize) -> Result<&'a str, String> {
+    if value.chars().count() > max_len {
+        Err(format!("{what} is longer than {max_len} characters"))
+    } else {
+        Ok(value)
+    }
+}
+
+/// Gesture names: `lock_pc`, `music_toggle`, ...
+fn identifier<'a>(what: &str, value: &'a str) -> Result<&'a str, String> {
+    let valid = !value.is_empty()
+        && value.len() <= 64
+        && value.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if valid {
+        Ok(value)
+    } else {
+        Err(format!("invalid {what} {value:?}"))
+    }
+}
+
+/// `en`, `en-US`, `hi-IN`, ...
+fn language_code(code: &str) -> Result<&str, String> {
+    let valid = (2..=16).contains(&code.len())
+        && code.starts_with(|c: char| c.is_ascii_alphabetic())
+        && code.chars().all(|c| c.is_ascii_alphanumeric() || c == '-');
+    if valid {
+        Ok(code)
+    } else {
+        Err(format!("invalid language code {code:?}"))
+    }
+}
+
+/// `ctrl+tab`, `ctrl+shift+p`: up to four keys joined by `+`
+fn hotkey(combo: &str) -> Result<&str, String> {
+    let keys: Vec<&str> = combo.split('+').collect();
+    let valid = keys.len() <= 4
+        && keys.iter().all(|key| {
+            (1..=12).contains(&key.len()) && key.chars().all(|c| c.is_ascii_alphanumeric())
+        });
+    if valid {
+        Ok(combo)
+    } else {
+        Err(format!("invalid hotkey {combo:?}"))
+    }
+}
+
+fn finite(what: &str, value: f64) -> Result<f64, String> {
+    if value.is_finite() {
+        Ok(value)
+    } else {
+        Err(format!("{what} must be a number"))
+    }
+}
+
+fn decode(data: &str) -> Result<Vec<u8>, String> {
+    // base64 is 4 characters for every 3 bytes
+    if data.len() / 4 * 3 > MAX_UPLOAD_BYTES {
+        return Err(format!(
+            "file is larger than {} MB",
+            MAX_UPLOAD_BYTES / 1024 / 1024
+        ));
+    }
+    BASE64
+        .decode(data)
+        .map_err(|e| format!("invalid file data: {e}"))
+}
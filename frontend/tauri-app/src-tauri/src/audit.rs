@@ -0,0 +1,806 @@
+// FRIDAY AI Assistant - Tool Audit Log
+// Append-only record of every tool the backend runs or refuses: approval,
+// outcome and execution time, with the arguments, result and error sealed
+// under the data key. The backend keeps recent executions in its tool journal
+// (backend/core/tool_journal.py); this long-polls them into
+// <app data dir>/audit/tool-audit.jsonl, which moves to tool-audit.1.jsonl
+// (and so on, up to ROTATED_LOGS) once it reaches MAX_LOG_BYTES. Each line
+// carries an HMAC, keyed by the data key, over itself and the hash of the one
+// before it, so editing, reordering or deleting entries breaks the chain.
+// audit.head records the first and last entries kept, so a cut-off start or
+// end shows up too.
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine as _;
+use chrono::{DateTime, Local, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tauri::{AppHandle, Manager, State};
+
+use crate::backend::{BackendStatus, BackendSupervisor};
+use crate::encryption::{DataKey, Encryption};
+
+const AUDIT_DIR: &str = "audit";
+const LOG_FILE: &str = "tool-audit.jsonl";
+const HEAD_FILE: &str = "audit.head";
+/// What the chain's HMAC subkey is derived for
+const MAC_PURPOSE: &str = "tool-audit";
+/// `prev_hash` of the first entry
+const GENESIS: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+/// Size at which the log moves to tool-audit.1.jsonl
+const MAX_LOG_BYTES: u64 = 4 * 1024 * 1024;
+/// Rotated logs kept next to the current one; older entries are dropped
+const ROTATED_LOGS: usize = 4;
+/// Longest argument list, result or error kept, as JSON
+const MAX_DETAIL_BYTES: usize = 8 * 1024;
+const LONG_POLL_SECS: u64 = 25;
+const RETRY_INTERVAL: Duration = Duration::from_secs(1);
+const DEFAULT_LIMIT: usize = 200;
+
+/// One execution from `/api/tools/executions`
+#[derive(Debug, Deserialize)]
+struct Execution {
+    seq: u64,
+    tool: String,
+    #[serde(default)]
+    arguments: Value,
+    source: Option<String>,
+    call_id: Option<String>,
+    conversation_id: Option<String>,
+    project_id: Option<String>,
+    approval: Option<Value>,
+    success: bool,
+    #[serde(default)]
+    result: Value,
+    error: Option<String>,
+    execution_time: Option<f64>,
+    /// Unix time, seconds
+    finished_at: f64,
+}
+
+#[derive(Debug, Deserialize)]
+struct Executions {
+    /// Changes when the backend restarts and its sequence numbers start over
+    boot_id: String,
+    executions: Vec<Execution>,
+}
+
+/// What a call was given and what came back, cut to MAX_DETAIL_BYTES each
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditDetails {
+    pub arguments: Value,
+    pub result: Value,
+    pub error: Option<String>,
+}
+
+impl AuditDetails {
+    fn seal(&self, key: &DataKey) -> String {
+        BASE64.encode(key.encrypt(&serde_json::to_vec(self).unwrap_or_default()))
+    }
+
+    fn open(sealed: &str, key: &DataKey) -> Result<Self, String> {
+        let data = BASE64.decode(sealed).map_err(|e| e.to_string())?;
+        serde_json::from_slice(&key.decrypt(&data)?).map_err(|e| e.to_string())
+    }
+}
+
+/// The hashed part of an entry
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditRecord {
+    pub seq: u64,
+    /// RFC 3339, UTC
+    pub finished_at: String,
+    pub tool: String,
+    /// `model`, `auto` or `api`
+    pub source: Option<String>,
+    pub call_id: Option<String>,
+    pub conversation_id: Option<String>,
+    pub project_id: Option<String>,
+    /// `{approved, reason, decided_by}` for calls that went through approval
+    pub approval: Option<Value>,
+    pub success: bool,
+    /// `AuditDetails`, encrypted with the data key and base64-encoded
+    pub sealed: String,
+    /// Seconds, as measured by the backend
+    pub execution_time: Option<f64>,
+    pub prev_hash: String,
+}
+
+impl AuditRecord {
+    fn hash(&self, key: &DataKey) -> String {
+        key.mac(MAC_PURPOSE, &serde_json::to_vec(self).unwrap_or_default())
+    }
+}
+
+/// One line of the log
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditEntry {
+    #[serde(flatten)]
+    pub record: AuditRecord,
+    pub hash: String,
+}
+
+/// An entry with its details opened, as shown in the UI
+#[derive(Debug, Clone, Serialize)]
+pub struct AuditView {
+    #[serde(flatten)]
+    pub entry: AuditEntry,
+    /// None if they no longer decrypt
+    pub details: Option<AuditDetails>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct AuditFilter {
+    pub tool: Option<String>,
+    pub conversation_id: Option<String>,
+    /// RFC 3339 or `YYYY-MM-DD` (local midnight)
+    pub since: Option<String>,
+    /// RFC 3339 or `YYYY-MM-DD` (that whole day)
+    pub until: Option<String>,
+    pub limit: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AuditVerification {
+    pub valid: bool,
+    pub entries: u64,
+    pub head_hash: Option<String>,
+    /// Line of the first entry that does not check out, counting across the
+    /// rotated logs from the oldest
+    pub broken_at: Option<u64>,
+    pub problem: Option<String>,
+}
+
+/// Ends of the chain: where the kept history starts and the next entry goes
+struct Chain {
+    dir: PathBuf,
+    first: u64,
+    seq: u64,
+    hash: String,
+}
+
+#[derive(Default)]
+pub struct AuditLog {
+    chain: Mutex<Option<Chain>>,
+}
+
+/// Open the log and start recording. Called once from `setup`.
+pub fn start(app: &AppHandle) {
+    let Some(dir) = app
+        .path_resolver()
+        .app_data_dir()
+        .map(|dir| dir.join(AUDIT_DIR))
+    else {
+        eprintln!("[audit] app data directory is unavailable; tool calls are not audited");
+        return;
+    };
+    if let Err(e) = fs::create_dir_all(&dir) {
+        eprintln!("[audit] could not create {}: {e}", dir.display());
+        return;
+    }
+    // Carry on from the last readable entry; `verify` reports anything after it
+    let entries = read_log(&dir).unwrap_or_default();
+    let (seq, hash) = entries
+        .iter()
+        .rev()
+        .find_map(|entry| entry.as_ref().ok())
+        .map(|entry| (entry.record.seq, entry.hash.clone()))
+        .unwrap_or((0, GENESIS.to_string()));
+    let first = first_seq(&entries).unwrap_or(seq + 1);
+    *app.state::<AuditLog>().chain.lock().unwrap() = Some(Chain {
+        dir,
+        first,
+        seq,
+        hash,
+    });
+
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        // (backend boot id, last sequence number recorded)
+        let mut cursor: Option<(String, u64)> = None;
+        loop {
+            let backend = app.state::<BackendSupervisor>().inner().clone();
+            if backend.status() != BackendStatus::Ready {
+                tokio::time::sleep(RETRY_INTERVAL).await;
+                continue;
+            }
+
+            let after = cursor.as_ref().map_or(0, |(_, seq)| *seq);
+            let url = format!("{}/api/tools/executions", backend.base_url());
+            let batch = match backend
+                .client()
+                .get(&url)
+                .query(&[("after", after), ("wait", LONG_POLL_SECS)])
+                .send()
+                .await
+            {
+                Ok(resp) => resp.json::<Executions>().await,
+                Err(_) => {
+                    tokio::time::sleep(RETRY_INTERVAL).await;
+                    continue;
+                }
+            };
+            let batch = match batch {
+                Ok(batch) => batch,
+                Err(e) => {
+                    eprintln!("[audit] bad response from {url}: {e}");
+                    tokio::time::sleep(RETRY_INTERVAL).await;
+                    continue;
+                }
+            };
+
+            // A restarted backend numbers from 1 again
+            if cursor
+                .as_ref()
+                .is_some_and(|(boot, _)| *boot != batch.boot_id)
+            {
+                cursor = None;
+                continue;
+            }
+            // Only move past what made it into the log; the rest comes back on
+            // the next poll
+            let mut last = after;
+            let mut failed = false;
+            for execution in batch.executions {
+                if execution.seq <= last {
+                    continue;
+                }
+                let seq = execution.seq;
+                match record(&app, execution) {
+                    Ok(view) => {
+                        last = seq;
+                        let _ = app.emit_all("audit://appended", view);
+                    }
+                    Err(e) => {
+                        eprintln!("[audit] could not append to {LOG_FILE}: {e}");
+                        failed = true;
+                        break;
+                    }
+                }
+            }
+            cursor = Some((batch.boot_id, last));
+            if failed {
+                tokio::time::sleep(RETRY_INTERVAL).await;
+            }
+        }
+    });
+}
+
+fn record(app: &AppHandle, execution: Execution) -> io::Result<AuditView> {
+    let key = app
+        .state::<Encryption>()
+        .key()
+        .ok_or_else(|| io::Error::other("data is locked"))?;
+    let log = app.state::<AuditLog>();
+    let mut chain = log.chain.lock().unwrap();
+    let chain = chain
+        .as_mut()
+        .ok_or_else(|| io::Error::other("audit log is not open"))?;
+    append(chain, &key, execution)
+}
+
+/// Seal `execution`, chain it after the last entry and update audit.head
+fn append(chain: &mut Chain, key: &DataKey, execution: Execution) -> io::Result<AuditView> {
+    let details = AuditDetails {
+        arguments: clip(execution.arguments),
+        result: clip(execution.result),
+        error: execution.error.map(|error| truncate(&error)),
+    };
+    let finished_at = DateTime::from_timestamp_millis((execution.finished_at * 1000.0) as i64)
+        .unwrap_or_else(Utc::now);
+    let record = AuditRecord {
+        seq: chain.seq + 1,
+        finished_at: finished_at.to_rfc3339_opts(chrono::SecondsFormat::Millis, true),
+        tool: execution.tool,
+        source: execution.source,
+        call_id: execution.call_id,
+        conversation_id: execution.conversation_id,
+        project_id: execution.project_id,
+        approval: execution.approval,
+        success: execution.success,
+        sealed: details.seal(key),
+        execution_time: execution.execution_time,
+        prev_hash: chain.hash.clone(),
+    };
+    let entry = AuditEntry {
+        hash: record.hash(key),
+        record,
+    };
+
+    rotate(chain)?;
+    let mut line = serde_json::to_string(&entry)?;
+    line.push('\n');
+    let mut file = open_private(&chain.dir.join(LOG_FILE), true)?;
+    file.write_all(line.as_bytes())?;
+    file.sync_data()?;
+
+    chain.seq = entry.record.seq;
+    chain.hash = entry.hash.clone();
+    write_head(chain, key)?;
+    Ok(AuditView {
+        entry,
+        details: Some(details),
+    })
+}
+
+/// `value` as is, or its JSON cut to MAX_DETAIL_BYTES
+fn clip(value: Value) -> Value {
+    let json = value.to_string();
+    if json.len() <= MAX_DETAIL_BYTES {
+        return value;
+    }
+    Value::String(truncate(&json))
+}
+
+fn truncate(text: &str) -> String {
+    if text.len() <= MAX_DETAIL_BYTES {
+        return text.to_string();
+    }
+    let end = (0..=MAX_DETAIL_BYTES)
+        .rev()
+        .find(|&i| text.is_char_boundary(i))
+        .unwrap_or(0);
+    format!("{}… ({} bytes in all)", &text[..end], text.len())
+}
+
+fn rotated_log(dir: &Path, n: usize) -> PathBuf {
+    dir.join(format!("tool-audit.{n}.jsonl"))
+}
+
+/// The current log and its rotated copies that exist, oldest first
+fn log_files(dir: &Path) -> Vec<PathBuf> {
+    (1..=ROTATED_LOGS)
+        .rev()
+        .map(|n| rotated_log(dir, n))
+        .chain([dir.join(LOG_FILE)])
+        .filter(|path| path.exists())
+        .collect()
+}
+
+/// Once the current log is full, shift the rotated copies up, dropping the
+/// oldest, and start a new one. The chain carries on across files.
+fn rotate(chain: &mut Chain) -> io::Result<()> {
+    let log = chain.dir.join(LOG_FILE);
+    if fs::metadata(&log).map_or(true, |meta| meta.len() < MAX_LOG_BYTES) {
+        return Ok(());
+    }
+    for n in (1..ROTATED_LOGS).rev() {
+        let from = rotated_log(&chain.dir, n);
+        if from.exists() {
+            fs::rename(from, rotated_log(&chain.dir, n + 1))?;
+        }
+    }
+    fs::rename(log, rotated_log(&chain.dir, 1))?;
+
+    let oldest = log_files(&chain.dir).into_iter().next();
+    let entries = match oldest {
+        Some(path) => read_entries(&path)?,
+        None => Vec::new(),
+    };
+    chain.first = first_seq(&entries).unwrap_or(chain.seq + 1);
+    Ok(())
+}
+
+/// `first seq hash mac`, so history cut from either end shows up
+fn write_head(chain: &Chain, key: &DataKey) -> io::Result<()> {
+    let line = format!("{} {} {}", chain.first, chain.seq, chain.hash);
+    let mac = key.mac(MAC_PURPOSE, line.as_bytes());
+    let mut head = open_private(&chain.dir.join(HEAD_FILE), false)?;
+    head.write_all(format!("{line} {mac}\n").as_bytes())?;
+    head.sync_data()
+}
+
+/// Owner-only on Unix; the log holds tool arguments and results
+fn open_private(path: &Path, append: bool) -> io::Result<File> {
+    let mut options = OpenOptions::new();
+    options.create(true);
+    if append {
+        options.append(true);
+    } else {
+        options.write(true).truncate(true);
+    }
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    options.open(path)
+}
+
+/// Every line of one log file, parsed where possible; a missing file is empty
+fn read_entries(path: &Path) -> io::Result<Vec<Result<AuditEntry, String>>> {
+    let file = match File::open(path) {
+        Ok(file) => file,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e),
+    };
+    BufReader::new(file)
+        .lines()
+        .map(|line| Ok(serde_json::from_str(&line?).map_err(|e| e.to_string())))
+        .collect()
+}
+
+/// Every line of every log file, oldest first
+fn read_log(dir: &Path) -> io::Result<Vec<Result<AuditEntry, String>>> {
+    let mut entries = Vec::new();
+    for path in log_files(dir) {
+        entries.extend(read_entries(&path)?);
+    }
+    Ok(entries)
+}
+
+fn first_seq(entries: &[Result<AuditEntry, String>]) -> Option<u64> {
+    entries
+        .iter()
+        .find_map(|entry| entry.as_ref().ok())
+        .map(|entry| entry.record.seq)
+}
+
+/// Recompute the chain from the oldest entry kept and compare both ends with
+/// audit.head
+fn verify(dir: &Path, key: &DataKey) -> io::Result<AuditVerification> {
+    let entries = read_log(dir)?;
+    let mut verification = AuditVerification {
+        valid: false,
+        entries: entries.len() as u64,
+        head_hash: None,
+        broken_at: None,
+        problem: None,
+    };
+    let mut first = None;
+    let mut last: Option<(u64, String)> = None;
+    for (line, entry) in (1u64..).zip(entries) {
+        let problem = match entry {
+            Err(e) => Some(format!("entry is unreadable: {e}")),
+            Ok(entry) => {
+                // Older entries may have been rotated away; audit.head says
+                // where the kept history should start
+                let (seq, prev_hash) = match &last {
+                    Some((seq, hash)) => (seq + 1, hash.clone()),
+                    None if entry.record.seq == 1 => (1, GENESIS.to_string()),
+                    None => (entry.record.seq, entry.record.prev_hash.clone()),
+                };
+                if entry.record.seq != seq {
+                    Some(format!(
+                        "expected entry #{seq}, found #{}",
+                        entry.record.seq
+                    ))
+                } else if entry.record.prev_hash != prev_hash {
+                    Some("does not follow the entry before it".into())
+                } else if entry.record.hash(key) != entry.hash {
+                    Some("contents do not match its hash".into())
+                } else {
+                    first.get_or_insert(seq);
+                    last = Some((seq, entry.hash));
+                    None
+                }
+            }
+        };
+        if problem.is_some() {
+            verification.broken_at = Some(line);
+            verification.problem = problem;
+            return Ok(verification);
+        }
+    }
+
+    let (seq, hash) = last.unwrap_or((0, GENESIS.to_string()));
+    let first = first.unwrap_or(seq + 1);
+    let head = fs::read_to_string(dir.join(HEAD_FILE)).ok();
+    let head = head
+        .as_deref()
+        .and_then(|head| head.trim().rsplit_once(' '));
+    verification.problem = match head {
+        None if seq > 0 => Some(format!("{HEAD_FILE} is missing or unreadable")),
+        None => None,
+        Some((line, mac)) if key.mac(MAC_PURPOSE, line.as_bytes()) != mac => {
+            Some(format!("{HEAD_FILE} has been altered"))
+        }
+        Some((line, _)) if line != format!("{first} {seq} {hash}") => {
+            let mut recorded = line.split(' ');
+            Some(format!(
+                "log holds entries #{first} to #{seq} but {HEAD_FILE} records #{} to #{}; entries were removed",
+                recorded.next().unwrap_or_default(),
+                recorded.next().unwrap_or_default(),
+            ))
+        }
+        Some(_) => None,
+    };
+    verification.valid = verification.problem.is_none();
+    verification.head_hash = (seq > 0).then_some(hash);
+    Ok(verification)
+}
+
+/// Re-seal every entry and recompute the chain under `to`. A log that already
+/// verifies under `to` is left alone; one that verifies under neither key is
+/// refused, since re-signing it would hide whatever broke it.
+pub fn rekey(app: &AppHandle, from: &DataKey, to: &DataKey) -> Result<(), String> {
+    let log = app.state::<AuditLog>();
+    let mut chain = log.chain.lock().unwrap();
+    let Some(chain) = chain.as_mut() else {
+        return Ok(());
+    };
+    if verify(&chain.dir, to).map_err(|e| e.to_string())?.valid {
+        return Ok(());
+    }
+    let verification = verify(&chain.dir, from).map_err(|e| e.to_string())?;
+    if let Some(problem) = verification.problem {
+        return Err(format!(
+            "the tool audit log does not verify ({problem}); move {} aside to rotate the key",
+            chain.dir.display()
+        ));
+    }
+
+    // Write every file before replacing any, so a failure leaves the log whole
+    let mut prev_hash: Option<String> = None;
+    let mut staged = Vec::new();
+    for path in log_files(&chain.dir) {
+        let mut lines = String::new();
+        for entry in read_entries(&path).map_err(|e| e.to_string())? {
+            let mut entry = entry?;
+            let details = AuditDetails::open(&entry.record.sealed, from)?;
+            entry.record.sealed = details.seal(to);
+            // The oldest entry kept points into rotated-away history
+            if let Some(hash) = prev_hash.take() {
+                entry.record.prev_hash = hash;
+            }
+            entry.hash = entry.record.hash(to);
+            prev_hash = Some(entry.hash.clone());
+            lines.push_str(&serde_json::to_string(&entry).map_err(|e| e.to_string())?);
+            lines.push('\n');
+        }
+        let temp = path.with_extension("jsonl.tmp");
+        open_private(&temp, false)
+            .and_then(|mut file| {
+                file.write_all(lines.as_bytes())?;
+                file.sync_data()
+            })
+            .map_err(|e| e.to_string())?;
+        staged.push((temp, path));
+    }
+    for (temp, path) in staged {
+        fs::rename(temp, path).map_err(|e| e.to_string())?;
+    }
+
+    if let Some(hash) = prev_hash {
+        chain.hash = hash;
+    }
+    write_head(chain, to).map_err(|e| e.to_string())
+}
+
+/// Start or end of a filter range
+fn parse_bound(value: &str, end_of_day: bool) -> Result<DateTime<Utc>, String> {
+    if let Ok(at) = DateTime::parse_from_rfc3339(value) {
+        return Ok(at.with_timezone(&Utc));
+    }
+    let date = NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .map_err(|_| format!("{value:?} is not a date or RFC 3339 time"))?;
+    let date = if end_of_day {
+        date.succ_opt()
+    } else {
+        Some(date)
+    };
+    date.and_then(|date| date.and_hms_opt(0, 0, 0))
+        .and_then(|midnight| midnight.and_local_timezone(Local).earliest())
+        .map(|at| at.with_timezone(&Utc))
+        .ok_or_else(|| format!("{value:?} is out of range"))
+}
+
+/// Entries matching `filter`, newest first
+#[tauri::command]
+pub fn audit_log(
+    log: State<'_, AuditLog>,
+    encryption: State<'_, Encryption>,
+    filter: AuditFilter,
+) -> Result<Vec<AuditView>, String> {
+    let key = encryption.key().ok_or("data is locked")?;
+    let since = filter
+        .since
+        .as_deref()
+        .map(|v| parse_bound(v, false))
+        .transpose()?;
+    let until = filter
+        .until
+        .as_deref()
+        .map(|v| parse_bound(v, true))
+        .transpose()?;
+
+    let chain = log.chain.lock().unwrap();
+    let chain = chain.as_ref().ok_or("audit log is not open")?;
+    let entries = read_log(&chain.dir).map_err(|e| e.to_string())?;
+
+    Ok(entries
+        .into_iter()
+        .rev()
+        .filter_map(Result::ok)
+        .filter(|entry| {
+            let record = &entry.record;
+            let at =
+                DateTime::parse_from_rfc3339(&record.finished_at).map(|at| at.with_timezone(&Utc));
+            filter.tool.as_ref().is_none_or(|tool| record.tool == *tool)
+                && filter
+                    .conversation_id
+                    .as_ref()
+                    .is_none_or(|id| record.conversation_id.as_ref() == Some(id))
+                && since.is_none_or(|since| at.is_ok_and(|at| at >= since))
+                && until.is_none_or(|until| at.is_ok_and(|at| at < until))
+        })
+        .take(filter.limit.unwrap_or(DEFAULT_LIMIT))
+        .map(|entry| AuditView {
+            details: AuditDetails::open(&entry.record.sealed, &key).ok(),
+            entry,
+        })
+        .collect())
+}
+
+/// Recompute the chain and compare its ends with audit.head
+#[tauri::command]
+pub fn verify_audit_log(
+    log: State<'_, AuditLog>,
+    encryption: State<'_, Encryption>,
+) -> Result<AuditVerification, String> {
+    let key = encryption.key().ok_or("data is locked")?;
+    let chain = log.chain.lock().unwrap();
+    let chain = chain.as_ref().ok_or("audit log is not open")?;
+    verify(&chain.dir, &key).map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn execution(seq: u64, tool: &str) -> Execution {
+        Execution {
+            seq,
+            tool: tool.into(),
+            arguments: serde_json::json!({ "path": "/tmp/notes.txt" }),
+            source: Some("model".into()),
+            call_id: Some(format!("call-{seq}")),
+            conversation_id: None,
+            project_id: None,
+            approval: None,
+            success: true,
+            result: serde_json::json!("ok"),
+            error: None,
+            execution_time: Some(0.25),
+            finished_at: 1_700_000_000.0 + seq as f64,
+        }
+    }
+
+    /// A log in a fresh temp dir holding `count` entries
+    fn log_with(count: u64, key: &DataKey) -> Chain {
+        let dir = std::env::temp_dir().join(format!("friday-audit-{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&dir).unwrap();
+        let mut chain = Chain {
+            dir,
+            first: 1,
+            seq: 0,
+            hash: GENESIS.to_string(),
+        };
+        for seq in 1..=count {
+            append(&mut chain, key, execution(seq, "read_file")).unwrap();
+        }
+        chain
+    }
+
+    fn lines(chain: &Chain) -> Vec<String> {
+        fs::read_to_string(chain.dir.join(LOG_FILE))
+            .unwrap()
+            .lines()
+            .map(String::from)
+            .collect()
+    }
+
+    fn write_lines(chain: &Chain, lines: &[String]) {
+        fs::write(chain.dir.join(LOG_FILE), lines.join("\n") + "\n").unwrap();
+    }
+
+    #[test]
+    fn appended_entries_verify() {
+        let key = DataKey::generate();
+        let chain = log_with(3, &key);
+        let verification = verify(&chain.dir, &key).unwrap();
+        assert!(verification.valid, "{:?}", verification.problem);
+        assert_eq!(verification.entries, 3);
+        assert_eq!(verification.head_hash, Some(chain.hash.clone()));
+
+        let entries = read_log(&chain.dir).unwrap();
+        let second = entries[1].as_ref().unwrap();
+        assert_eq!(second.record.seq, 2);
+        assert_eq!(second.record.prev_hash, entries[0].as_ref().unwrap().hash);
+        let details = AuditDetails::open(&second.record.sealed, &key).unwrap();
+        assert_eq!(details.arguments["path"], "/tmp/notes.txt");
+        assert!(!fs::read_to_string(chain.dir.join(LOG_FILE))
+            .unwrap()
+            .contains("notes.txt"));
+        fs::remove_dir_all(&chain.dir).unwrap();
+    }
+
+    #[test]
+    fn an_edited_entry_breaks_the_chain() {
+        let key = DataKey::generate();
+        let chain = log_with(3, &key);
+        let mut lines = lines(&chain);
+        lines[1] = lines[1].replace("read_file", "delete_file");
+        write_lines(&chain, &lines);
+
+        let verification = verify(&chain.dir, &key).unwrap();
+        assert!(!verification.valid);
+        assert_eq!(verification.broken_at, Some(2));
+        assert_eq!(
+            verification.problem.as_deref(),
+            Some("contents do not match its hash")
+        );
+        fs::remove_dir_all(&chain.dir).unwrap();
+    }
+
+    #[test]
+    fn a_deleted_entry_is_reported() {
+        let key = DataKey::generate();
+        let chain = log_with(3, &key);
+        let mut lines = lines(&chain);
+
+        // From the middle: the next entry no longer follows on
+        let middle = lines.remove(1);
+        write_lines(&chain, &lines);
+        let verification = verify(&chain.dir, &key).unwrap();
+        assert!(!verification.valid);
+        assert_eq!(verification.broken_at, Some(2));
+        assert_eq!(
+            verification.problem.as_deref(),
+            Some("expected entry #2, found #3")
+        );
+
+        // From the end: the chain holds up but audit.head does not match
+        lines.insert(1, middle);
+        lines.pop();
+        write_lines(&chain, &lines);
+        let verification = verify(&chain.dir, &key).unwrap();
+        assert!(!verification.valid);
+        assert_eq!(verification.broken_at, None);
+        assert!(verification
+            .problem
+            .unwrap()
+            .ends_with("records #1 to #3; entries were removed"));
+        fs::remove_dir_all(&chain.dir).unwrap();
+    }
+
+    #[test]
+    fn a_broken_head_is_reported() {
+        let key = DataKey::generate();
+        let chain = log_with(2, &key);
+        let head = chain.dir.join(HEAD_FILE);
+        let altered = fs::read_to_string(&head).unwrap().replacen("1 2", "1 1", 1);
+        fs::write(&head, altered).unwrap();
+        let verification = verify(&chain.dir, &key).unwrap();
+        assert!(!verification.valid);
+        assert_eq!(
+            verification.problem,
+            Some(format!("{HEAD_FILE} has been altered"))
+        );
+
+        fs::remove_file(&head).unwrap();
+        let verification = verify(&chain.dir, &key).unwrap();
+        assert!(!verification.valid);
+        assert_eq!(
+            verification.problem,
+            Some(format!("{HEAD_FILE} is missing or unreadable"))
+        );
+        fs::remove_dir_all(&chain.dir).unwrap();
+    }
+
+    #[test]
+    fn another_key_does_not_verify() {
+        let chain = log_with(1, &DataKey::generate());
+        let verification = verify(&chain.dir, &DataKey::generate()).unwrap();
+        assert!(!verification.valid);
+        assert_eq!(verification.broken_at, Some(1));
+        fs::remove_dir_all(&chain.dir).unwrap();
+    }
+}
@@ -0,0 +1,474 @@
+// FRIDAY AI Assistant - Backend Supervisor
+// Runs the FastAPI backend (backend/main.py) as a managed child process:
+// spawn, wait for /health, restart with backoff on crash, graceful stop on exit.
+// The backend listens on loopback only and rejects requests without the
+// random token generated for each launch of the app.
+
+use std::collections::HashMap;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::net::TcpListener;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use rand::rngs::OsRng;
+use rand::RngCore;
+use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION};
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Config, Manager};
+use tokio::process::{Child, Command};
+use tokio::sync::Notify;
+
+/// Port the backend used before it was supervised; still preferred when free
+pub const DEFAULT_PORT: u16 = 8000;
+/// Where `friday-cli` finds the running backend, in the app local data dir
+pub const PORT_FILE: &str = "backend.port";
+/// The launch token, next to the port file and readable by the owner only
+pub const TOKEN_FILE: &str = "backend.token";
+
+const READY_TIMEOUT: Duration = Duration::from_secs(90);
+const HEALTH_POLL_INTERVAL: Duration = Duration::from_millis(500);
+const SHUTDOWN_GRACE: Duration = Duration::from_secs(10);
+const BACKOFF_INITIAL: Duration = Duration::from_secs(1);
+const BACKOFF_MAX: Duration = Duration::from_secs(30);
+/// A backend that stays up this long resets the restart backoff
+const STABLE_AFTER: Duration = Duration::from_secs(60);
+const MAX_RESTARTS: u32 = 10;
+
+/// Lifecycle of the backend process, emitted to the frontend as `backend://status`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BackendStatus {
+    Starting,
+    Ready,
+    Restarting,
+    Stopped,
+    Failed,
+}
+
+/// Overall health reported by core/health_check.py at backend startup
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SystemHealth {
+    Healthy,
+    Degraded,
+    Unhealthy,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Handle to the supervised backend, stored in Tauri managed state
+#[derive(Clone)]
+pub struct BackendSupervisor {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    port: u16,
+    token: String,
+    status: Mutex<BackendStatus>,
+    health: Mutex<SystemHealth>,
+    stop: Notify,
+    restart: Notify,
+    stopped: Notify,
+    http: reqwest::Client,
+    /// Extra variables for the child, applied at each launch
+    env: Mutex<HashMap<String, String>>,
+}
+
+impl BackendSupervisor {
+    pub fn new(port: u16) -> Self {
+        let token = generate_token();
+        Self {
+            inner: Arc::new(Inner {
+                port,
+                http: authorized_client(&token),
+                token,
+                status: Mutex::new(BackendStatus::Stopped),
+                health: Mutex::new(SystemHealth::Unknown),
+                stop: Notify::new(),
+                restart: Notify::new(),
+                stopped: Notify::new(),
+                env: Mutex::new(HashMap::new()),
+            }),
+        }
+    }
+
+    pub fn port(&self) -> u16 {
+        self.inner.port
+    }
+
+    pub fn base_url(&self) -> String {
+        format!("http://127.0.0.1:{}", self.inner.port)
+    }
+
+    /// Shared HTTP client for requests to the backend; sends the launch token
+    pub fn client(&self) -> &reqwest::Client {
+        &self.inner.http
+    }
+
+    pub fn status(&self) -> BackendStatus {
+        *self.inner.status.lock().unwrap()
+    }
+
+    pub fn health(&self) -> SystemHealth {
+        *self.inner.health.lock().unwrap()
+    }
+
+    /// Pass `key=value` to the backend from its next launch on
+    pub fn set_env(&self, key: &str, value: &str) {
+        self.inner
+            .env
+            .lock()
+            .unwrap()
+            .insert(key.to_owned(), value.to_owned());
+    }
+
+    pub fn remove_env(&self, key: &str) {
+        self.inner.env.lock().unwrap().remove(key);
+    }
+
+    /// Start supervising in the background. Returns immediately.
+    pub fn start(&self, app: AppHandle) {
+        let supervisor = self.clone();
+        tauri::async_runtime::spawn(async move {
+            supervisor.supervise(&app).await;
+            supervisor.inner.stopped.notify_waiters();
+        });
+    }
+
+    /// Ask the backend to exit cleanly (so its shutdown hook saves memory) and
+    /// wait for the supervision loop to finish.
+    pub async fn stop(&self) {
+        if matches!(
+            self.status(),
+            BackendStatus::Stopped | BackendStatus::Failed
+        ) {
+            return;
+        }
+        let stopped = self.inner.stopped.notified();
+        self.inner.stop.notify_one();
+        let _ = tokio::time::timeout(SHUTDOWN_GRACE * 2, stopped).await;
+    }
+
+    /// Gracefully restart the backend, or start supervising again if it had given up
+    pub fn restart(&self, app: AppHandle) {
+        match self.status() {
+            BackendStatus::Stopped | BackendStatus::Failed => self.start(app),
+            _ => self.inner.restart.notify_one(),
+        }
+    }
+
+    async fn supervise(&self, app: &AppHandle) {
+        let backend_dir = match find_backend_dir() {
+            Some(dir) => dir,
+            None => {
+                eprintln!("[backend] could not locate backend/main.py; set FRIDAY_BACKEND_DIR");
+                self.set_status(app, BackendStatus::Failed);
+                return;
+            }
+        };
+
+        let mut backoff = BACKOFF_INITIAL;
+        let mut restarts = 0;
+
+        loop {
+            self.set_status(
+                app,
+                if restarts == 0 {
+                    BackendStatus::Starting
+                } else {
+                    BackendStatus::Restarting
+                },
+            );
+
+            let mut child = match self.spawn(&backend_dir) {
+                Ok(child) => child,
+                Err(e) => {
+                    eprintln!("[backend] failed to spawn python: {e}");
+                    self.set_status(app, BackendStatus::Failed);
+                    return;
+                }
+            };
+            let started = Instant::now();
+
+            let ready = tokio::select! {
+                health = self.wait_until_ready() => match health {
+                    Some(health) => {
+                        *self.inner.health.lock().unwrap() = health;
+                        self.set_status(app, BackendStatus::Ready);
+                        true
+                    }
+                    None => {
+                        // A hung start counts as a crash so it ends in restart or Failed
+                        eprintln!("[backend] did not report healthy within {READY_TIMEOUT:?}");
+                        self.shutdown_child(&mut child).await;
+                        false
+                    }
+                },
+                exit = child.wait() => {
+                    eprintln!("[backend] exited before it was ready: {exit:?}");
+                    false
+                }
+                _ = self.inner.stop.notified() => {
+                    self.shutdown_child(&mut child).await;
+                    self.set_status(app, BackendStatus::Stopped);
+                    return;
+                }
+                _ = self.inner.restart.notified() => {
+                    self.shutdown_child(&mut child).await;
+                    (backoff, restarts) = (BACKOFF_INITIAL, 0);
+                    continue;
+                }
+            };
+
+            if ready {
+                tokio::select! {
+                    exit = child.wait() => {
+                        eprintln!("[backend] exited unexpectedly: {exit:?}");
+                    }
+                    _ = self.inner.stop.notified() => {
+                        self.shutdown_child(&mut child).await;
+                        self.set_status(app, BackendStatus::Stopped);
+                        return;
+                    }
+                    _ = self.inner.restart.notified() => {
+                        self.shutdown_child(&mut child).await;
+                        (backoff, restarts) = (BACKOFF_INITIAL, 0);
+                        continue;
+                    }
+                }
+            }
+
+            if ready && started.elapsed() >= STABLE_AFTER {
+                backoff = BACKOFF_INITIAL;
+                restarts = 0;
+            }
+            restarts += 1;
+            if restarts > MAX_RESTARTS {
+                eprintln!("[backend] giving up after {MAX_RESTARTS} restarts");
+                self.set_status(app, BackendStatus::Failed);
+                return;
+            }
+
+            self.set_status(app, BackendStatus::Restarting);
+            tokio::select! {
+                _ = tokio::time::sleep(backoff) => {}
+                _ = self.inner.stop.notified() => {
+                    self.set_status(app, BackendStatus::Stopped);
+                    return;
+                }
+            }
+            backoff = (backoff * 2).min(BACKOFF_MAX);
+        }
+    }
+
+    fn spawn(&self, backend_dir: &Path) -> std::io::Result<Child> {
+        let mut cmd = Command::new(python_executable(backend_dir));
+        cmd.arg("main.py")
+            .current_dir(backend_dir)
+            .env("PORT", self.inner.port.to_string())
+            // Uvicorn's reloader forks a worker we could not signal cleanly
+            .env("RELOAD", "false")
+            .env("PYTHONUNBUFFERED", "1")
+            .envs(self.inner.env.lock().unwrap().iter())
+            // Set last so nothing above can widen access
+            .env("SERVER_HOST", "127.0.0.1")
+            .env("API_KEY_REQUIRED", "true")
+            .env("API_KEY", &self.inner.token)
+            .stdin(Stdio::null())
+            .kill_on_drop(true);
+
+        #[cfg(windows)]
+        {
+            const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+            cmd.creation_flags(CREATE_NO_WINDOW);
+        }
+
+        cmd.spawn()
+    }
+
+    /// Poll `/health` until the server answers, returning the startup health report
+    async fn wait_until_ready(&self) -> Option<SystemHealth> {
+        #[derive(Deserialize)]
+        struct Health {
+            status: String,
+            system_status: Option<SystemHealth>,
+        }
+
+        let url = format!("{}/health", self.base_url());
+        let deadline = Instant::now() + READY_TIMEOUT;
+
+        while Instant::now() < deadline {
+            if let Ok(resp) = self.inner.http.get(&url).send().await {
+                if let Ok(health) = resp.json::<Health>().await {
+                    if health.status == "healthy" {
+                        return Some(health.system_status.unwrap_or(SystemHealth::Unknown));
+                    }
+                }
+            }
+            tokio::time::sleep(HEALTH_POLL_INTERVAL).await;
+        }
+        None
+    }
+
+    async fn shutdown_child(&self, child: &mut Child) {
+        let url = format!("{}/shutdown", self.base_url());
+        let requested = self
+            .inner
+            .http
+            .post(&url)
+            .timeout(Duration::from_secs(2))
+            .send()
+            .await
+            .is_ok();
+
+        if requested {
+            if let Ok(Ok(_)) = tokio::time::timeout(SHUTDOWN_GRACE, child.wait()).await {
+                return;
+            }
+            eprintln!("[backend] did not exit within {SHUTDOWN_GRACE:?}, killing");
+        }
+        let _ = child.kill().await;
+    }
+
+    fn set_status(&self, app: &AppHandle, status: BackendStatus) {
+        *self.inner.status.lock().unwrap() = status;
+        if status != BackendStatus::Ready {
+            *self.inner.health.lock().unwrap() = SystemHealth::Unknown;
+        }
+        let _ = app.emit_all("backend://status", status);
+    }
+}
+
+/// Pick a free loopback port for the backend, keeping 8000 when nothing else has it.
+/// The port is handed to the child through the `PORT` env var config.py reads.
+pub fn pick_port() -> u16 {
+    [DEFAULT_PORT, 0]
+        .into_iter()
+        .filter_map(|port| TcpListener::bind(("127.0.0.1", port)).ok())
+        .find_map(|listener| listener.local_addr().ok())
+        .map(|addr| addr.port())
+        .unwrap_or(DEFAULT_PORT)
+}
+
+/// Record the chosen port and launch token for `friday-cli` and other local tools
+pub fn publish(config: &Config, supervisor: &BackendSupervisor) {
+    let Some(dir) = tauri::api::path::app_local_data_dir(config) else {
+        return;
+    };
+    let written = std::fs::create_dir_all(&dir)
+        .and_then(|_| std::fs::write(dir.join(PORT_FILE), supervisor.port().to_string()))
+        .and_then(|_| write_private(&dir.join(TOKEN_FILE), &supervisor.inner.token));
+    if let Err(e) = written {
+        eprintln!(
+            "[backend] could not record port and token in {}: {e}",
+            dir.display()
+        );
+    }
+}
+
+/// 256 random bits, hex-encoded
+pub(crate) fn generate_token() -> String {
+    let mut bytes = [0u8; 32];
+    OsRng.fill_bytes(&mut bytes);
+    hex::encode(bytes)
+}
+
+fn authorized_client(token: &str) -> reqwest::Client {
+    let mut value =
+        HeaderValue::from_str(&format!("Bearer {token}")).expect("hex token is a valid header");
+    value.set_sensitive(true);
+    let mut headers = HeaderMap::new();
+    headers.insert(AUTHORIZATION, value);
+    reqwest::Client::builder()
+        .default_headers(headers)
+        .build()
+        .expect("failed to build the backend HTTP client")
+}
+
+/// Replace `path` with an owner-only file (Unix) holding `contents`. The old
+/// file is removed first so its permissions are not inherited.
+pub(crate) fn write_private(path: &Path, contents: &str) -> std::io::Result<()> {
+    match std::fs::remove_file(path) {
+        Err(e) if e.kind() != std::io::ErrorKind::NotFound => return Err(e),
+        _ => {}
+    }
+    let mut options = OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    options.open(path)?.write_all(contents.as_bytes())
+}
+
+/// Locate the directory holding `main.py`, honouring `FRIDAY_BACKEND_DIR`
+pub fn find_backend_dir() -> Option<PathBuf> {
+    if let Ok(dir) = std::env::var("FRIDAY_BACKEND_DIR") {
+        return Some(PathBuf::from(dir));
+    }
+
+    let mut roots = Vec::new();
+    if let Ok(cwd) = std::env::current_dir() {
+        roots.push(cwd);
+    }
+    if let Some(exe_dir) = std::env::current_exe()
+        .ok()
+        .and_then(|p| p.parent().map(Path::to_path_buf))
+    {
+        roots.push(exe_dir);
+    }
+
+    roots
+        .iter()
+        .flat_map(|root| root.ancestors())
+        .map(|dir| dir.join("backend"))
+        .find(|dir| dir.join("main.py").is_file())
+}
+
+/// The backend's data directory (`DATA_DIR` in config.py, relative to backend/)
+pub fn data_dir() -> Option<PathBuf> {
+    let backend_dir = find_backend_dir()?;
+    let dir = std::env::var("DATA_DIR").unwrap_or_else(|_| "../data".into());
+    Some(backend_dir.join(dir))
+}
+
+/// Honour `FRIDAY_PYTHON`, then the venv created by INSTALL-FRIDAY.ps1, then PATH
+fn python_executable(backend_dir: &Path) -> PathBuf {
+    if let Ok(python) = std::env::var("FRIDAY_PYTHON") {
+        return PathBuf::from(python);
+    }
+
+    let venv = if cfg!(windows) {
+        backend_dir.join("venv").join("Scripts").join("python.exe")
+    } else {
+        backend_dir.join("venv").join("bin").join("python")
+    };
+    if venv.is_file() {
+        return venv;
+    }
+
+    PathBuf::from(if cfg!(windows) { "python" } else { "python3" })
+}
+
+#[tauri::command]
+pub fn backend_url(supervisor: tauri::State<'_, BackendSupervisor>) -> String {
+    supervisor.base_url()
+}
+
+#[tauri::command]
+pub fn backend_status(supervisor: tauri::State<'_, BackendSupervisor>) -> BackendStatus {
+    supervisor.status()
+}
+
+#[tauri::command]
+pub fn backend_restart(app: AppHandle, supervisor: tauri::State<'_, BackendSupervisor>) {
+    // Launched by `unlock_data` once the data key is known
+    if crate::encryption::is_unlocked(&app) {
+        supervisor.restart(app);
+    }
+}
@@ -0,0 +1,731 @@
+// FRIDAY AI Assistant - Backups
+// Scheduled, rotating .tar.gz snapshots of the backend's data directory
+// (projects, learning logs, chroma vector stores) and the conversation store
+// (friday.db) in <app data dir>/backups. The backend is stopped while a
+// snapshot is taken or restored so chroma's files are consistent. Files are
+// archived as they are on disk and the store as a SQLCipher export, so both
+// stay encrypted; rotating the data key re-encrypts the snapshots too.
+
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime};
+
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tauri::{AppHandle, Manager, State};
+
+use crate::backend::{self, BackendStatus, BackendSupervisor};
+use crate::chat::ChatStreams;
+use crate::encryption::{self, DataKey, Encryption, ENCRYPTED_DIRS, FILE_MAGIC};
+use crate::store::{self, Store};
+
+const CONFIG_FILE: &str = "backup.json";
+const BACKUP_DIR: &str = "backups";
+const FILE_PREFIX: &str = "friday-data-";
+const FILE_SUFFIX: &str = ".tar.gz";
+const MANIFEST: &str = "manifest.json";
+/// Archive prefix for the data directory's contents
+const DATA_PREFIX: &str = "data/";
+/// Archive name of the conversation store's export
+const STORE_ENTRY: &str = "friday.db";
+const MANIFEST_FORMAT: &str = "friday-backup";
+const MANIFEST_VERSION: u32 = 2;
+const CHECK_INTERVAL: Duration = Duration::from_secs(15 * 60);
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BackupConfig {
+    pub enabled: bool,
+    pub interval_hours: u32,
+    /// Snapshots kept; older ones are deleted after each new snapshot
+    pub keep: usize,
+}
+
+impl Default for BackupConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            interval_hours: 24,
+            keep: 7,
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct Backups {
+    config: Mutex<BackupConfig>,
+    /// Held while a snapshot or restore is running
+    busy: tokio::sync::Mutex<()>,
+}
+
+impl Backups {
+    /// Hold off snapshots and restores, e.g. while the data key changes
+    pub async fn pause(&self) -> tokio::sync::MutexGuard<'_, ()> {
+        self.busy.lock().await
+    }
+}
+
+/// Stored inside every archive, after the data files
+#[derive(Debug, Serialize, Deserialize)]
+struct Manifest {
+    format: String,
+    version: u32,
+    created_at: String,
+    files: Vec<ManifestEntry>,
+    /// The conversation store; version 1 snapshots do not have one
+    #[serde(default)]
+    store: Option<ManifestEntry>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ManifestEntry {
+    /// Relative to the data directory, `/`-separated
+    path: String,
+    size: u64,
+    sha256: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SnapshotInfo {
+    pub path: PathBuf,
+    pub created_at: String,
+    /// Compressed size in bytes
+    pub size: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeKind {
+    /// Missing now; the restore brings it back
+    Added,
+    /// Differs from the snapshot; the restore overwrites it
+    Modified,
+    /// Not in the snapshot; the restore deletes it
+    Removed,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FileChange {
+    pub path: String,
+    pub change: ChangeKind,
+}
+
+/// What restoring a snapshot would do, after its checksums were verified
+#[derive(Debug, Clone, Serialize)]
+pub struct RestorePreview {
+    pub snapshot: PathBuf,
+    pub created_at: String,
+    pub files: usize,
+    pub unchanged: usize,
+    pub changes: Vec<FileChange>,
+}
+
+/// Load the schedule and start checking it. Called once from `setup`.
+pub fn start(app: &AppHandle) {
+    let config = config_path(app)
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default();
+    *app.state::<Backups>().config.lock().unwrap() = config;
+
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        loop {
+            tokio::time::sleep(CHECK_INTERVAL).await;
+
+            let config = app.state::<Backups>().config.lock().unwrap().clone();
+            // Stopping the backend would cut a reply off mid-stream
+            if !config.enabled
+                || !encryption::is_unlocked(&app)
+                || !app.state::<ChatStreams>().is_idle()
+            {
+                continue;
+            }
+            let due = match latest_snapshot_age(&app) {
+                Some(age) => age >= Duration::from_secs(u64::from(config.interval_hours) * 3600),
+                None => true,
+            };
+            if due {
+                if let Err(e) = snapshot(&app).await {
+                    eprintln!("[backup] scheduled snapshot failed: {e}");
+                }
+            }
+        }
+    });
+}
+
+fn config_path(app: &AppHandle) -> Option<PathBuf> {
+    app.path_resolver()
+        .app_config_dir()
+        .map(|dir| dir.join(CONFIG_FILE))
+}
+
+fn backup_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    app.path_resolver()
+        .app_data_dir()
+        .map(|dir| dir.join(BACKUP_DIR))
+        .ok_or_else(|| "app data directory is unavailable".into())
+}
+
+fn data_dir() -> Result<PathBuf, String> {
+    backend::data_dir().ok_or_else(|| "backend data directory not found".into())
+}
+
+/// Snapshots, newest first
+fn snapshots(app: &AppHandle) -> Result<Vec<SnapshotInfo>, String> {
+    let mut snapshots: Vec<SnapshotInfo> = fs::read_dir(backup_dir(app)?)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter_map(|entry| {
+            let name = entry.file_name().into_string().ok()?;
+            let stamp = name.strip_prefix(FILE_PREFIX)?.strip_suffix(FILE_SUFFIX)?;
+            let created_at = chrono::NaiveDateTime::parse_from_str(stamp, "%Y%m%d-%H%M%S").ok()?;
+            Some(SnapshotInfo {
+                path: entry.path(),
+                created_at: created_at.format("%Y-%m-%dT%H:%M:%S").to_string(),
+                size: entry.metadata().ok()?.len(),
+            })
+        })
+        .collect();
+    snapshots.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+    Ok(snapshots)
+}
+
+fn latest_snapshot_age(app: &AppHandle) -> Option<Duration> {
+    let latest = snapshots(app).ok()?.into_iter().next()?;
+    let modified = fs::metadata(latest.path).ok()?.modified().ok()?;
+    SystemTime::now().duration_since(modified).ok()
+}
+
+/// Run `task` with the backend stopped, restarting it afterwards if it was up
+async fn quiesced<T: Send + 'static>(
+    app: &AppHandle,
+    task: impl FnOnce() -> Result<T, String> + Send + 'static,
+) -> Result<T, String> {
+    let supervisor = app.state::<BackendSupervisor>().inner().clone();
+    let was_running = !matches!(
+        supervisor.status(),
+        BackendStatus::Stopped | BackendStatus::Failed
+    );
+    supervisor.stop().await;
+
+    let result = tauri::async_runtime::spawn_blocking(task)
+        .await
+        .map_err(|e| e.to_string())
+        .and_then(|result| result);
+
+    if was_running {
+        supervisor.start(app.clone());
+    }
+    result
+}
+
+async fn snapshot(app: &AppHandle) -> Result<SnapshotInfo, String> {
+    let backups = app.state::<Backups>();
+    let _busy = backups.busy.lock().await;
+
+    let key = app.state::<Encryption>().key().ok_or("data is locked")?;
+    let data_dir = data_dir()?;
+    let dir = backup_dir(app)?;
+    fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    let stamp = chrono::Local::now().format("%Y%m%d-%H%M%S");
+    let path = dir.join(format!("{FILE_PREFIX}{stamp}{FILE_SUFFIX}"));
+    let copy = dir.join(format!("{FILE_PREFIX}{stamp}.db.partial"));
+
+    let archive = path.clone();
+    let handle = app.clone();
+    quiesced(app, move || {
+        let written = store::managed(&handle)?
+            .export_copy(&copy, &key)
+            .map_err(|e| format!("could not copy the conversation store: {e}"))
+            .and_then(|_| write_archive(&data_dir, &copy, &archive));
+        let _ = fs::remove_file(&copy);
+        written
+    })
+    .await?;
+
+    let keep = backups.config.lock().unwrap().keep.max(1);
+    for old in snapshots(app)?.iter().skip(keep) {
+        if let Err(e) = fs::remove_file(&old.path) {
+            eprintln!("[backup] could not remove {}: {e}", old.path.display());
+        }
+    }
+
+    let info = snapshots(app)?
+        .into_iter()
+        .find(|snapshot| snapshot.path == path)
+        .ok_or("snapshot disappeared after writing")?;
+    let _ = app.emit_all("backup://created", &info);
+    Ok(info)
+}
+
+/// Every regular file under `dir` with its `/`-separated relative path
+fn data_files(dir: &Path) -> Vec<(String, PathBuf)> {
+    walkdir::WalkDir::new(dir)
+        .into_iter()
+        .flatten()
+        .filter(|entry| entry.file_type().is_file())
+        .filter_map(|entry| {
+            let relative = entry.path().strip_prefix(dir).ok()?;
+            let relative: Vec<_> = relative
+                .components()
+                .map(|part| part.as_os_str().to_string_lossy())
+                .collect();
+            Some((relative.join("/"), entry.into_path()))
+        })
+        .collect()
+}
+
+/// Feeds everything read through a SHA-256
+struct Hashing<R> {
+    inner: R,
+    hasher: Sha256,
+}
+
+impl<R: Read> Read for Hashing<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.hasher.update(&buf[..n]);
+        Ok(n)
+    }
+}
+
+fn sha256_file(path: &Path) -> io::Result<String> {
+    let mut hasher = Sha256::new();
+    io::copy(&mut File::open(path)?, &mut hasher)?;
+    Ok(hex::encode(hasher.finalize()))
+}
+
+/// Add `source` to the archive as `name`, returning its size and checksum
+fn append_file<W: Write>(
+    tar: &mut tar::Builder<W>,
+    name: &str,
+    source: &Path,
+) -> Result<(u64, String), String> {
+    let file = File::open(source).map_err(|e| format!("{}: {e}", source.display()))?;
+    let metadata = file.metadata().map_err(|e| e.to_string())?;
+    let mut header = tar::Header::new_gnu();
+    header.set_metadata(&metadata);
+    let mut reader = Hashing {
+        inner: file,
+        hasher: Sha256::new(),
+    };
+    tar.append_data(&mut header, name, &mut reader)
+        .map_err(|e| format!("{}: {e}", source.display()))?;
+    Ok((metadata.len(), hex::encode(reader.hasher.finalize())))
+}
+
+/// Archive the data directory and `store_copy`, an export of the store
+fn write_archive(data_dir: &Path, store_copy: &Path, path: &Path) -> Result<(), String> {
+    let mut partial = path.as_os_str().to_owned();
+    partial.push(".partial");
+
+    let file = File::create(&partial).map_err(|e| e.to_string())?;
+    let mut tar = tar::Builder::new(GzEncoder::new(file, Compression::default()));
+    let mut manifest = Manifest {
+        format: MANIFEST_FORMAT.into(),
+        version: MANIFEST_VERSION,
+        created_at: store::now(),
+        files: Vec::new(),
+        store: None,
+    };
+
+    for (relative, source) in data_files(data_dir) {
+        let (size, sha256) = append_file(&mut tar, &format!("{DATA_PREFIX}{relative}"), &source)?;
+        manifest.files.push(ManifestEntry {
+            path: relative,
+            size,
+            sha256,
+        });
+    }
+    let (size, sha256) = append_file(&mut tar, STORE_ENTRY, store_copy)?;
+    manifest.store = Some(ManifestEntry {
+        path: STORE_ENTRY.into(),
+        size,
+        sha256,
+    });
+
+    let json = serde_json::to_vec_pretty(&manifest).map_err(|e| e.to_string())?;
+    let mut header = tar::Header::new_gnu();
+    header.set_size(json.len() as u64);
+    header.set_mode(0o644);
+    tar.append_data(&mut header, MANIFEST, json.as_slice())
+        .map_err(|e| e.to_string())?;
+
+    tar.into_inner()
+        .and_then(|gz| gz.finish())
+        .and_then(|file| file.sync_all())
+        .and_then(|_| fs::rename(&partial, path))
+        .map_err(|e| e.to_string())
+}
+
+/// A snapshot from `list_backups`; restores never read archives from elsewhere
+fn find_snapshot(app: &AppHandle, path: &Path) -> Result<PathBuf, String> {
+    snapshots(app)?
+        .into_iter()
+        .map(|snapshot| snapshot.path)
+        .find(|snapshot| snapshot == path)
+        .ok_or_else(|| format!("{} is not a FRIDAY backup", path.display()))
+}
+
+/// Whether `relative` is a data file the backend encrypts with the data key
+fn is_encrypted(relative: &str) -> bool {
+    relative.split_once('/').is_some_and(|(dir, name)| {
+        ENCRYPTED_DIRS.contains(&dir) && !name.contains('/') && name.ends_with(".json")
+    })
+}
+
+/// Re-encrypt every snapshot's encrypted files under `to`, decrypting with
+/// whichever of `from` and `to` fits. Called while rotating the data key.
+pub fn rekey_snapshots(app: &AppHandle, from: &DataKey, to: &DataKey) -> Result<(), String> {
+    for snapshot in snapshots(app)? {
+        rekey_archive(&snapshot.path, &[from, to], to)
+            .map_err(|e| format!("could not re-encrypt {}: {e}", snapshot.path.display()))?;
+    }
+    Ok(())
+}
+
+fn rekey_archive(path: &Path, old: &[&DataKey], new: &DataKey) -> Result<(), String> {
+    let (mut manifest, _) = verify(path)?;
+    let mut partial = path.as_os_str().to_owned();
+    partial.push(".partial");
+
+    let source = File::open(path).map_err(|e| e.to_string())?;
+    let mut source = tar::Archive::new(GzDecoder::new(source));
+    let file = File::create(&partial).map_err(|e| e.to_string())?;
+    let mut tar = tar::Builder::new(GzEncoder::new(file, Compression::default()));
+    manifest.files.clear();
+    manifest.store = None;
+
+    for entry in source.entries().map_err(|e| e.to_string())? {
+        let mut entry = entry.map_err(|e| format!("corrupt archive: {e}"))?;
+        let name = entry
+            .path()
+            .map_err(|e| e.to_string())?
+            .to_string_lossy()
+            .into_owned();
+        if name == STORE_ENTRY {
+            let mut copy = partial.clone();
+            copy.push(".db");
+            let copy = PathBuf::from(copy);
+            let rekeyed = File::create(&copy)
+                .and_then(|mut file| io::copy(&mut entry, &mut file))
+                .map_err(|e| e.to_string())
+                .and_then(|_| store::rekey_copy(&copy, old, new))
+                .and_then(|_| append_file(&mut tar, STORE_ENTRY, &copy));
+            let _ = fs::remove_file(&copy);
+            let (size, sha256) = rekeyed?;
+            manifest.store = Some(ManifestEntry {
+                path: STORE_ENTRY.into(),
+                size,
+                sha256,
+            });
+            continue;
+        }
+        // The manifest is rewritten below with the new checksums
+        let Some(relative) = name.strip_prefix(DATA_PREFIX).map(str::to_owned) else {
+            continue;
+        };
+        let mut header = entry.header().clone();
+
+        let (size, sha256) = if is_encrypted(&relative) {
+            let mut data = Vec::new();
+            entry.read_to_end(&mut data).map_err(|e| e.to_string())?;
+            if data.starts_with(FILE_MAGIC) {
+                let plaintext = old
+                    .iter()
+                    .find_map(|key| key.decrypt(&data).ok())
+                    .ok_or_else(|| format!("{relative} was encrypted with a different key"))?;
+                data = new.encrypt(&plaintext);
+            }
+            header.set_size(data.len() as u64);
+            tar.append_data(&mut header, &name, data.as_slice())
+                .map_err(|e| e.to_string())?;
+            (data.len() as u64, hex::encode(Sha256::digest(&data)))
+        } else {
+            let size = header.size().map_err(|e| e.to_string())?;
+            let mut reader = Hashing {
+                inner: &mut entry,
+                hasher: Sha256::new(),
+            };
+            tar.append_data(&mut header, &name, &mut reader)
+                .map_err(|e| e.to_string())?;
+            (size, hex::encode(reader.hasher.finalize()))
+        };
+        manifest.files.push(ManifestEntry {
+            path: relative,
+            size,
+            sha256,
+        });
+    }
+
+    let json = serde_json::to_vec_pretty(&manifest).map_err(|e| e.to_string())?;
+    let mut header = tar::Header::new_gnu();
+    header.set_size(json.len() as u64);
+    header.set_mode(0o644);
+    tar.append_data(&mut header, MANIFEST, json.as_slice())
+        .map_err(|e| e.to_string())?;
+
+    tar.into_inner()
+        .and_then(|gz| gz.finish())
+        .and_then(|file| file.sync_all())
+        .and_then(|_| fs::rename(&partial, path))
+        .map_err(|e| e.to_string())
+}
+
+/// Read the whole archive, checking every file against the manifest.
+/// Returns the manifest and each file's checksum.
+fn verify(path: &Path) -> Result<(Manifest, BTreeMap<String, String>), String> {
+    let file = File::open(path).map_err(|e| e.to_string())?;
+    let mut archive = tar::Archive::new(GzDecoder::new(file));
+    let mut manifest: Option<Manifest> = None;
+    let mut checksums = BTreeMap::new();
+    let mut store_checksum = None;
+
+    for entry in archive.entries().map_err(|e| e.to_string())? {
+        let mut entry = entry.map_err(|e| format!("corrupt archive: {e}"))?;
+        let name = entry
+            .path()
+            .map_err(|e| e.to_string())?
+            .to_string_lossy()
+            .into_owned();
+        if name == MANIFEST {
+            manifest = Some(
+                serde_json::from_reader(&mut entry)
+                    .map_err(|e| format!("corrupt manifest: {e}"))?,
+            );
+        } else if name == STORE_ENTRY {
+            let mut hasher = Sha256::new();
+            io::copy(&mut entry, &mut hasher).map_err(|e| format!("corrupt archive: {e}"))?;
+            store_checksum = Some(hex::encode(hasher.finalize()));
+        } else if let Some(relative) = name.strip_prefix(DATA_PREFIX) {
+            let mut hasher = Sha256::new();
+            io::copy(&mut entry, &mut hasher).map_err(|e| format!("corrupt archive: {e}"))?;
+            checksums.insert(relative.to_owned(), hex::encode(hasher.finalize()));
+        }
+    }
+
+    let manifest = manifest.ok_or("not a FRIDAY backup: manifest missing")?;
+    if manifest.format != MANIFEST_FORMAT || manifest.version > MANIFEST_VERSION {
+        return Err(format!(
+            "unsupported backup {} v{}",
+            manifest.format, manifest.version
+        ));
+    }
+    for file in &manifest.files {
+        match checksums.get(&file.path) {
+            Some(sha256) if *sha256 == file.sha256 => {}
+            Some(_) => return Err(format!("checksum mismatch for {}", file.path)),
+            None => return Err(format!("{} is missing from the archive", file.path)),
+        }
+    }
+    if checksums.len() != manifest.files.len() {
+        return Err("archive holds files not listed in its manifest".into());
+    }
+    match (&manifest.store, &store_checksum) {
+        (Some(store), Some(sha256)) if store.sha256 == *sha256 => {}
+        (Some(_), Some(_)) => return Err(format!("checksum mismatch for {STORE_ENTRY}")),
+        (Some(_), None) => return Err(format!("{STORE_ENTRY} is missing from the archive")),
+        (None, Some(_)) => return Err("archive holds files not listed in its manifest".into()),
+        (None, None) => {}
+    }
+    Ok((manifest, checksums))
+}
+
+fn preview(path: &Path) -> Result<RestorePreview, String> {
+    let (manifest, snapshot) = verify(path)?;
+    let current: BTreeMap<String, PathBuf> = data_files(&data_dir()?).into_iter().collect();
+
+    let mut changes = Vec::new();
+    let mut unchanged = 0;
+    for (relative, sha256) in &snapshot {
+        let change = match current.get(relative) {
+            None => Some(ChangeKind::Added),
+            Some(file) => match sha256_file(file) {
+                Ok(current) if current == *sha256 => None,
+                _ => Some(ChangeKind::Modified),
+            },
+        };
+        match change {
+            Some(change) => changes.push(FileChange {
+                path: relative.clone(),
+                change,
+            }),
+            None => unchanged += 1,
+        }
+    }
+    changes.extend(
+        current
+            .keys()
+            .filter(|relative| !snapshot.contains_key(*relative))
+            .map(|relative| FileChange {
+                path: relative.clone(),
+                change: ChangeKind::Removed,
+            }),
+    );
+    // An export is encrypted afresh each time, so it cannot be compared
+    if manifest.store.is_some() {
+        changes.push(FileChange {
+            path: STORE_ENTRY.into(),
+            change: ChangeKind::Modified,
+        });
+    }
+    changes.sort_by(|a, b| a.path.cmp(&b.path));
+
+    Ok(RestorePreview {
+        snapshot: path.to_path_buf(),
+        created_at: manifest.created_at,
+        files: snapshot.len(),
+        unchanged,
+        changes,
+    })
+}
+
+/// Unpack next to the data directory, then swap it in and replace the store's
+/// contents with the snapshot's. The replaced directory is kept as
+/// `<data dir>.before-restore` and the store as `<data dir>.before-restore.db`
+/// until the next restore. Snapshots that do not open with `key` are refused.
+fn apply_restore(store: &Store, path: &Path, key: &DataKey) -> Result<(), String> {
+    let (manifest, _) = verify(path)?;
+    let data_dir = data_dir()?;
+    let parent = data_dir.parent().ok_or("data directory has no parent")?;
+    let name = data_dir
+        .file_name()
+        .ok_or("data directory has no name")?
+        .to_string_lossy()
+        .into_owned();
+    let staging = parent.join(format!(".{name}.restoring"));
+    let previous = parent.join(format!("{name}.before-restore"));
+    let previous_store = parent.join(format!("{name}.before-restore.db"));
+
+    let _ = fs::remove_dir_all(&staging);
+    fs::create_dir_all(&staging).map_err(|e| e.to_string())?;
+    let file = File::open(path).map_err(|e| e.to_string())?;
+    let mut archive = tar::Archive::new(GzDecoder::new(file));
+    for entry in archive.entries().map_err(|e| e.to_string())? {
+        let mut entry = entry.map_err(|e| e.to_string())?;
+        let wanted = entry
+            .path()
+            .map(|path| path.starts_with(DATA_PREFIX) || path == Path::new(STORE_ENTRY))
+            .unwrap_or(false);
+        if wanted {
+            // unpack_in refuses paths escaping the staging directory
+            entry.unpack_in(&staging).map_err(|e| e.to_string())?;
+        }
+    }
+    let restored = staging.join(DATA_PREFIX.trim_end_matches('/'));
+    fs::create_dir_all(&restored).map_err(|e| e.to_string())?;
+    if let Err(e) = encryption::check_backend_files(&restored, key) {
+        let _ = fs::remove_dir_all(&staging);
+        return Err(format!(
+            "this snapshot does not match the current data key and cannot be restored ({e})"
+        ));
+    }
+    // Opening also runs any migrations newer than the snapshot
+    let snapshot_store = match manifest.store {
+        Some(_) => match Store::open(&staging.join(STORE_ENTRY), key) {
+            Ok(snapshot_store) => Some(snapshot_store),
+            Err(e) => {
+                let _ = fs::remove_dir_all(&staging);
+                return Err(format!(
+                    "this snapshot's conversation store does not open with the current data key ({e})"
+                ));
+            }
+        },
+        None => None,
+    };
+
+    let _ = fs::remove_dir_all(&previous);
+    if data_dir.exists() {
+        fs::rename(&data_dir, &previous).map_err(|e| e.to_string())?;
+    }
+    if let Err(e) = fs::rename(&restored, &data_dir) {
+        let _ = fs::rename(&previous, &data_dir);
+        return Err(e.to_string());
+    }
+    if let Some(snapshot_store) = snapshot_store {
+        if let Err(e) = store.export_copy(&previous_store, key) {
+            eprintln!("[backup] could not keep a copy of the conversation store: {e}");
+        }
+        if let Err(e) = store.restore_from(&snapshot_store) {
+            let _ = fs::remove_dir_all(&data_dir);
+            let _ = fs::rename(&previous, &data_dir);
+            let _ = fs::remove_dir_all(&staging);
+            return Err(format!("could not restore the conversation store: {e}"));
+        }
+    }
+    let _ = fs::remove_dir_all(&staging);
+    Ok(())
+}
+
+#[tauri::command]
+pub fn get_backup_config(backups: State<'_, Backups>) -> BackupConfig {
+    backups.config.lock().unwrap().clone()
+}
+
+#[tauri::command]
+pub fn set_backup_config(
+    app: AppHandle,
+    backups: State<'_, Backups>,
+    config: BackupConfig,
+) -> Result<(), String> {
+    if config.interval_hours == 0 || config.keep == 0 {
+        return Err("interval_hours and keep must be at least 1".into());
+    }
+    let path = config_path(&app).ok_or("app config directory is unavailable")?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+    }
+    let json = serde_json::to_string_pretty(&config).map_err(|e| e.to_string())?;
+    fs::write(path, json).map_err(|e| e.to_string())?;
+
+    *backups.config.lock().unwrap() = config;
+    Ok(())
+}
+
+#[tauri::command]
+pub fn list_backups(app: AppHandle) -> Result<Vec<SnapshotInfo>, String> {
+    snapshots(&app)
+}
+
+/// Take a snapshot now, briefly stopping the backend
+#[tauri::command]
+pub async fn create_backup(app: AppHandle) -> Result<SnapshotInfo, String> {
+    snapshot(&app).await
+}
+
+/// Verify a snapshot's checksums and list what restoring it would change
+#[tauri::command]
+pub async fn preview_restore(app: AppHandle, path: PathBuf) -> Result<RestorePreview, String> {
+    let path = find_snapshot(&app, &path)?;
+    tauri::async_runtime::spawn_blocking(move || preview(&path))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+/// Replace the data directory with a snapshot, after verifying it again
+#[tauri::command]
+pub async fn restore_backup(
+    app: AppHandle,
+    backups: State<'_, Backups>,
+    path: PathBuf,
+) -> Result<(), String> {
+    let snapshot = find_snapshot(&app, &path)?;
+    let key = app.state::<Encryption>().key().ok_or("data is locked")?;
+    let _busy = backups.busy.lock().await;
+    let handle = app.clone();
+    quiesced(&app, move || {
+        let store = store::managed(&handle)?;
+        apply_restore(&store, &snapshot, &key)
+    })
+    .await?;
+    let _ = app.emit_all("backup://restored", &path);
+    Ok(())
+}
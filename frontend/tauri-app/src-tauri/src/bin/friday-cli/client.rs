@@ -0,0 +1,145 @@
+// FRIDAY CLI - Backend client
+// Finds the backend the desktop app is running (or `--url` / FRIDAY_URL) and
+// wraps its JSON and streaming endpoints.
+
+use std::path::PathBuf;
+
+use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION};
+use serde::Serialize;
+use serde_json::Value;
+
+/// Must match `identifier` in tauri.conf.json
+const APP_IDENTIFIER: &str = "com.dipesh.friday";
+/// Written by the desktop app at launch (see `backend::publish`)
+const PORT_FILE: &str = "backend.port";
+const TOKEN_FILE: &str = "backend.token";
+const DEFAULT_PORT: u16 = 8000;
+
+pub struct Client {
+    base_url: String,
+    http: reqwest::Client,
+}
+
+impl Client {
+    /// `--url`, then FRIDAY_URL, then the port the desktop app recorded, then 8000.
+    /// The access token is FRIDAY_TOKEN or the one the desktop app recorded.
+    pub fn discover(url: Option<String>) -> Self {
+        let base_url = url
+            .or_else(|| std::env::var("FRIDAY_URL").ok())
+            .unwrap_or_else(|| {
+                let port = read_data_file(PORT_FILE)
+                    .and_then(|port| port.parse().ok())
+                    .unwrap_or(DEFAULT_PORT);
+                format!("http://127.0.0.1:{port}")
+            });
+        let token = std::env::var("FRIDAY_TOKEN")
+            .ok()
+            .or_else(|| read_data_file(TOKEN_FILE));
+
+        let mut headers = HeaderMap::new();
+        if let Some(Ok(mut value)) =
+            token.map(|token| HeaderValue::from_str(&format!("Bearer {token}")))
+        {
+            value.set_sensitive(true);
+            headers.insert(AUTHORIZATION, value);
+        }
+        let http = reqwest::Client::builder()
+            .default_headers(headers)
+            .build()
+            .unwrap_or_default();
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            http,
+        }
+    }
+
+    pub async fn get(&self, path: &str, query: &[(&str, String)]) -> Result<Value, String> {
+        let request = self.http.get(self.url(path)).query(query);
+        self.json(request).await
+    }
+
+    pub async fn post(&self, path: &str, body: &impl Serialize) -> Result<Value, String> {
+        let request = self.http.post(self.url(path)).json(body);
+        self.json(request).await
+    }
+
+    /// POST with query parameters only, for endpoints that take no body
+    pub async fn post_query(&self, path: &str, query: &[(&str, String)]) -> Result<Value, String> {
+        let request = self.http.post(self.url(path)).query(query);
+        self.json(request).await
+    }
+
+    /// Open an SSE stream; the caller reads chunks off the response
+    pub async fn stream(
+        &self,
+        path: &str,
+        body: &impl Serialize,
+    ) -> Result<reqwest::Response, String> {
+        let response = self
+            .http
+            .post(self.url(path))
+            .json(body)
+            .send()
+            .await
+            .map_err(|e| self.unreachable(e))?;
+        check(response).await
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}{path}", self.base_url)
+    }
+
+    async fn json(&self, request: reqwest::RequestBuilder) -> Result<Value, String> {
+        let response = request.send().await.map_err(|e| self.unreachable(e))?;
+        check(response)
+            .await?
+            .json()
+            .await
+            .map_err(|e| format!("unexpected response: {e}"))
+    }
+
+    fn unreachable(&self, e: reqwest::Error) -> String {
+        if e.is_connect() {
+            format!(
+                "cannot reach the FRIDAY backend at {} - is FRIDAY running?",
+                self.base_url
+            )
+        } else {
+            e.to_string()
+        }
+    }
+}
+
+/// Turn HTTP errors into the backend's `detail` message where there is one
+async fn check(response: reqwest::Response) -> Result<reqwest::Response, String> {
+    let status = response.status();
+    if status.is_success() {
+        return Ok(response);
+    }
+    let body: Value = response.json().await.unwrap_or_default();
+    let detail = match &body["detail"] {
+        Value::String(detail) => detail.clone(),
+        Value::Null => status.to_string(),
+        other => other.to_string(),
+    };
+    Err(format!("backend error ({}): {detail}", status.as_u16()))
+}
+
+fn read_data_file(name: &str) -> Option<String> {
+    let contents = std::fs::read_to_string(app_data_dir()?.join(name)).ok()?;
+    Some(contents.trim().to_string())
+}
+
+/// Same location as Tauri's app local data dir. Resolved by hand so the CLI
+/// does not link the webview stack.
+fn app_data_dir() -> Option<PathBuf> {
+    let env = |name: &str| std::env::var_os(name).map(PathBuf::from);
+    let local_data = if cfg!(windows) {
+        env("LOCALAPPDATA")
+    } else if cfg!(target_os = "macos") {
+        env("HOME").map(|home| home.join("Library").join("Application Support"))
+    } else {
+        env("XDG_DATA_HOME").or_else(|| env("HOME").map(|home| home.join(".local").join("share")))
+    };
+    local_data.map(|dir| dir.join(APP_IDENTIFIER))
+}
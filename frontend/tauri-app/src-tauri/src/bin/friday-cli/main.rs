@@ -0,0 +1,683 @@
+// FRIDAY CLI - Headless command-line client
+// Talks to the same backend as the desktop app: streams chat replies to
+// stdout (piped stdin becomes context), lists and resumes conversations, and
+// runs routines, timers and todos. `--json` switches every command to
+// machine-readable output; chat then prints one StreamChunk per line.
+
+// Shared with the desktop app; the CLI does not need every helper
+#[allow(dead_code)]
+#[path = "../../stream.rs"]
+mod stream;
+
+mod client;
+
+use std::io::{IsTerminal, Read, Write};
+use std::process::ExitCode;
+
+use serde_json::{json, Value};
+
+use client::Client;
+use stream::{SseDecoder, StreamChunk};
+
+const USAGE: &str = "\
+Usage: friday-cli [--json] [--url <backend url>] <command>
+
+Chat:
+  ask [-c <conversation id>] [-p <project id>] <message...>
+                                  Stream a reply; piped stdin is added as context
+  resume <conversation id> <message...>
+  conversations [-n <limit>] [-p <project id>]
+  show <conversation id>
+
+Routines, timers and todos:
+  routines                        List routines
+  routine <name>                  Run a routine
+  timers                          List active timers
+  timer set <duration> [--name <name>]   e.g. 90, 45s, 5m, 1h30m
+  timer cancel [<timer id>]
+  todos [-l <list>]
+  todo add <item...> [-l <list>]
+  todo done <item...> [-l <list>]
+
+The backend is found through --url, FRIDAY_URL, or the port the running
+desktop app recorded. Requests carry FRIDAY_TOKEN, or the access token the
+desktop app recorded for this launch.";
+
+struct Options {
+    json: bool,
+    url: Option<String>,
+}
+
+#[derive(Debug, PartialEq)]
+enum Command {
+    Ask {
+        message: Option<String>,
+        conversation: Option<String>,
+        project: Option<String>,
+    },
+    Conversations {
+        limit: u32,
+        project: Option<String>,
+    },
+    Show {
+        id: String,
+    },
+    Routines,
+    Routine {
+        name: String,
+    },
+    Timers,
+    TimerSet {
+        seconds: u64,
+        name: Option<String>,
+    },
+    TimerCancel {
+        id: Option<String>,
+    },
+    Todos {
+        list: String,
+    },
+    TodoAdd {
+        item: String,
+        list: String,
+    },
+    TodoDone {
+        item: String,
+        list: String,
+    },
+}
+
+/// Positional arguments plus the value of every `-x <value>` flag
+struct Args {
+    positional: Vec<String>,
+    flags: Vec<(String, String)>,
+}
+
+impl Args {
+    fn flag(&self, names: &[&str]) -> Option<String> {
+        self.flags
+            .iter()
+            .rev()
+            .find(|(name, _)| names.contains(&name.as_str()))
+            .map(|(_, value)| value.clone())
+    }
+
+    fn rest(&self, from: usize) -> Option<String> {
+        let words = self.positional.get(from..).unwrap_or_default();
+        (!words.is_empty()).then(|| words.join(" "))
+    }
+}
+
+fn main() -> ExitCode {
+    let (options, command) = match parse(std::env::args().skip(1).collect()) {
+        Ok(Some(parsed)) => parsed,
+        Ok(None) => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Err(e) => {
+            eprintln!("friday-cli: {e}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    let runtime = match tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+    {
+        Ok(runtime) => runtime,
+        Err(e) => {
+            eprintln!("friday-cli: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let client = Client::discover(options.url.clone());
+    match runtime.block_on(run(&client, &options, command)) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("friday-cli: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn parse(raw: Vec<String>) -> Result<Option<(Options, Command)>, String> {
+    let mut options = Options {
+        json: false,
+        url: None,
+    };
+    let mut args = Args {
+        positional: Vec::new(),
+        flags: Vec::new(),
+    };
+
+    let mut iter = raw.into_iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(None),
+            "--json" => options.json = true,
+            "--url" => options.url = Some(iter.next().ok_or("--url needs a value")?),
+            "--" => args.positional.extend(iter.by_ref()),
+            flag if flag.starts_with('-') && flag.len() > 1 => {
+                let value = iter.next().ok_or(format!("{flag} needs a value"))?;
+                args.flags.push((flag.to_string(), value));
+            }
+            _ => args.positional.push(arg),
+        }
+    }
+
+    let Some(name) = args.positional.first().cloned() else {
+        return Ok(None);
+    };
+    let arg = |index: usize, what: &str| {
+        args.positional
+            .get(index)
+            .cloned()
+            .ok_or(format!("{name} needs {what}"))
+    };
+    let list = || args.flag(&["-l", "--list"]).unwrap_or("default".into());
+
+    let command = match name.as_str() {
+        "ask" => Command::Ask {
+            message: args.rest(1),
+            conversation: args.flag(&["-c", "--conversation"]),
+            project: args.flag(&["-p", "--project"]),
+        },
+        "resume" => Command::Ask {
+            conversation: Some(arg(1, "a conversation id")?),
+            message: args.rest(2),
+            project: None,
+        },
+        "conversations" => Command::Conversations {
+            limit: match args.flag(&["-n", "--limit"]) {
+                Some(limit) => limit.parse().map_err(|_| format!("bad limit {limit:?}"))?,
+                None => 20,
+            },
+            project: args.flag(&["-p", "--project"]),
+        },
+        "show" => Command::Show {
+            id: arg(1, "a conversation id")?,
+        },
+        "routines" => Command::Routines,
+        "routine" => Command::Routine {
+            name: args.rest(1).ok_or("routine needs a name")?,
+        },
+        "timers" => Command::Timers,
+        "timer" => match arg(1, "set or cancel")?.as_str() {
+            "set" => Command::TimerSet {
+                seconds: parse_duration(&arg(2, "a duration")?)?,
+                name: args.flag(&["--name"]),
+            },
+            "cancel" => Command::TimerCancel {
+                id: args.positional.get(2).cloned(),
+            },
+            other => return Err(format!("unknown timer command {other:?}")),
+        },
+        "todos" => Command::Todos { list: list() },
+        "todo" => {
+            let item = args.rest(2).ok_or("todo needs an item")?;
+            match arg(1, "add or done")?.as_str() {
+                "add" => Command::TodoAdd { item, list: list() },
+                "done" => Command::TodoDone { item, list: list() },
+                other => return Err(format!("unknown todo command {other:?}")),
+            }
+        }
+        other => return Err(format!("unknown command {other:?}")),
+    };
+    Ok(Some((options, command)))
+}
+
+/// `90`, `45s`, `5m`, `1h30m`
+fn parse_duration(text: &str) -> Result<u64, String> {
+    let bad = || format!("bad duration {text:?}");
+    if let Ok(seconds) = text.parse::<u64>() {
+        return Ok(seconds);
+    }
+
+    let (mut total, mut number) = (0u64, String::new());
+    for c in text.chars() {
+        if c.is_ascii_digit() {
+            number.push(c);
+            continue;
+        }
+        let unit = match c {
+            'h' => 3600,
+            'm' => 60,
+            's' => 1,
+            _ => return Err(bad()),
+        };
+        let value: u64 = number.parse().map_err(|_| bad())?;
+        total = value
+            .checked_mul(unit)
+            .and_then(|seconds| total.checked_add(seconds))
+            .ok_or_else(bad)?;
+        number.clear();
+    }
+    if !number.is_empty() || total == 0 {
+        return Err(bad());
+    }
+    Ok(total)
+}
+
+async fn run(client: &Client, options: &Options, command: Command) -> Result<(), String> {
+    match command {
+        Command::Ask {
+            message,
+            conversation,
+            project,
+        } => {
+            let message = with_stdin_context(message)?;
+            ask(client, options, message, conversation, project).await
+        }
+        Command::Conversations { limit, project } => {
+            let mut query = vec![("limit", limit.to_string())];
+            query.extend(project.map(|id| ("project_id", id)));
+            let conversations = client.get("/api/chat/conversations", &query).await?;
+            print_or(options, &conversations, print_conversations)
+        }
+        Command::Show { id } => {
+            let conversation = client
+                .get(&format!("/api/chat/conversations/{id}"), &[])
+                .await?;
+            print_or(options, &conversation, print_messages)
+        }
+        Command::Routines => {
+            let routines = client.get("/api/alexa/routines", &[]).await?;
+            print_or(options, &routines, print_routines)
+        }
+        Command::Routine { name } => {
+            let result = client
+                .post("/api/alexa/routine/run", &json!({ "routine_name": name }))
+                .await?;
+            print_or(options, &result, print_routine_result)?;
+            succeeded(&result)
+        }
+        Command::Timers => {
+            let timers = client.get("/api/alexa/timers", &[]).await?;
+            print_or(options, &timers, print_timers)
+        }
+        Command::TimerSet { seconds, name } => {
+            let body = json!({ "duration": seconds, "name": name.unwrap_or("Timer".into()) });
+            let result = client.post("/api/alexa/timer/set", &body).await?;
+            print_or(options, &result, print_message)?;
+            succeeded(&result)
+        }
+        Command::TimerCancel { id } => {
+            let query: Vec<_> = id.map(|id| ("timer_id", id)).into_iter().collect();
+            let result = client.post_query("/api/alexa/timer/cancel", &query).await?;
+            print_or(options, &result, print_message)?;
+            succeeded(&result)
+        }
+        Command::Todos { list } => {
+            let todos = client
+                .get("/api/alexa/todos", &[("list_name", list)])
+                .await?;
+            print_or(options, &todos, print_todos)
+        }
+        Command::TodoAdd { item, list } => {
+            let body = json!({ "item": item, "list_name": list });
+            let result = client.post("/api/alexa/todo/add", &body).await?;
+            print_or(options, &result, print_message)?;
+            succeeded(&result)
+        }
+        Command::TodoDone { item, list } => {
+            let body = json!({ "item_text": item, "list_name": list });
+            let result = client.post("/api/alexa/todo/complete", &body).await?;
+            print_or(options, &result, print_message)?;
+            succeeded(&result)
+        }
+    }
+}
+
+/// Piped stdin becomes context for the message, or the message itself
+fn with_stdin_context(message: Option<String>) -> Result<String, String> {
+    let mut context = String::new();
+    let stdin = std::io::stdin();
+    if !stdin.is_terminal() {
+        stdin
+            .lock()
+            .read_to_string(&mut context)
+            .map_err(|e| format!("could not read stdin: {e}"))?;
+    }
+    let context = context.trim();
+
+    match (message, context.is_empty()) {
+        (Some(message), true) => Ok(message),
+        (Some(message), false) => Ok(format!("{message}\n\nContext:\n```\n{context}\n```")),
+        (None, false) => Ok(context.to_string()),
+        (None, true) => Err("nothing to ask - pass a message or pipe text in".into()),
+    }
+}
+
+async fn ask(
+    client: &Client,
+    options: &Options,
+    message: String,
+    conversation: Option<String>,
+    project: Option<String>,
+) -> Result<(), String> {
+    let body = json!({
+        "message": message,
+        "conversation_id": conversation,
+        "project_id": project,
+    });
+    let mut response = client.stream("/api/chat/stream", &body).await?;
+
+    let mut stdout = std::io::stdout().lock();
+    let mut failed = None;
+    let mut emit = |chunk: StreamChunk| {
+        if options.json {
+            if let Ok(line) = serde_json::to_string(&chunk) {
+                let _ = writeln!(stdout, "{line}");
+            }
+        } else {
+            match &chunk {
+                StreamChunk::Token {
+                    content: Some(text),
+                } => {
+                    let _ = write!(stdout, "{text}");
+                }
+                StreamChunk::ToolCall { data: Some(data) } => {
+                    let tool = data["name"].as_str().unwrap_or("tool");
+                    eprintln!("\n[running {tool}]");
+                }
+                _ => {}
+            }
+        }
+        let _ = stdout.flush();
+        if let StreamChunk::Error { content } = chunk {
+            failed = Some(content.unwrap_or("chat failed".into()));
+        }
+    };
+
+    let conversation_id = response
+        .headers()
+        .get("x-conversation-id")
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string);
+    if let Some(id) = &conversation_id {
+        emit(StreamChunk::ConversationId {
+            id: Some(id.clone()),
+        });
+    }
+
+    let mut decoder = SseDecoder::default();
+    loop {
+        match response.chunk().await {
+            Ok(Some(bytes)) => decoder.push(&bytes).into_iter().for_each(&mut emit),
+            Ok(None) => break,
+            Err(e) => {
+                emit(StreamChunk::Error {
+                    content: Some(format!("chat stream interrupted: {e}")),
+                });
+                break;
+            }
+        }
+    }
+    decoder.finish().into_iter().for_each(&mut emit);
+
+    if !options.json {
+        println!();
+        if let Some(id) = conversation_id {
+            eprintln!("conversation: {id}");
+        }
+    }
+    match failed {
+        Some(e) => Err(e),
+        None => Ok(()),
+    }
+}
+
+/// Pretty JSON with `--json`, otherwise the command's plain-text view
+fn print_or(options: &Options, value: &Value, plain: fn(&Value)) -> Result<(), String> {
+    if options.json {
+        let text = serde_json::to_string_pretty(value).map_err(|e| e.to_string())?;
+        println!("{text}");
+    } else {
+        plain(value);
+    }
+    Ok(())
+}
+
+/// The alexa endpoints report failures as `success: false` with a message
+fn succeeded(result: &Value) -> Result<(), String> {
+    if result["success"] == false {
+        let message = result["message"].as_str().unwrap_or("request failed");
+        return Err(message.to_string());
+    }
+    Ok(())
+}
+
+fn text<'a>(value: &'a Value, key: &str) -> &'a str {
+    value[key].as_str().unwrap_or_default()
+}
+
+fn print_message(result: &Value) {
+    if result["success"] != false {
+        println!("{}", text(result, "message"));
+    }
+}
+
+fn print_conversations(conversations: &Value) {
+    let list = conversations.as_array().cloned().unwrap_or_default();
+    if list.is_empty() {
+        println!("No conversations");
+    }
+    for conversation in list {
+        let updated = text(&conversation, "updated_at");
+        let name = conversation["name"].as_str().unwrap_or("(untitled)");
+        let count = conversation["messages"].as_array().map_or(0, Vec::len);
+        println!(
+            "{}  {}  {name} ({count} messages)",
+            text(&conversation, "id"),
+            updated.get(..16).unwrap_or(updated).replace('T', " "),
+        );
+    }
+}
+
+fn print_messages(conversation: &Value) {
+    if let Some(name) = conversation["name"].as_str() {
+        println!("# {name}\n");
+    }
+    for message in conversation["messages"].as_array().into_iter().flatten() {
+        println!(
+            "{}:\n{}\n",
+            text(message, "role"),
+            text(message, "content").trim()
+        );
+    }
+}
+
+fn print_routines(routines: &Value) {
+    let Some(routines) = routines["routines"].as_object() else {
+        println!("No routines");
+        return;
+    };
+    for (name, routine) in routines {
+        let actions = routine["actions"].as_array().map_or(0, Vec::len);
+        let disabled = if routine["enabled"] == false {
+            " (disabled)"
+        } else {
+            ""
+        };
+        println!("{name}{disabled}: {actions} actions");
+    }
+}
+
+fn print_routine_result(result: &Value) {
+    if result["success"] == false {
+        return;
+    }
+    println!(
+        "Ran {} ({} actions)",
+        text(result, "routine"),
+        result["actions_executed"]
+    );
+    for action in result["results"].as_array().into_iter().flatten() {
+        if let Some(said) = action["text"].as_str() {
+            println!("  {said}");
+        } else if let Some(message) = action["message"].as_str() {
+            println!("  {message}");
+        }
+    }
+}
+
+fn print_timers(timers: &Value) {
+    let list = timers["timers"].as_array().cloned().unwrap_or_default();
+    if list.is_empty() {
+        println!("No active timers");
+    }
+    for timer in list {
+        println!(
+            "{}  {}  {} left",
+            text(&timer, "id"),
+            text(&timer, "name"),
+            text(&timer, "remaining_text")
+        );
+    }
+}
+
+fn print_todos(todos: &Value) {
+    let items = todos["items"].as_array().cloned().unwrap_or_default();
+    if items.is_empty() {
+        println!("Nothing to do");
+    }
+    for item in items {
+        println!("- {}", text(&item, "text"));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn command(args: &[&str]) -> Result<Command, String> {
+        let raw = args.iter().map(|arg| arg.to_string()).collect();
+        Ok(parse(raw)?.expect("a command").1)
+    }
+
+    #[test]
+    fn durations() {
+        assert_eq!(parse_duration("90"), Ok(90));
+        assert_eq!(parse_duration("45s"), Ok(45));
+        assert_eq!(parse_duration("5m"), Ok(300));
+        assert_eq!(parse_duration("1h30m"), Ok(5400));
+        assert_eq!(parse_duration("2m2m"), Ok(240));
+        assert_eq!(parse_duration(&u64::MAX.to_string()), Ok(u64::MAX));
+    }
+
+    #[test]
+    fn bad_durations() {
+        for text in ["", "m", "5x", "0m", "1h30", "-5s", "1.5m", " 5m"] {
+            assert!(parse_duration(text).is_err(), "{text:?} was accepted");
+        }
+        assert!(parse_duration("99999999999999999999s").is_err());
+    }
+
+    #[test]
+    fn overflowing_durations_are_rejected() {
+        let hours = u64::MAX / 3600 + 1;
+        assert!(parse_duration(&format!("{hours}h")).is_err());
+        assert!(parse_duration(&format!("{}s1s", u64::MAX)).is_err());
+        assert!(parse_duration(&format!("{}m", u64::MAX / 60)).is_ok());
+    }
+
+    #[test]
+    fn help_and_no_command() {
+        assert!(parse(vec![]).unwrap().is_none());
+        assert!(parse(vec!["--help".into()]).unwrap().is_none());
+        assert!(parse(vec!["--json".into(), "-h".into(), "ask".into()])
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn global_options() {
+        let raw = ["--json", "--url", "http://127.0.0.1:9", "routines"];
+        let (options, command) = parse(raw.map(String::from).to_vec()).unwrap().unwrap();
+        assert!(options.json);
+        assert_eq!(options.url.as_deref(), Some("http://127.0.0.1:9"));
+        assert_eq!(command, Command::Routines);
+        assert!(parse(vec!["--url".into()]).is_err());
+    }
+
+    #[test]
+    fn chat_commands() {
+        assert_eq!(
+            command(&["ask", "-c", "c1", "what", "time", "is", "it"]),
+            Ok(Command::Ask {
+                message: Some("what time is it".into()),
+                conversation: Some("c1".into()),
+                project: None,
+            })
+        );
+        assert_eq!(
+            command(&["ask"]),
+            Ok(Command::Ask {
+                message: None,
+                conversation: None,
+                project: None,
+            })
+        );
+        assert_eq!(
+            command(&["resume", "c1", "go", "on"]),
+            Ok(Command::Ask {
+                message: Some("go on".into()),
+                conversation: Some("c1".into()),
+                project: None,
+            })
+        );
+        assert_eq!(
+            command(&["ask", "--", "-n", "is", "a", "flag"]),
+            Ok(Command::Ask {
+                message: Some("-n is a flag".into()),
+                conversation: None,
+                project: None,
+            })
+        );
+        assert_eq!(
+            command(&["conversations", "-n", "5", "--project", "p1"]),
+            Ok(Command::Conversations {
+                limit: 5,
+                project: Some("p1".into()),
+            })
+        );
+        assert!(command(&["conversations", "-n", "many"]).is_err());
+        assert!(command(&["resume"]).is_err());
+        assert!(command(&["show"]).is_err());
+    }
+
+    #[test]
+    fn timer_and_todo_commands() {
+        assert_eq!(
+            command(&["timer", "set", "1h30m", "--name", "tea"]),
+            Ok(Command::TimerSet {
+                seconds: 5400,
+                name: Some("tea".into()),
+            })
+        );
+        assert_eq!(
+            command(&["timer", "cancel"]),
+            Ok(Command::TimerCancel { id: None })
+        );
+        assert!(command(&["timer", "set", "soon"]).is_err());
+        assert!(command(&["timer", "pause"]).is_err());
+        assert_eq!(
+            command(&["todo", "add", "buy", "milk", "-l", "shopping"]),
+            Ok(Command::TodoAdd {
+                item: "buy milk".into(),
+                list: "shopping".into(),
+            })
+        );
+        assert_eq!(
+            command(&["todos"]),
+            Ok(Command::Todos {
+                list: "default".into()
+            })
+        );
+        assert!(command(&["todo", "add"]).is_err());
+        assert!(command(&["todo", "drop", "milk"]).is_err());
+        assert!(command(&["routine"]).is_err());
+        assert!(command(&["dance"]).is_err());
+    }
+}
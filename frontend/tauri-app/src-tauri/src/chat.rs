@@ -0,0 +1,203 @@
+// FRIDAY AI Assistant - Native chat streaming
+// Opens the backend chat stream and re-emits every StreamChunk as a
+// `chat://<type>` event tagged with the caller's request id.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use tauri::{AppHandle, Manager, State};
+use tokio::sync::Notify;
+
+use crate::backend::BackendSupervisor;
+use crate::store;
+use crate::stream::{SseDecoder, StreamChunk, TextDecoder};
+
+/// Which backend chat endpoint to stream from
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChatEndpoint {
+    /// `/api/chat/stream` with a `ChatRequest` body
+    #[default]
+    V1,
+    /// `/api/chat/v2/stream` with a `ChatV2Request` body
+    V2,
+    /// `/api/llm/local_stream` with a `LocalChatRequest` body; replies in
+    /// plain text rather than server-sent events
+    Local,
+}
+
+impl ChatEndpoint {
+    fn path(self) -> &'static str {
+        match self {
+            ChatEndpoint::V1 => "/api/chat/stream",
+            ChatEndpoint::V2 => "/api/chat/v2/stream",
+            ChatEndpoint::Local => "/api/llm/local_stream",
+        }
+    }
+}
+
+/// In-flight chat streams by request id, so they can be cancelled
+#[derive(Default)]
+pub struct ChatStreams {
+    active: Mutex<HashMap<String, Arc<Notify>>>,
+}
+
+impl ChatStreams {
+    pub fn is_idle(&self) -> bool {
+        self.active.lock().unwrap().is_empty()
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ChatOutcome {
+    pub conversation_id: Option<String>,
+    pub cancelled: bool,
+}
+
+#[derive(Clone, Serialize)]
+struct ChatEvent<'a> {
+    request_id: &'a str,
+    #[serde(flatten)]
+    chunk: &'a StreamChunk,
+}
+
+/// Stream a chat reply, emitting `chat://token`, `chat://tool_call`,
+/// `chat://tool_result`, `chat://error` and `chat://done` as chunks arrive.
+/// Resolves once the stream ends or is cancelled with `chat_cancel`.
+#[tauri::command]
+pub async fn chat_stream(
+    app: AppHandle,
+    backend: State<'_, BackendSupervisor>,
+    streams: State<'_, ChatStreams>,
+    request_id: String,
+    endpoint: Option<ChatEndpoint>,
+    body: Value,
+) -> Result<ChatOutcome, String> {
+    let cancel = Arc::new(Notify::new());
+    {
+        let mut active = streams.active.lock().unwrap();
+        if active.contains_key(&request_id) {
+            return Err(format!("chat request {request_id} is already streaming"));
+        }
+        active.insert(request_id.clone(), cancel.clone());
+    }
+
+    let endpoint = endpoint.unwrap_or_default();
+    let url = format!("{}{}", backend.base_url(), endpoint.path());
+    let result = tokio::select! {
+        result = pump(&app, backend.client(), endpoint, &url, &body, &request_id) => {
+            result.map(|conversation_id| ChatOutcome { conversation_id, cancelled: false })
+        }
+        _ = cancel.notified() => {
+            // Dropping the response closes the connection and stops generation
+            let _ = app.emit_all("chat://cancelled", json!({ "request_id": request_id }));
+            Ok(ChatOutcome { conversation_id: None, cancelled: true })
+        }
+    };
+
+    streams.active.lock().unwrap().remove(&request_id);
+
+    // The backend has saved the new messages; bring the local store up to date
+    // before returning, since the UI reloads its list from the store
+    if let Ok(ChatOutcome {
+        conversation_id: Some(id),
+        ..
+    }) = &result
+    {
+        if let Err(e) = store::sync_from_backend(&app, id).await {
+            eprintln!("[chat] could not sync conversation {id}: {e}");
+        }
+    }
+    result
+}
+
+/// Cancel an in-flight `chat_stream`. Returns false if it already finished.
+#[tauri::command]
+pub fn chat_cancel(streams: State<'_, ChatStreams>, request_id: String) -> bool {
+    match streams.active.lock().unwrap().get(&request_id) {
+        Some(cancel) => {
+            cancel.notify_one();
+            true
+        }
+        None => false,
+    }
+}
+
+async fn pump(
+    app: &AppHandle,
+    client: &reqwest::Client,
+    endpoint: ChatEndpoint,
+    url: &str,
+    body: &Value,
+    request_id: &str,
+) -> Result<Option<String>, String> {
+    let emit = |chunk: &StreamChunk| {
+        let _ = app.emit_all(
+            &format!("chat://{}", chunk.kind()),
+            ChatEvent { request_id, chunk },
+        );
+    };
+
+    let mut response = client
+        .post(url)
+        .json(body)
+        .send()
+        .await
+        .and_then(|r| r.error_for_status())
+        .map_err(|e| {
+            let message = format!("chat stream failed: {e}");
+            emit(&StreamChunk::Error {
+                content: Some(message.clone()),
+            });
+            emit(&StreamChunk::Done);
+            message
+        })?;
+
+    let mut conversation_id = response
+        .headers()
+        .get("x-conversation-id")
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string);
+
+    let mut decoder = SseDecoder::default();
+    let mut text = TextDecoder::default();
+    let plain = matches!(endpoint, ChatEndpoint::Local);
+    let mut done = false;
+    {
+        let mut forward = |chunk: StreamChunk| {
+            match &chunk {
+                StreamChunk::ConversationId { id: Some(id) } => conversation_id = Some(id.clone()),
+                StreamChunk::Done => done = true,
+                StreamChunk::Unknown => return,
+                _ => {}
+            }
+            emit(&chunk);
+        };
+
+        loop {
+            match response.chunk().await {
+                Ok(Some(bytes)) if plain => text.push(&bytes).into_iter().for_each(&mut forward),
+                Ok(Some(bytes)) => decoder.push(&bytes).into_iter().for_each(&mut forward),
+                Ok(None) => break,
+                Err(e) => {
+                    emit(&StreamChunk::Error {
+                        content: Some(format!("chat stream interrupted: {e}")),
+                    });
+                    break;
+                }
+            }
+        }
+        decoder.finish().into_iter().for_each(&mut forward);
+        text.finish().into_iter().for_each(&mut forward);
+    }
+
+    // Errors end the stream without a `done`; always close it for listeners,
+    // as the failed request above does
+    if !done {
+        emit(&StreamChunk::Done);
+    }
+
+    Ok(conversation_id)
+}
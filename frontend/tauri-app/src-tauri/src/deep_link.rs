@@ -0,0 +1,370 @@
+// FRIDAY AI Assistant - Deep Links
+// Handles friday:// URLs from browsers, scripts and other apps. The OS starts
+// FRIDAY with the URL as its argument; a running instance receives it through
+// single_instance. Links are validated here, then sent to the frontend as
+// `deep-link://open` events; nothing a link asks for happens unattended.
+//
+//   friday://ask?q=<text>        (fills in the chat box for the user to send)
+//   friday://conversation/<id>
+//   friday://project/<id>
+//   friday://routine/run?name=<routine>  (runs once the user confirms)
+
+use std::sync::Mutex;
+use std::time::Duration;
+
+use serde::Serialize;
+use serde_json::json;
+use tauri::{AppHandle, Manager, State, Url};
+
+use crate::backend::{BackendStatus, BackendSupervisor};
+use crate::tray;
+
+pub const SCHEME: &str = "friday";
+const MAX_QUERY_LEN: usize = 4000;
+const MAX_ID_LEN: usize = 128;
+/// How long a routine link waits for the backend to come up (e.g. while locked)
+const BACKEND_WAIT: Duration = Duration::from_secs(120);
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "route", rename_all = "snake_case")]
+pub enum DeepLink {
+    Ask { q: String },
+    Conversation { id: String },
+    Project { id: String },
+    RoutineRun { name: String },
+}
+
+impl DeepLink {
+    pub fn parse(link: &str) -> Result<Self, String> {
+        let url = Url::parse(link).map_err(|e| format!("invalid link: {e}"))?;
+        if url.scheme() != SCHEME {
+            return Err(format!("not a {SCHEME}:// link"));
+        }
+
+        let host = url.host_str().unwrap_or_default();
+        let path: Vec<&str> = url
+            .path()
+            .split('/')
+            .filter(|part| !part.is_empty())
+            .collect();
+        let query = |key: &str| {
+            url.query_pairs()
+                .find(|(k, _)| k == key)
+                .map(|(_, v)| v.trim().to_string())
+        };
+
+        match (host, path.as_slice()) {
+            ("ask", []) => {
+                let q = query("q").unwrap_or_default();
+                if q.is_empty() {
+                    return Err("ask needs a non-empty q parameter".into());
+                }
+                if q.chars().count() > MAX_QUERY_LEN {
+                    return Err(format!("q is longer than {MAX_QUERY_LEN} characters"));
+                }
+                Ok(Self::Ask { q })
+            }
+            ("conversation", [id]) => Ok(Self::Conversation { id: valid_id(id)? }),
+            ("project", [id]) => Ok(Self::Project { id: valid_id(id)? }),
+            ("routine", ["run"]) => Ok(Self::RoutineRun {
+                name: valid_routine_name(&query("name").unwrap_or_default())?,
+            }),
+            _ => Err(format!("unknown route {host}{}", url.path())),
+        }
+    }
+}
+
+fn valid_id(id: &str) -> Result<String, String> {
+    let valid = !id.is_empty()
+        && id.len() <= MAX_ID_LEN
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-'));
+    if valid {
+        Ok(id.to_string())
+    } else {
+        Err(format!("invalid id {id:?}"))
+    }
+}
+
+fn valid_routine_name(name: &str) -> Result<String, String> {
+    let valid = !name.is_empty()
+        && name.len() <= MAX_ID_LEN
+        && name
+            .chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, ' ' | '_' | '-'));
+    if valid {
+        Ok(name.to_string())
+    } else {
+        Err(format!("invalid routine name {name:?}"))
+    }
+}
+
+/// The first friday:// argument, if the app was launched through a link
+pub fn find(args: &[String]) -> Option<&String> {
+    args.iter()
+        .find(|arg| arg.starts_with(&format!("{SCHEME}://")))
+}
+
+/// Frontend links that arrived before the webview was listening
+#[derive(Default)]
+pub struct DeepLinks {
+    pending: Mutex<Pending>,
+}
+
+#[derive(Default)]
+struct Pending {
+    ready: bool,
+    links: Vec<DeepLink>,
+}
+
+/// Parse and act on a link. Errors are logged and emitted as `deep-link://error`.
+pub fn handle(app: &AppHandle, link: &str) {
+    let link = match DeepLink::parse(link) {
+        Ok(link) => link,
+        Err(e) => {
+            eprintln!("[deep_link] rejected {link}: {e}");
+            let _ = app.emit_all("deep-link://error", e);
+            return;
+        }
+    };
+
+    tray::show_main_window(app);
+    let links = app.state::<DeepLinks>();
+    let mut pending = links.pending.lock().unwrap();
+    if pending.ready {
+        let _ = app.emit_all("deep-link://open", link);
+    } else {
+        pending.links.push(link);
+    }
+}
+
+/// Run the routine from a `routine/run` link once the user has confirmed it.
+/// The result is emitted as `deep-link://routine`.
+#[tauri::command]
+pub fn run_linked_routine(app: AppHandle, name: String) -> Result<(), String> {
+    run_routine(&app, valid_routine_name(&name)?);
+    Ok(())
+}
+
+fn run_routine(app: &AppHandle, name: String) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let backend = app.state::<BackendSupervisor>().inner().clone();
+        let result = if wait_for_backend(&backend).await {
+            post_routine(&backend, &name).await
+        } else {
+            Err("backend is not running".to_string())
+        };
+        let event = match result {
+            Ok(result) => json!({ "name": name, "success": true, "result": result }),
+            Err(e) => {
+                eprintln!("[deep_link] routine {name:?} failed: {e}");
+                json!({ "name": name, "success": false, "error": e })
+            }
+        };
+        let _ = app.emit_all("deep-link://routine", event);
+    });
+}
+
+async fn wait_for_backend(backend: &BackendSupervisor) -> bool {
+    let deadline = tokio::time::Instant::now() + BACKEND_WAIT;
+    while tokio::time::Instant::now() < deadline {
+        match backend.status() {
+            BackendStatus::Ready => return true,
+            BackendStatus::Failed => return false,
+            _ => tokio::time::sleep(Duration::from_millis(500)).await,
+        }
+    }
+    false
+}
+
+async fn post_routine(
+    backend: &BackendSupervisor,
+    name: &str,
+) -> Result<serde_json::Value, String> {
+    let url = format!("{}/api/alexa/routine/run", backend.base_url());
+    let resp = backend
+        .client()
+        .post(&url)
+        .json(&json!({ "routine_name": name }))
+        .send()
+        .await
+        .map_err(|e| e.to_string())?
+        .error_for_status()
+        .map_err(|e| e.to_string())?;
+    let result: serde_json::Value = resp.json().await.map_err(|e| e.to_string())?;
+    // Unknown or disabled routines come back as 200 with success: false
+    if result["success"] == false {
+        let message = result["message"].as_str().unwrap_or("routine did not run");
+        return Err(message.to_string());
+    }
+    Ok(result)
+}
+
+/// Register FRIDAY as the friday:// handler for the current user. macOS
+/// picks the scheme up from Info.plist instead, but only delivers links to a
+/// running app as Apple events, which Tauri 1 does not expose.
+pub fn register() {
+    let Ok(exe) = std::env::current_exe() else {
+        return;
+    };
+    if let Err(e) = register_scheme(&exe.to_string_lossy()) {
+        eprintln!("[deep_link] could not register {SCHEME}://: {e}");
+    }
+}
+
+#[cfg(windows)]
+fn register_scheme(exe: &str) -> std::io::Result<()> {
+    use std::os::windows::process::CommandExt;
+    const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+
+    let key = format!(r"HKCU\Software\Classes\{SCHEME}");
+    let command = format!(r#""{exe}" "%1""#);
+    // (key, value name or None for the default value, data)
+    let entries: [(String, Option<&str>, &str); 3] = [
+        (key.clone(), None, "URL:FRIDAY"),
+        (key.clone(), Some("URL Protocol"), ""),
+        (format!(r"{key}\shell\open\command"), None, &command),
+    ];
+    for (key, value, data) in entries {
+        let mut cmd = std::process::Command::new("reg");
+        cmd.args(["add", &key]);
+        match value {
+            Some(name) => cmd.args(["/v", name]),
+            None => cmd.arg("/ve"),
+        };
+        let status = cmd
+            .args(["/d", data, "/f"])
+            .creation_flags(CREATE_NO_WINDOW)
+            .status()?;
+        if !status.success() {
+            return Err(std::io::Error::other(format!("reg add {key} failed")));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(target_os = "linux")]
+fn register_scheme(exe: &str) -> std::io::Result<()> {
+    const DESKTOP_FILE: &str = "friday-url-handler.desktop";
+
+    let Some(dir) = tauri::api::path::data_dir().map(|dir| dir.join("applications")) else {
+        return Ok(());
+    };
+    std::fs::create_dir_all(&dir)?;
+    std::fs::write(
+        dir.join(DESKTOP_FILE),
+        format!(
+            "[Desktop Entry]\nType=Application\nName=FRIDAY\nExec=\"{exe}\" %u\n\
+             NoDisplay=true\nMimeType=x-scheme-handler/{SCHEME};\n"
+        ),
+    )?;
+    std::process::Command::new("xdg-mime")
+        .args([
+            "default",
+            DESKTOP_FILE,
+            &format!("x-scheme-handler/{SCHEME}"),
+        ])
+        .status()?;
+    Ok(())
+}
+
+#[cfg(not(any(windows, target_os = "linux")))]
+fn register_scheme(_exe: &str) -> std::io::Result<()> {
+    Ok(())
+}
+
+/// Called by the frontend once its listeners are attached; returns the links
+/// that arrived before that and switches to emitting `deep-link://open`
+#[tauri::command]
+pub fn take_pending_deep_links(links: State<'_, DeepLinks>) -> Vec<DeepLink> {
+    let mut pending = links.pending.lock().unwrap();
+    pending.ready = true;
+    std::mem::take(&mut pending.links)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rejected(link: &str) -> bool {
+        DeepLink::parse(link).is_err()
+    }
+
+    #[test]
+    fn known_routes() {
+        assert_eq!(
+            DeepLink::parse("friday://ask?q=what%27s+the+weather"),
+            Ok(DeepLink::Ask {
+                q: "what's the weather".into()
+            })
+        );
+        assert_eq!(
+            DeepLink::parse("friday://conversation/3f2a-b_9"),
+            Ok(DeepLink::Conversation {
+                id: "3f2a-b_9".into()
+            })
+        );
+        assert_eq!(
+            DeepLink::parse("friday://project/p1/"),
+            Ok(DeepLink::Project { id: "p1".into() })
+        );
+        assert_eq!(
+            DeepLink::parse("friday://routine/run?name=Good%20Morning"),
+            Ok(DeepLink::RoutineRun {
+                name: "Good Morning".into()
+            })
+        );
+    }
+
+    #[test]
+    fn unknown_hosts_and_routes() {
+        assert!(rejected("https://ask?q=hi"));
+        assert!(rejected("friday://settings"));
+        assert!(rejected("friday://ask/extra?q=hi"));
+        assert!(rejected("friday://conversation"));
+        assert!(rejected("friday://conversation/a/b"));
+        assert!(rejected("friday://routine/stop?name=lights"));
+        assert!(rejected("friday:ask?q=hi"));
+        assert!(rejected("not a link"));
+    }
+
+    #[test]
+    fn ask_needs_a_bounded_question() {
+        assert!(rejected("friday://ask"));
+        assert!(rejected("friday://ask?q=%20%20"));
+        let long = "a".repeat(MAX_QUERY_LEN + 1);
+        assert!(rejected(&format!("friday://ask?q={long}")));
+        let longest = "a".repeat(MAX_QUERY_LEN);
+        assert!(DeepLink::parse(&format!("friday://ask?q={longest}")).is_ok());
+    }
+
+    #[test]
+    fn bad_routine_names() {
+        assert!(rejected("friday://routine/run"));
+        assert!(rejected("friday://routine/run?name="));
+        assert!(rejected("friday://routine/run?name=lights%3Boff"));
+        assert!(rejected("friday://routine/run?name=..%2F..%2Fetc"));
+        assert!(rejected("friday://routine/run?name=lights%0Aoff"));
+        let long = "r".repeat(MAX_ID_LEN + 1);
+        assert!(rejected(&format!("friday://routine/run?name={long}")));
+    }
+
+    #[test]
+    fn encoded_ids_are_not_decoded() {
+        // Path segments stay percent-encoded, so these never reach the store as ids
+        assert!(rejected("friday://conversation/abc%2F..%2Fsecret"));
+        assert!(rejected("friday://conversation/a%20b"));
+        assert!(rejected("friday://project/%2e%2e"));
+        // Dot segments are resolved before routing, so they cannot leave the route
+        assert_eq!(
+            DeepLink::parse("friday://conversation/../secrets"),
+            Ok(DeepLink::Conversation {
+                id: "secrets".into()
+            })
+        );
+        let long = "c".repeat(MAX_ID_LEN + 1);
+        assert!(rejected(&format!("friday://conversation/{long}")));
+    }
+}
@@ -0,0 +1,716 @@
+// FRIDAY AI Assistant - Encryption at Rest
+// Owns the data key that encrypts the conversation store (SQLCipher) and the
+// backend's conversation and learning JSON files (core/secure_storage.py).
+// The key lives in the OS credential store, or wrapped with a passphrase that
+// must be entered at startup; nothing is opened or launched until it is known.
+// Chroma cannot be encrypted, so with a key the backend keeps vector stores in memory only.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use aes_gcm::aead::consts::U12;
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Nonce};
+use argon2::{Algorithm, Argon2, Params, Version};
+use hmac::{Hmac, Mac};
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use tauri::api::dialog::blocking::FileDialogBuilder;
+use tauri::{AppHandle, Manager, State};
+use zeroize::Zeroizing;
+
+use crate::audit;
+use crate::backend::{self, BackendSupervisor};
+use crate::backup::{self, Backups};
+use crate::export::{ExportFormat, ExportScope, Selection};
+use crate::secrets;
+use crate::store;
+
+const KEYSTORE_FILE: &str = "keystore.json";
+/// The new key of a rotation in progress, protected like the current one
+const PENDING_KEYSTORE_FILE: &str = "keystore.pending.json";
+const KEYRING_SERVICE: &str = "com.dipesh.friday";
+const KEYRING_USER: &str = "data-key";
+const KEYRING_PENDING_USER: &str = "data-key-pending";
+const MIN_PASSPHRASE_LEN: usize = 8;
+
+/// Header of files encrypted with the data key; matches core/secure_storage.py
+pub const FILE_MAGIC: &[u8] = b"FRIDAYENC1";
+const NONCE_LEN: usize = 12;
+/// Directories under data/ whose JSON files go through core/secure_storage.py
+pub(crate) const ENCRYPTED_DIRS: &[&str] = &["conversations", "projects", "chats", "learning"];
+
+/// The 256-bit key everything at rest is encrypted with
+pub struct DataKey(Zeroizing<[u8; 32]>);
+
+impl DataKey {
+    pub(crate) fn generate() -> Self {
+        let mut key = Zeroizing::new([0u8; 32]);
+        OsRng.fill_bytes(key.as_mut());
+        Self(key)
+    }
+
+    fn from_hex(hex: &str) -> Result<Self, String> {
+        let mut key = Zeroizing::new([0u8; 32]);
+        hex::decode_to_slice(hex.trim(), key.as_mut()).map_err(|_| "malformed data key")?;
+        Ok(Self(key))
+    }
+
+    pub fn hex(&self) -> Zeroizing<String> {
+        Zeroizing::new(hex::encode(self.0.as_ref()))
+    }
+
+    /// Raw-key form (`x'..'`) for SQLCipher's `PRAGMA key`, `PRAGMA rekey` and `ATTACH ... KEY`
+    pub fn sqlcipher_key(&self) -> Zeroizing<String> {
+        Zeroizing::new(format!("x'{}'", self.hex().as_str()))
+    }
+
+    fn cipher(&self) -> Aes256Gcm {
+        Aes256Gcm::new(self.0.as_ref().into())
+    }
+
+    /// `FILE_MAGIC | nonce | ciphertext`
+    pub fn encrypt(&self, plaintext: &[u8]) -> Vec<u8> {
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        let ciphertext = self
+            .cipher()
+            .encrypt(&nonce, plaintext)
+            .expect("AES-GCM encryption cannot fail for in-memory buffers");
+        [FILE_MAGIC, &nonce[..], &ciphertext].concat()
+    }
+
+    /// Hex HMAC-SHA256 of `data` under a subkey derived for `purpose`, so MACs
+    /// never share a key with encryption
+    pub fn mac(&self, purpose: &str, data: &[u8]) -> String {
+        let subkey = <Hmac<Sha256> as Mac>::new_from_slice(self.0.as_ref())
+            .expect("HMAC accepts any key length")
+            .chain_update(purpose)
+            .finalize()
+            .into_bytes();
+        let mac = <Hmac<Sha256> as Mac>::new_from_slice(&subkey)
+            .expect("HMAC accepts any key length")
+            .chain_update(data)
+            .finalize();
+        hex::encode(mac.into_bytes())
+    }
+
+    /// Decrypt a file written by `encrypt`; plaintext files are returned as-is
+    pub fn decrypt(&self, data: &[u8]) -> Result<Vec<u8>, String> {
+        let Some(rest) = data.strip_prefix(FILE_MAGIC) else {
+            return Ok(data.to_vec());
+        };
+        if rest.len() < NONCE_LEN {
+            return Err("truncated encrypted file".into());
+        }
+        let (nonce, ciphertext) = rest.split_at(NONCE_LEN);
+        self.cipher()
+            .decrypt(&nonce_from(nonce), ciphertext)
+            .map_err(|_| "file was encrypted with a different key".into())
+    }
+}
+
+/// How the data key is protected, persisted in <app data dir>/keystore.json
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "protection", rename_all = "snake_case")]
+enum Keystore {
+    /// Kept in the OS credential store (Credential Manager, Keychain, Secret Service)
+    OsSecret,
+    /// Wrapped with an Argon2id key derived from a passphrase
+    Passphrase {
+        salt: String,
+        m_cost: u32,
+        t_cost: u32,
+        p_cost: u32,
+        nonce: String,
+        wrapped_key: String,
+    },
+}
+
+impl Keystore {
+    fn wrap(key: &DataKey, passphrase: &str) -> Result<Self, String> {
+        let mut salt = [0u8; 16];
+        OsRng.fill_bytes(&mut salt);
+        let params = Params::default();
+        let kek = derive(
+            passphrase,
+            &salt,
+            params.m_cost(),
+            params.t_cost(),
+            params.p_cost(),
+        )?;
+
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        let wrapped = kek
+            .cipher()
+            .encrypt(&nonce, key.0.as_ref())
+            .map_err(|_| "could not wrap data key")?;
+        Ok(Keystore::Passphrase {
+            salt: hex::encode(salt),
+            m_cost: params.m_cost(),
+            t_cost: params.t_cost(),
+            p_cost: params.p_cost(),
+            nonce: hex::encode(nonce),
+            wrapped_key: hex::encode(wrapped),
+        })
+    }
+
+    fn unwrap_key(&self, passphrase: Option<&str>) -> Result<DataKey, String> {
+        self.unwrap_from(KEYRING_USER, passphrase)
+    }
+
+    /// `unwrap_key` for a key kept under another credential store entry
+    fn unwrap_from(&self, keyring_user: &str, passphrase: Option<&str>) -> Result<DataKey, String> {
+        match self {
+            Keystore::OsSecret => keychain_entry(keyring_user)?
+                .get_password()
+                .map_err(|e| {
+                    format!("could not read the data key from the OS credential store: {e}")
+                })
+                .and_then(|hex| DataKey::from_hex(&hex)),
+            Keystore::Passphrase {
+                salt,
+                m_cost,
+                t_cost,
+                p_cost,
+                nonce,
+                wrapped_key,
+            } => {
+                let passphrase = passphrase.ok_or("a passphrase is required")?;
+                let salt = hex::decode(salt).map_err(|_| "corrupt keystore")?;
+                let nonce = hex::decode(nonce).map_err(|_| "corrupt keystore")?;
+                let wrapped = hex::decode(wrapped_key).map_err(|_| "corrupt keystore")?;
+                if nonce.len() != NONCE_LEN {
+                    return Err("corrupt keystore".into());
+                }
+
+                let kek = derive(passphrase, &salt, *m_cost, *t_cost, *p_cost)?;
+                let key = Zeroizing::new(
+                    kek.cipher()
+                        .decrypt(&nonce_from(&nonce), wrapped.as_slice())
+                        .map_err(|_| "wrong passphrase")?,
+                );
+                let mut bytes = Zeroizing::new([0u8; 32]);
+                if key.len() != bytes.len() {
+                    return Err("corrupt keystore".into());
+                }
+                bytes.copy_from_slice(&key);
+                Ok(DataKey(bytes))
+            }
+        }
+    }
+
+    fn protection(&self) -> Protection {
+        match self {
+            Keystore::OsSecret => Protection::OsSecret,
+            Keystore::Passphrase { .. } => Protection::Passphrase,
+        }
+    }
+}
+
+fn nonce_from(bytes: &[u8]) -> Nonce<U12> {
+    let bytes: [u8; NONCE_LEN] = bytes
+        .try_into()
+        .expect("nonce length is checked by callers");
+    bytes.into()
+}
+
+fn derive(passphrase: &str, salt: &[u8], m: u32, t: u32, p: u32) -> Result<DataKey, String> {
+    let params = Params::new(m, t, p, Some(32)).map_err(|e| e.to_string())?;
+    let mut kek = Zeroizing::new([0u8; 32]);
+    Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+        .hash_password_into(passphrase.as_bytes(), salt, kek.as_mut())
+        .map_err(|e| e.to_string())?;
+    Ok(DataKey(kek))
+}
+
+fn keychain_entry(user: &str) -> Result<keyring::Entry, String> {
+    keyring::Entry::new(KEYRING_SERVICE, user).map_err(|e| e.to_string())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Protection {
+    OsSecret,
+    Passphrase,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LockState {
+    /// No key yet and the OS credential store was unavailable; set a passphrase
+    Uninitialized,
+    Locked,
+    Unlocked,
+}
+
+/// Emitted as `encryption://status` whenever it changes
+#[derive(Debug, Clone, Serialize)]
+pub struct LockStatus {
+    pub state: LockState,
+    pub protection: Option<Protection>,
+}
+
+#[derive(Default)]
+pub struct Encryption {
+    key: Mutex<Option<Arc<DataKey>>>,
+}
+
+impl Encryption {
+    pub fn key(&self) -> Option<Arc<DataKey>> {
+        self.key.lock().unwrap().clone()
+    }
+}
+
+/// Unlock straight away when the key is in the OS credential store (creating
+/// one on first run); otherwise wait for `unlock_data`. Called once from `setup`.
+pub fn init(app: &AppHandle) {
+    let unlocked = match read_keystore(app) {
+        Ok(Some(keystore @ Keystore::OsSecret)) => keystore
+            .unwrap_key(None)
+            .and_then(|key| unlock(app, key, pending_key(app, None)?)),
+        Ok(Some(Keystore::Passphrase { .. })) => Ok(()),
+        Ok(None) => {
+            let key = DataKey::generate();
+            keychain_entry(KEYRING_USER)
+                .and_then(|entry| {
+                    entry
+                        .set_password(key.hex().as_str())
+                        .map_err(|e| e.to_string())
+                })
+                .and_then(|_| write_keystore(app, &Keystore::OsSecret))
+                .and_then(|_| unlock(app, key, None))
+        }
+        Err(e) => Err(e),
+    };
+    if let Err(e) = unlocked {
+        eprintln!("[encryption] data stays locked: {e}");
+    }
+    emit_status(app);
+}
+
+pub fn is_unlocked(app: &AppHandle) -> bool {
+    app.state::<Encryption>().key().is_some()
+}
+
+/// Open the store and launch the backend with the key. `pending` is the new
+/// key of a rotation that was interrupted; it is rolled back first.
+fn unlock(app: &AppHandle, key: DataKey, pending: Option<DataKey>) -> Result<(), String> {
+    let opened = match &pending {
+        // Committed, but the pending copy was not cleared
+        Some(pending) if *pending.0 == *key.0 => {
+            clear_pending(app);
+            store::init(app, &key)
+        }
+        // The store may have been re-encrypted before the interruption
+        Some(pending) => store::init(app, &key).or_else(|_| store::init(app, pending)),
+        None => store::init(app, &key),
+    };
+    opened.map_err(|e| format!("could not open the conversation store: {e}"))?;
+    if let Some(pending) = pending.filter(|pending| *pending.0 != *key.0) {
+        roll_back_rotation(app, &pending, &key)?;
+    }
+    if let Some(data_dir) = backend::data_dir() {
+        // Files written before encryption was set up, or by a standalone backend
+        recrypt_backend_files(&data_dir, &[], &key)?;
+    }
+
+    let supervisor = app.state::<BackendSupervisor>();
+    supervisor.set_env("FRIDAY_DATA_KEY", key.hex().as_str());
+    if let Err(e) = secrets::inject(app, &key) {
+        eprintln!("[encryption] provider keys unavailable: {e}");
+    }
+    *app.state::<Encryption>().key.lock().unwrap() = Some(Arc::new(key));
+    supervisor.start(app.clone());
+    Ok(())
+}
+
+/// Rewrite every backend data file under `new`, decrypting with whichever of
+/// `old` fits. With no `old` keys only plaintext files are touched.
+fn recrypt_backend_files(data_dir: &Path, old: &[&DataKey], new: &DataKey) -> Result<(), String> {
+    for dir in ENCRYPTED_DIRS {
+        for path in json_files(&data_dir.join(dir)) {
+            let data = fs::read(&path).map_err(|e| e.to_string())?;
+            let plaintext = match old {
+                [] if data.starts_with(FILE_MAGIC) => continue,
+                [] => Ok(data),
+                keys => keys
+                    .iter()
+                    .find_map(|key| key.decrypt(&data).ok())
+                    .ok_or_else(|| "file was encrypted with a different key".to_string()),
+            }
+            .map_err(|e| format!("{}: {e}", path.display()))?;
+            write_atomic(&path, &new.encrypt(&plaintext))?;
+        }
+    }
+    Ok(())
+}
+
+/// Check that every encrypted backend data file under `data_dir` opens with `key`
+pub(crate) fn check_backend_files(data_dir: &Path, key: &DataKey) -> Result<(), String> {
+    for dir in ENCRYPTED_DIRS {
+        for path in json_files(&data_dir.join(dir)) {
+            let data = fs::read(&path).map_err(|e| e.to_string())?;
+            key.decrypt(&data)
+                .map_err(|e| format!("{}: {e}", path.display()))?;
+        }
+    }
+    Ok(())
+}
+
+fn json_files(dir: &Path) -> Vec<PathBuf> {
+    fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect()
+}
+
+fn write_atomic(path: &Path, data: &[u8]) -> Result<(), String> {
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(".tmp");
+    fs::write(&tmp, data)
+        .and_then(|_| fs::rename(&tmp, path))
+        .map_err(|e| format!("could not write {}: {e}", path.display()))
+}
+
+fn keystore_path(app: &AppHandle, file: &str) -> Result<PathBuf, String> {
+    app.path_resolver()
+        .app_data_dir()
+        .map(|dir| dir.join(file))
+        .ok_or_else(|| "app data directory is unavailable".into())
+}
+
+fn read_keystore(app: &AppHandle) -> Result<Option<Keystore>, String> {
+    read_keystore_file(app, KEYSTORE_FILE)
+}
+
+fn write_keystore(app: &AppHandle, keystore: &Keystore) -> Result<(), String> {
+    write_keystore_file(app, KEYSTORE_FILE, keystore)
+}
+
+fn read_keystore_file(app: &AppHandle, file: &str) -> Result<Option<Keystore>, String> {
+    let path = keystore_path(app, file)?;
+    match fs::read_to_string(&path) {
+        Ok(json) => serde_json::from_str(&json)
+            .map(Some)
+            .map_err(|e| format!("corrupt keystore: {e}")),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+fn write_keystore_file(app: &AppHandle, file: &str, keystore: &Keystore) -> Result<(), String> {
+    let path = keystore_path(app, file)?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+    }
+    let json = serde_json::to_string_pretty(keystore).map_err(|e| e.to_string())?;
+    write_atomic(&path, json.as_bytes())
+}
+
+/// The new key of an interrupted rotation, if there is one
+fn pending_key(app: &AppHandle, passphrase: Option<&str>) -> Result<Option<DataKey>, String> {
+    match read_keystore_file(app, PENDING_KEYSTORE_FILE)? {
+        Some(pending) => pending
+            .unwrap_from(KEYRING_PENDING_USER, passphrase)
+            .map(Some)
+            .map_err(|e| format!("could not read the key of an interrupted rotation: {e}")),
+        None => Ok(None),
+    }
+}
+
+fn rotation_pending(app: &AppHandle) -> bool {
+    keystore_path(app, PENDING_KEYSTORE_FILE).is_ok_and(|path| path.exists())
+}
+
+fn clear_pending(app: &AppHandle) {
+    if let Ok(path) = keystore_path(app, PENDING_KEYSTORE_FILE) {
+        let _ = fs::remove_file(path);
+    }
+    let _ = keychain_entry(KEYRING_PENDING_USER)
+        .and_then(|entry| entry.delete_credential().map_err(|e| e.to_string()));
+}
+
+fn status(app: &AppHandle) -> LockStatus {
+    let protection = read_keystore(app)
+        .ok()
+        .flatten()
+        .map(|keystore| keystore.protection());
+    let state = match (is_unlocked(app), protection) {
+        (true, _) => LockState::Unlocked,
+        (false, Some(_)) => LockState::Locked,
+        (false, None) => LockState::Uninitialized,
+    };
+    LockStatus { state, protection }
+}
+
+fn emit_status(app: &AppHandle) {
+    let _ = app.emit_all("encryption://status", status(app));
+}
+
+fn check_passphrase(passphrase: &str) -> Result<(), String> {
+    if passphrase.chars().count() < MIN_PASSPHRASE_LEN {
+        Err(format!(
+            "passphrase must be at least {MIN_PASSPHRASE_LEN} characters"
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+#[tauri::command]
+pub fn data_lock_status(app: AppHandle) -> LockStatus {
+    status(&app)
+}
+
+/// The startup unlock step. With no key yet, this creates one protected by `passphrase`.
+#[tauri::command]
+pub async fn unlock_data(app: AppHandle, passphrase: String) -> Result<LockStatus, String> {
+    if is_unlocked(&app) {
+        return Ok(status(&app));
+    }
+
+    let (key, pending) = match read_keystore(&app)? {
+        Some(keystore) => (
+            keystore.unwrap_key(Some(&passphrase))?,
+            pending_key(&app, Some(&passphrase))?,
+        ),
+        None => {
+            check_passphrase(&passphrase)?;
+            let key = DataKey::generate();
+            write_keystore(&app, &Keystore::wrap(&key, &passphrase)?)?;
+            (key, None)
+        }
+    };
+    unlock(&app, key, pending)?;
+    emit_status(&app);
+    Ok(status(&app))
+}
+
+/// Switch between passphrase and OS credential store protection, or change the
+/// passphrase. Data is not re-encrypted; only the key's wrapping changes.
+#[tauri::command]
+pub async fn set_data_passphrase(
+    app: AppHandle,
+    encryption: State<'_, Encryption>,
+    current: Option<String>,
+    new: Option<String>,
+) -> Result<LockStatus, String> {
+    let key = encryption.key().ok_or("data is locked")?;
+    let keystore = read_keystore(&app)?.ok_or("data is locked")?;
+    if let Keystore::Passphrase { .. } = keystore {
+        keystore.unwrap_key(current.as_deref())?;
+    }
+    if rotation_pending(&app) {
+        return Err(ROTATION_PENDING.into());
+    }
+
+    match new {
+        Some(passphrase) => {
+            check_passphrase(&passphrase)?;
+            write_keystore(&app, &Keystore::wrap(&key, &passphrase)?)?;
+            if let Keystore::OsSecret = keystore {
+                let _ = keychain_entry(KEYRING_USER)
+                    .and_then(|e| e.delete_credential().map_err(|e| e.to_string()));
+            }
+        }
+        None => {
+            keychain_entry(KEYRING_USER)?
+                .set_password(key.hex().as_str())
+                .map_err(|e| format!("could not use the OS credential store: {e}"))?;
+            write_keystore(&app, &Keystore::OsSecret)?;
+        }
+    }
+    emit_status(&app);
+    Ok(status(&app))
+}
+
+/// What `rotate_data_key` re-encrypts, in order
+#[derive(Debug, Clone, Copy)]
+enum RotationStep {
+    Store,
+    BackendFiles,
+    Secrets,
+    Backups,
+    AuditLog,
+}
+
+const ROTATION: [RotationStep; 5] = [
+    RotationStep::Store,
+    RotationStep::BackendFiles,
+    RotationStep::Secrets,
+    RotationStep::Backups,
+    RotationStep::AuditLog,
+];
+
+const ROTATION_PENDING: &str =
+    "an interrupted key rotation has not been rolled back yet; restart FRIDAY first";
+
+/// Re-encrypt one part of the data from `from` to `to`. Each step tolerates
+/// data already under `to`, so running it with the keys swapped undoes it,
+/// including after a partial failure.
+fn rotate_step(
+    app: &AppHandle,
+    step: RotationStep,
+    from: &DataKey,
+    to: &DataKey,
+) -> Result<(), String> {
+    match step {
+        RotationStep::Store => store::managed(app)?
+            .rekey(to)
+            .map_err(|e| format!("could not re-encrypt the conversation store: {e}")),
+        RotationStep::BackendFiles => match backend::data_dir() {
+            Some(data_dir) => recrypt_backend_files(&data_dir, &[from, to], to),
+            None => Ok(()),
+        },
+        RotationStep::Secrets => secrets::rekey(app, from, to),
+        RotationStep::Backups => backup::rekey_snapshots(app, from, to),
+        RotationStep::AuditLog => audit::rekey(app, from, to),
+    }
+}
+
+/// Put every step of an interrupted rotation back under `old` and drop the
+/// pending key. Run on unlock, before anything else reads the data.
+fn roll_back_rotation(app: &AppHandle, new: &DataKey, old: &DataKey) -> Result<(), String> {
+    for step in ROTATION.iter().rev() {
+        rotate_step(app, *step, new, old).map_err(|e| {
+            format!("could not roll back an interrupted key rotation ({step:?}): {e}")
+        })?;
+    }
+    clear_pending(app);
+    eprintln!("[encryption] rolled back an interrupted key rotation");
+    Ok(())
+}
+
+/// Replace the data key: stop the backend, re-encrypt the store, the backend
+/// data files, the backups and the tool audit log under a new key, protect it
+/// the same way, and restart. If any step fails, the ones already run are
+/// undone and the old key stays in use. The new key is saved as pending before
+/// anything is re-encrypted, so a crash part way is rolled back on next unlock.
+#[tauri::command]
+pub async fn rotate_data_key(
+    app: AppHandle,
+    encryption: State<'_, Encryption>,
+    passphrase: Option<String>,
+) -> Result<(), String> {
+    let old = encryption.key().ok_or("data is locked")?;
+    let keystore = read_keystore(&app)?.ok_or("data is locked")?;
+    if let Keystore::Passphrase { .. } = keystore {
+        keystore.unwrap_key(passphrase.as_deref())?;
+    }
+    if rotation_pending(&app) {
+        return Err(ROTATION_PENDING.into());
+    }
+
+    let new = DataKey::generate();
+    let pending = match (&keystore, passphrase.as_deref()) {
+        (Keystore::Passphrase { .. }, Some(passphrase)) => Keystore::wrap(&new, passphrase)?,
+        _ => {
+            keychain_entry(KEYRING_PENDING_USER)?
+                .set_password(new.hex().as_str())
+                .map_err(|e| format!("could not use the OS credential store: {e}"))?;
+            Keystore::OsSecret
+        }
+    };
+    write_keystore_file(&app, PENDING_KEYSTORE_FILE, &pending)?;
+
+    let backups = app.state::<Backups>();
+    let _paused = backups.pause().await;
+    let supervisor = app.state::<BackendSupervisor>().inner().clone();
+    supervisor.stop().await;
+
+    let mut attempted = 0;
+    let mut rotated = Ok(());
+    for step in ROTATION {
+        attempted += 1;
+        rotated = rotate_step(&app, step, &old, &new);
+        if rotated.is_err() {
+            break;
+        }
+    }
+    if rotated.is_ok() {
+        rotated = match pending {
+            wrapped @ Keystore::Passphrase { .. } => write_keystore(&app, &wrapped),
+            Keystore::OsSecret => keychain_entry(KEYRING_USER).and_then(|entry| {
+                entry
+                    .set_password(new.hex().as_str())
+                    .map_err(|e| e.to_string())
+            }),
+        };
+    }
+
+    match &mut rotated {
+        Ok(()) => {
+            clear_pending(&app);
+            supervisor.set_env("FRIDAY_DATA_KEY", new.hex().as_str());
+            *encryption.key.lock().unwrap() = Some(Arc::new(new));
+        }
+        Err(e) => {
+            let mut undone = true;
+            for step in ROTATION[..attempted].iter().rev() {
+                if let Err(undo) = rotate_step(&app, *step, &new, &old) {
+                    eprintln!(
+                        "[encryption] could not undo {step:?} after a failed rotation: {undo}"
+                    );
+                    e.push_str(&format!("; undoing {step:?} also failed: {undo}"));
+                    undone = false;
+                }
+            }
+            // Otherwise the pending key lets the next unlock try again
+            if undone {
+                clear_pending(&app);
+            }
+        }
+    }
+    supervisor.start(app.clone());
+    rotated
+}
+
+/// Escape hatch: write a plaintext copy of everything to a folder the user
+/// picks - the store as a JSON bundle plus decrypted backend data files.
+/// Resolves to the folder, or `None` if the dialog was cancelled.
+#[tauri::command]
+pub async fn export_unencrypted(
+    app: AppHandle,
+    encryption: State<'_, Encryption>,
+) -> Result<Option<PathBuf>, String> {
+    let key = encryption.key().ok_or("data is locked")?;
+    let Some(dest) = tauri::async_runtime::spawn_blocking(|| {
+        FileDialogBuilder::new()
+            .set_title("Export unencrypted copy")
+            .pick_folder()
+    })
+    .await
+    .map_err(|e| e.to_string())?
+    else {
+        return Ok(None);
+    };
+
+    let dest = dest.join(format!("friday-unencrypted-{}", &store::now()[..10]));
+    fs::create_dir_all(&dest).map_err(|e| e.to_string())?;
+
+    let bundle = Selection::collect(&*store::managed(&app)?, &ExportScope::All)?
+        .render(ExportFormat::Json)?;
+    fs::write(dest.join("conversations.json"), bundle).map_err(|e| e.to_string())?;
+
+    if let Some(data_dir) = backend::data_dir() {
+        for dir in ENCRYPTED_DIRS {
+            let target = dest.join("data").join(dir);
+            for path in json_files(&data_dir.join(dir)) {
+                let data = fs::read(&path).map_err(|e| e.to_string())?;
+                let plaintext = key
+                    .decrypt(&data)
+                    .map_err(|e| format!("{}: {e}", path.display()))?;
+                fs::create_dir_all(&target).map_err(|e| e.to_string())?;
+                if let Some(name) = path.file_name() {
+                    fs::write(target.join(name), plaintext).map_err(|e| e.to_string())?;
+                }
+            }
+        }
+    }
+    Ok(Some(dest))
+}
@@ -0,0 +1,365 @@
+// FRIDAY AI Assistant - Export
+// Writes one conversation, a project with all its conversations, or the whole
+// store to Markdown, a self-contained HTML page styled like the chat view, or
+// a versioned JSON bundle that `import` can read back.
+
+use std::fmt::Write as _;
+use std::fs;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+use tauri::api::dialog::blocking::FileDialogBuilder;
+use tauri::AppHandle;
+
+use crate::sanitize;
+use crate::store::{self, Conversation, Project, Role, Store};
+
+/// Identifies our bundles; bump `BUNDLE_VERSION` on incompatible changes
+pub const BUNDLE_FORMAT: &str = "friday-export";
+pub const BUNDLE_VERSION: u32 = 1;
+
+/// What to export
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "scope", rename_all = "snake_case")]
+pub enum ExportScope {
+    Conversation {
+        id: String,
+    },
+    /// The project and every conversation in its `conversation_ids`
+    Project {
+        id: String,
+    },
+    All,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    Markdown,
+    Html,
+    Json,
+}
+
+impl ExportFormat {
+    fn extension(self) -> &'static str {
+        match self {
+            ExportFormat::Markdown => "md",
+            ExportFormat::Html => "html",
+            ExportFormat::Json => "json",
+        }
+    }
+
+    fn filter_name(self) -> &'static str {
+        match self {
+            ExportFormat::Markdown => "Markdown",
+            ExportFormat::Html => "HTML page",
+            ExportFormat::Json => "FRIDAY bundle",
+        }
+    }
+}
+
+/// Versioned JSON export; the payload uses the backend's schema as-is
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Bundle {
+    pub format: String,
+    pub version: u32,
+    pub exported_at: String,
+    #[serde(default)]
+    pub projects: Vec<Project>,
+    #[serde(default)]
+    pub conversations: Vec<Conversation>,
+}
+
+/// Everything selected by a scope, plus a title and file name stem
+pub struct Selection {
+    pub title: String,
+    pub file_stem: String,
+    pub projects: Vec<Project>,
+    pub conversations: Vec<Conversation>,
+}
+
+impl Selection {
+    pub fn collect(store: &Store, scope: &ExportScope) -> Result<Self, String> {
+        let err = |e: rusqlite::Error| e.to_string();
+        let load = |ids: Vec<String>| -> Result<Vec<Conversation>, String> {
+            let mut conversations = Vec::new();
+            for id in ids {
+                if let Some(conversation) = store.conversation(&id).map_err(err)? {
+                    conversations.push(conversation);
+                }
+            }
+            Ok(conversations)
+        };
+
+        match scope {
+            ExportScope::Conversation { id } => {
+                let conversation = store
+                    .conversation(id)
+                    .map_err(err)?
+                    .ok_or_else(|| format!("conversation {id} not found"))?;
+                let title = conversation_title(&conversation).to_string();
+                Ok(Self {
+                    file_stem: slug(&title),
+                    title,
+                    projects: Vec::new(),
+                    conversations: vec![conversation],
+                })
+            }
+            ExportScope::Project { id } => {
+                let project = store
+                    .project(id)
+                    .map_err(err)?
+                    .ok_or_else(|| format!("project {id} not found"))?;
+                // conversation_ids keeps the project's order; conversations that only
+                // point at the project via project_id come after
+                let mut ids = project.conversation_ids.clone();
+                for summary in store.list_conversations(Some(id), u32::MAX).map_err(err)? {
+                    if !ids.contains(&summary.id) {
+                        ids.push(summary.id);
+                    }
+                }
+                Ok(Self {
+                    title: project.name.clone(),
+                    file_stem: slug(&project.name),
+                    conversations: load(ids)?,
+                    projects: vec![project],
+                })
+            }
+            ExportScope::All => {
+                let ids = store
+                    .list_conversations(None, u32::MAX)
+                    .map_err(err)?
+                    .into_iter()
+                    .map(|summary| summary.id)
+                    .collect();
+                Ok(Self {
+                    title: "FRIDAY conversations".into(),
+                    file_stem: format!("friday-export-{}", &store::now()[..10]),
+                    projects: store.projects().map_err(err)?,
+                    conversations: load(ids)?,
+                })
+            }
+        }
+    }
+
+    pub fn render(&self, format: ExportFormat) -> Result<String, String> {
+        match format {
+            ExportFormat::Markdown => Ok(self.markdown()),
+            ExportFormat::Html => Ok(self.html()),
+            ExportFormat::Json => serde_json::to_string_pretty(&Bundle {
+                format: BUNDLE_FORMAT.into(),
+                version: BUNDLE_VERSION,
+                exported_at: store::now(),
+                projects: self.projects.clone(),
+                conversations: self.conversations.clone(),
+            })
+            .map_err(|e| e.to_string()),
+        }
+    }
+
+    fn markdown(&self) -> String {
+        let mut out = String::new();
+        let single = self.projects.is_empty() && self.conversations.len() == 1;
+
+        if !single {
+            let _ = writeln!(out, "# {}\n", self.title);
+            if let [project] = self.projects.as_slice() {
+                if let Some(description) = project.description.as_deref().filter(|d| !d.is_empty())
+                {
+                    let _ = writeln!(out, "{description}\n");
+                }
+            }
+        }
+
+        let level = if single { 1 } else { 2 };
+        for conversation in &self.conversations {
+            let _ = writeln!(
+                out,
+                "{} {}\n",
+                "#".repeat(level),
+                conversation_title(conversation)
+            );
+            let _ = writeln!(
+                out,
+                "_Created {} · Updated {}_\n",
+                conversation.created_at, conversation.updated_at
+            );
+
+            for message in &conversation.messages {
+                let _ = writeln!(
+                    out,
+                    "{} {} · {}\n",
+                    "#".repeat(level + 1),
+                    role_label(message.role),
+                    message.timestamp
+                );
+                // Content is already Markdown, so code blocks carry over untouched
+                let _ = writeln!(out, "{}\n", message.content.trim_end());
+                if let Some(calls) = message.tool_calls.as_ref().filter(|c| !c.is_empty()) {
+                    let json = serde_json::to_string_pretty(calls).unwrap_or_default();
+                    let _ = writeln!(out, "```json\n{json}\n```\n");
+                }
+            }
+        }
+        out
+    }
+
+    fn html(&self) -> String {
+        let mut body = String::new();
+        let _ = writeln!(body, "<h1>{}</h1>", escape(&self.title));
+        if let [project] = self.projects.as_slice() {
+            if let Some(description) = project.description.as_deref().filter(|d| !d.is_empty()) {
+                let _ = writeln!(body, "<p class=\"meta\">{}</p>", escape(description));
+            }
+        }
+
+        for conversation in &self.conversations {
+            body.push_str("<section class=\"conversation\">\n");
+            if self.conversations.len() > 1 {
+                let _ = writeln!(
+                    body,
+                    "<h2>{}</h2>",
+                    escape(conversation_title(conversation))
+                );
+            }
+            let _ = writeln!(
+                body,
+                "<p class=\"meta\">Created {} · Updated {}</p>",
+                escape(&conversation.created_at),
+                escape(&conversation.updated_at)
+            );
+
+            for message in &conversation.messages {
+                let role = message.role.as_str();
+                let avatar = if message.role == Role::User { "U" } else { "F" };
+                let mut text = sanitize::render(&message.content);
+                if let Some(calls) = message.tool_calls.as_ref().filter(|c| !c.is_empty()) {
+                    let json = serde_json::to_string_pretty(calls).unwrap_or_default();
+                    let _ = write!(text, "<pre><code>{}</code></pre>", escape(&json));
+                }
+                let _ = writeln!(
+                    body,
+                    "<div class=\"message {role}\">\
+                     <div class=\"message-avatar\">{avatar}</div>\
+                     <div class=\"message-content\">\
+                     <div class=\"message-time\">{}</div>\
+                     <div class=\"message-text\">{text}</div>\
+                     </div></div>",
+                    escape(&message.timestamp)
+                );
+            }
+            body.push_str("</section>\n");
+        }
+
+        format!(
+            "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+             <title>{}</title>\n<style>{HTML_STYLE}</style>\n</head>\n<body>\n<main>\n{body}</main>\n</body>\n</html>\n",
+            escape(&self.title)
+        )
+    }
+}
+
+/// The chat view's message styles from src/styles.css, trimmed for a static page
+const HTML_STYLE: &str = "
+:root { --primary: #00d9ff; --border: rgba(0, 217, 255, 0.3); --text: #e0f7ff; --dim: #8fa5b8; }
+* { box-sizing: border-box; }
+body { margin: 0; font-family: 'Segoe UI', -apple-system, BlinkMacSystemFont, sans-serif;
+       background: #050810; color: var(--text); }
+main { max-width: 960px; margin: 0 auto; padding: 2rem; }
+h1, h2 { color: var(--primary); }
+.meta, .message-time { color: var(--dim); font-size: 0.8rem; }
+.conversation { display: flex; flex-direction: column; gap: 1.5rem; margin-bottom: 3rem; }
+.message { display: flex; gap: 1rem; }
+.message.user { flex-direction: row-reverse; }
+.message-avatar { width: 40px; height: 40px; border-radius: 50%; background: rgba(0, 217, 255, 0.2);
+                  border: 2px solid var(--primary); display: flex; align-items: center;
+                  justify-content: center; font-weight: bold; flex-shrink: 0; }
+.message.assistant .message-avatar { background: radial-gradient(circle, var(--primary) 0%, transparent 70%); }
+.message-content { flex: 1; max-width: 70%; }
+.message.user .message-content { background: rgba(0, 217, 255, 0.1); border: 1px solid var(--border);
+                                 border-radius: 10px 10px 0 10px; padding: 1rem; }
+.message.assistant .message-content { padding: 0.5rem 0; }
+.message-text { line-height: 1.6; }
+.message-text code { background: rgba(0, 217, 255, 0.1); padding: 0.2rem 0.4rem; border-radius: 3px;
+                     font-family: 'Courier New', monospace; font-size: 0.9em; }
+.message-text pre { background: rgba(0, 20, 40, 0.8); border: 1px solid var(--border); border-radius: 5px;
+                    padding: 1rem; overflow-x: auto; margin: 0.5rem 0; }
+.message-text pre code { background: none; padding: 0; }
+.message-text a { color: var(--primary); }
+";
+
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+fn conversation_title(conversation: &Conversation) -> &str {
+    conversation
+        .name
+        .as_deref()
+        .filter(|name| !name.is_empty())
+        .unwrap_or("Untitled conversation")
+}
+
+fn role_label(role: Role) -> &'static str {
+    match role {
+        Role::User => "You",
+        Role::Assistant => "FRIDAY",
+        Role::System => "System",
+        Role::Tool => "Tool",
+    }
+}
+
+/// File-name-safe version of a title
+fn slug(title: &str) -> String {
+    let slug = title
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+        .to_lowercase();
+    if slug.is_empty() {
+        "conversation".into()
+    } else {
+        slug
+    }
+}
+
+/// Export to a file the user picks in a native save dialog. Resolves to the
+/// written path, or `None` if the dialog was cancelled.
+#[tauri::command]
+pub async fn export_conversations(
+    app: AppHandle,
+    scope: ExportScope,
+    format: ExportFormat,
+) -> Result<Option<PathBuf>, String> {
+    let selection = Selection::collect(&*store::managed(&app)?, &scope)?;
+    let contents = selection.render(format)?;
+
+    let file_name = format!("{}.{}", selection.file_stem, format.extension());
+    let path = tauri::async_runtime::spawn_blocking(move || {
+        FileDialogBuilder::new()
+            .set_title("Export conversations")
+            .set_file_name(&file_name)
+            .add_filter(format.filter_name(), &[format.extension()])
+            .save_file()
+    })
+    .await
+    .map_err(|e| e.to_string())?;
+
+    let Some(path) = path else {
+        return Ok(None);
+    };
+    fs::write(&path, contents).map_err(|e| format!("could not write {}: {e}", path.display()))?;
+    Ok(Some(path))
+}
@@ -0,0 +1,442 @@
+// FRIDAY AI Assistant - Import
+// Reads a JSON bundle written by `export`, validates every project and
+// conversation against the backend schema, and merges it into the store.
+// Colliding IDs are skipped, overwritten or duplicated under fresh IDs.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use rusqlite::{Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tauri::api::dialog::blocking::FileDialogBuilder;
+use tauri::{AppHandle, Manager};
+
+use crate::export::{BUNDLE_FORMAT, BUNDLE_VERSION};
+use crate::store::{self, Conversation, Project, Store};
+
+/// What to do when a bundle entry has the same ID as something in the store
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictPolicy {
+    /// Keep the local copy
+    #[default]
+    Skip,
+    /// Replace the local copy with the bundle's
+    Overwrite,
+    /// Import the bundle's copy under a new ID
+    Duplicate,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EntryKind {
+    Project,
+    Conversation,
+}
+
+/// A bundle entry whose ID already exists locally
+#[derive(Debug, Clone, Serialize)]
+pub struct Conflict {
+    pub kind: EntryKind,
+    pub id: String,
+    /// Name of the local copy
+    pub local_name: Option<String>,
+    /// Name of the bundle's copy
+    pub bundle_name: Option<String>,
+}
+
+/// A bundle entry that failed validation and will not be imported
+#[derive(Debug, Clone, Serialize)]
+pub struct Rejected {
+    pub kind: EntryKind,
+    /// Position in the bundle's list
+    pub index: usize,
+    pub id: Option<String>,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ImportPreview {
+    pub path: PathBuf,
+    pub exported_at: String,
+    pub version: u32,
+    pub projects: usize,
+    pub conversations: usize,
+    pub conflicts: Vec<Conflict>,
+    pub rejected: Vec<Rejected>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Duplicated {
+    pub from: String,
+    pub to: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct Outcome {
+    pub imported: Vec<String>,
+    pub overwritten: Vec<String>,
+    pub duplicated: Vec<Duplicated>,
+    pub skipped: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ImportReport {
+    pub projects: Outcome,
+    pub conversations: Outcome,
+    pub rejected: Vec<Rejected>,
+    /// `conversation_ids` dropped from projects because neither the bundle nor
+    /// the store has that conversation
+    pub unlinked: Vec<String>,
+}
+
+/// A bundle split into entries that passed validation and ones that did not
+struct Validated {
+    exported_at: String,
+    version: u32,
+    projects: Vec<Project>,
+    conversations: Vec<Conversation>,
+    rejected: Vec<Rejected>,
+}
+
+impl Validated {
+    fn read(path: &Path) -> Result<Self, String> {
+        let json = fs::read_to_string(path)
+            .map_err(|e| format!("could not read {}: {e}", path.display()))?;
+        let bundle: Value =
+            serde_json::from_str(&json).map_err(|e| format!("not a JSON file: {e}"))?;
+
+        if bundle.get("format").and_then(Value::as_str) != Some(BUNDLE_FORMAT) {
+            return Err("not a FRIDAY export bundle".into());
+        }
+        let version = bundle
+            .get("version")
+            .and_then(Value::as_u64)
+            .ok_or("bundle has no version")? as u32;
+        if version > BUNDLE_VERSION {
+            return Err(format!(
+                "bundle version {version} is newer than this FRIDAY supports ({BUNDLE_VERSION})"
+            ));
+        }
+
+        let mut rejected = Vec::new();
+        let projects = entries(
+            &bundle,
+            "projects",
+            EntryKind::Project,
+            &mut rejected,
+            |p: &Project| {
+                check_id(&p.id)?;
+                check_timestamp(&p.created_at)?;
+                check_timestamp(&p.updated_at)
+            },
+        );
+        let conversations = entries(
+            &bundle,
+            "conversations",
+            EntryKind::Conversation,
+            &mut rejected,
+            |c: &Conversation| {
+                check_id(&c.id)?;
+                check_timestamp(&c.created_at)?;
+                check_timestamp(&c.updated_at)?;
+                for (i, message) in c.messages.iter().enumerate() {
+                    check_timestamp(&message.timestamp).map_err(|e| format!("message {i}: {e}"))?;
+                }
+                Ok(())
+            },
+        );
+
+        Ok(Self {
+            exported_at: bundle
+                .get("exported_at")
+                .and_then(Value::as_str)
+                .unwrap_or_default()
+                .to_string(),
+            version,
+            projects,
+            conversations,
+            rejected,
+        })
+    }
+}
+
+/// Deserialize each element of `bundle[key]` on its own, so one bad entry
+/// rejects only itself
+fn entries<T: serde::de::DeserializeOwned>(
+    bundle: &Value,
+    key: &str,
+    kind: EntryKind,
+    rejected: &mut Vec<Rejected>,
+    check: impl Fn(&T) -> Result<(), String>,
+) -> Vec<T> {
+    let items = bundle.get(key).and_then(Value::as_array);
+    let mut valid = Vec::new();
+    for (index, item) in items.into_iter().flatten().enumerate() {
+        let id = item.get("id").and_then(Value::as_str).map(str::to_string);
+        match serde_json::from_value::<T>(item.clone())
+            .map_err(|e| e.to_string())
+            .and_then(|entry| check(&entry).map(|_| entry))
+        {
+            Ok(entry) => valid.push(entry),
+            Err(reason) => rejected.push(Rejected {
+                kind,
+                index,
+                id,
+                reason,
+            }),
+        }
+    }
+    valid
+}
+
+fn check_id(id: &str) -> Result<(), String> {
+    if id.trim().is_empty() {
+        Err("empty id".into())
+    } else {
+        Ok(())
+    }
+}
+
+fn check_timestamp(timestamp: &str) -> Result<(), String> {
+    let normalized = store::normalize_timestamp(timestamp);
+    chrono::NaiveDateTime::parse_from_str(&normalized, "%Y-%m-%dT%H:%M:%S%.f")
+        .map(|_| ())
+        .or_else(|_| chrono::DateTime::parse_from_rfc3339(&normalized).map(|_| ()))
+        .map_err(|_| format!("invalid timestamp {timestamp:?}"))
+}
+
+fn local_name(
+    conn: &Connection,
+    kind: EntryKind,
+    id: &str,
+) -> rusqlite::Result<Option<Option<String>>> {
+    let sql = match kind {
+        EntryKind::Project => "SELECT name FROM projects WHERE id = ?1",
+        EntryKind::Conversation => "SELECT name FROM conversations WHERE id = ?1",
+    };
+    conn.query_row(sql, [id], |row| row.get(0)).optional()
+}
+
+impl Store {
+    fn preview_import(&self, path: PathBuf) -> Result<ImportPreview, String> {
+        let bundle = Validated::read(&path)?;
+        let conn = self.conn();
+
+        let mut conflicts = Vec::new();
+        let entries = bundle
+            .projects
+            .iter()
+            .map(|p| (EntryKind::Project, &p.id, Some(p.name.clone())))
+            .chain(
+                bundle
+                    .conversations
+                    .iter()
+                    .map(|c| (EntryKind::Conversation, &c.id, c.name.clone())),
+            );
+        for (kind, id, bundle_name) in entries {
+            if let Some(local_name) = local_name(&conn, kind, id).map_err(|e| e.to_string())? {
+                conflicts.push(Conflict {
+                    kind,
+                    id: id.clone(),
+                    local_name,
+                    bundle_name,
+                });
+            }
+        }
+
+        Ok(ImportPreview {
+            path,
+            exported_at: bundle.exported_at,
+            version: bundle.version,
+            projects: bundle.projects.len(),
+            conversations: bundle.conversations.len(),
+            conflicts,
+            rejected: bundle.rejected,
+        })
+    }
+
+    /// Merge a bundle in one transaction. `overrides` picks a policy per
+    /// conflicting ID; everything else follows `on_conflict`.
+    fn import_bundle(
+        &self,
+        path: &Path,
+        on_conflict: ConflictPolicy,
+        overrides: &HashMap<String, ConflictPolicy>,
+    ) -> Result<ImportReport, String> {
+        let Validated {
+            mut projects,
+            mut conversations,
+            rejected,
+            ..
+        } = Validated::read(path)?;
+        let mut report = ImportReport {
+            rejected,
+            ..Default::default()
+        };
+
+        let mut conn = self.conn();
+        let tx = conn.transaction().map_err(|e| e.to_string())?;
+        let err = |e: rusqlite::Error| e.to_string();
+
+        // Decide every entry first so IDs can be remapped before anything is written
+        let mut project_ids = HashMap::new();
+        let mut keep_projects = Vec::new();
+        for mut project in projects.drain(..) {
+            let policy = overrides.get(&project.id).copied().unwrap_or(on_conflict);
+            let exists = local_name(&tx, EntryKind::Project, &project.id)
+                .map_err(err)?
+                .is_some();
+            match (exists, policy) {
+                (false, _) => report.projects.imported.push(project.id.clone()),
+                (true, ConflictPolicy::Skip) => {
+                    report.projects.skipped.push(project.id.clone());
+                    continue;
+                }
+                (true, ConflictPolicy::Overwrite) => {
+                    report.projects.overwritten.push(project.id.clone())
+                }
+                (true, ConflictPolicy::Duplicate) => {
+                    let id = uuid::Uuid::new_v4().to_string();
+                    report.projects.duplicated.push(Duplicated {
+                        from: project.id.clone(),
+                        to: id.clone(),
+                    });
+                    project_ids.insert(project.id.clone(), id.clone());
+                    project.id = id;
+                    project.name = format!("{} (imported)", project.name);
+                }
+            }
+            keep_projects.push(project);
+        }
+
+        let mut conversation_ids = HashMap::new();
+        let mut keep_conversations = Vec::new();
+        for mut conversation in conversations.drain(..) {
+            let policy = overrides
+                .get(&conversation.id)
+                .copied()
+                .unwrap_or(on_conflict);
+            let exists = local_name(&tx, EntryKind::Conversation, &conversation.id)
+                .map_err(err)?
+                .is_some();
+            match (exists, policy) {
+                (false, _) => report.conversations.imported.push(conversation.id.clone()),
+                (true, ConflictPolicy::Skip) => {
+                    report.conversations.skipped.push(conversation.id.clone());
+                    continue;
+                }
+                (true, ConflictPolicy::Overwrite) => report
+                    .conversations
+                    .overwritten
+                    .push(conversation.id.clone()),
+                (true, ConflictPolicy::Duplicate) => {
+                    let id = uuid::Uuid::new_v4().to_string();
+                    report.conversations.duplicated.push(Duplicated {
+                        from: conversation.id.clone(),
+                        to: id.clone(),
+                    });
+                    conversation_ids.insert(conversation.id.clone(), id.clone());
+                    conversation.id = id;
+                    conversation.name = conversation.name.map(|name| format!("{name} (imported)"));
+                }
+            }
+            if let Some(project_id) = &conversation.project_id {
+                if let Some(new_id) = project_ids.get(project_id) {
+                    conversation.project_id = Some(new_id.clone());
+                }
+            }
+            keep_conversations.push(conversation);
+        }
+
+        // Re-link projects to the IDs their conversations ended up with
+        for project in &mut keep_projects {
+            let mut linked = Vec::new();
+            for id in project.conversation_ids.drain(..) {
+                let id = conversation_ids.get(&id).cloned().unwrap_or(id);
+                let known = keep_conversations.iter().any(|c| c.id == id)
+                    || local_name(&tx, EntryKind::Conversation, &id)
+                        .map_err(err)?
+                        .is_some();
+                if known {
+                    linked.push(id);
+                } else {
+                    report.unlinked.push(id);
+                }
+            }
+            project.conversation_ids = linked;
+            store::upsert_project(&tx, project).map_err(err)?;
+        }
+        for conversation in &keep_conversations {
+            store::upsert_conversation(&tx, conversation).map_err(err)?;
+        }
+
+        tx.commit().map_err(err)?;
+        Ok(report)
+    }
+}
+
+/// Read a bundle (picked in a native open dialog when `path` is omitted) and
+/// report what importing it would do. Resolves to `None` if the dialog was cancelled.
+#[tauri::command]
+pub async fn preview_import(
+    app: AppHandle,
+    path: Option<PathBuf>,
+) -> Result<Option<ImportPreview>, String> {
+    let path = match path {
+        Some(path) => Some(path),
+        None => tauri::async_runtime::spawn_blocking(|| {
+            FileDialogBuilder::new()
+                .set_title("Import conversations")
+                .add_filter("FRIDAY bundle", &["json"])
+                .pick_file()
+        })
+        .await
+        .map_err(|e| e.to_string())?,
+    };
+    match path {
+        Some(path) => store::managed(&app)?.preview_import(path).map(Some),
+        None => Ok(None),
+    }
+}
+
+/// Import a bundle previously shown by `preview_import`
+#[tauri::command]
+pub fn import_bundle(
+    app: AppHandle,
+    path: PathBuf,
+    on_conflict: Option<ConflictPolicy>,
+    overrides: Option<HashMap<String, ConflictPolicy>>,
+) -> Result<ImportReport, String> {
+    let report = store::managed(&app)?.import_bundle(
+        &path,
+        on_conflict.unwrap_or_default(),
+        &overrides.unwrap_or_default(),
+    )?;
+    let _ = app.emit_all("store://imported", &report);
+
+    // The backend keeps its own copy of each conversation it chats in; give it
+    // the imported history so new messages continue it instead of starting over
+    let written = &report.conversations;
+    let ids: Vec<String> = written
+        .imported
+        .iter()
+        .chain(&written.overwritten)
+        .chain(written.duplicated.iter().map(|d| &d.to))
+        .cloned()
+        .collect();
+    tauri::async_runtime::spawn(async move {
+        for id in ids {
+            let conversation = match store::managed(&app).map(|s| s.conversation(&id)) {
+                Ok(Ok(Some(conversation))) => conversation,
+                _ => continue,
+            };
+            if let Err(e) = store::push_to_backend(&app, &conversation).await {
+                eprintln!("[import] could not hand {id} to the backend: {e}");
+            }
+        }
+    });
+    Ok(report)
+}
@@ -2,10 +2,179 @@
 // Prevents additional console window on Windows in release
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
+mod api;
+mod approvals;
+mod audit;
+mod backend;
+mod backup;
+mod chat;
+mod deep_link;
+mod encryption;
+mod export;
+mod import;
+mod mini;
+mod notifications;
+mod overlay;
+mod sandbox;
+mod sanitize;
+mod search;
+mod secrets;
+mod settings;
+mod shortcuts;
+mod single_instance;
+mod store;
+mod stream;
+mod tray;
+mod window_state;
+
+use approvals::Approvals;
+use audit::AuditLog;
+use backend::BackendSupervisor;
+use backup::Backups;
+use chat::ChatStreams;
+use deep_link::DeepLinks;
+use encryption::Encryption;
+use mini::Mini;
+use overlay::Overlay;
+use settings::Settings;
+use shortcuts::Shortcuts;
+use single_instance::{Instance, LaunchAttachments};
+use tauri::{Manager, RunEvent, WindowEvent};
+use window_state::WindowStates;
+
 fn main() {
-    tauri::Builder::default()
-        .invoke_handler(tauri::generate_handler![])
-        .run(tauri::generate_context!())
-        .expect("error while running FRIDAY application");
-}
+    // Code snippets run in a copy of this binary; see sandbox.rs
+    if std::env::args().nth(1).as_deref() == Some(sandbox::SUBCOMMAND) {
+        std::process::exit(sandbox::run_subcommand());
+    }
+
+    let context = tauri::generate_context!();
 
+    // A second launch forwards its arguments to the running FRIDAY and exits
+    // before it can start another backend
+    let instance_listener = match single_instance::acquire(context.config()) {
+        Instance::Primary(listener) => listener,
+        Instance::Forwarded => return,
+    };
+
+    let supervisor = BackendSupervisor::new(backend::pick_port());
+    backend::publish(context.config(), &supervisor);
+
+    let app = tauri::Builder::default()
+        .manage(supervisor)
+        .manage(ChatStreams::default())
+        .manage(Encryption::default())
+        .manage(Backups::default())
+        .manage(Settings::default())
+        .manage(Shortcuts::default())
+        .manage(WindowStates::default())
+        .manage(Mini::default())
+        .manage(Overlay::default())
+        .manage(DeepLinks::default())
+        .manage(LaunchAttachments::default())
+        .manage(Approvals::default())
+        .manage(AuditLog::default())
+        .system_tray(tray::build())
+        .on_system_tray_event(tray::handle_event)
+        .on_window_event(|event| {
+            if !overlay::is_overlay(event.window()) {
+                window_state::track(event.window(), event.event());
+            }
+            if let WindowEvent::CloseRequested { api, .. } = event.event() {
+                window_state::save(&event.window().app_handle());
+                tray::hide_on_close(event.window(), api);
+            }
+        })
+        .setup(move |app| {
+            single_instance::listen(app.handle(), instance_listener);
+            window_state::init(&app.handle());
+            tray::watch_backend(&app.handle());
+            shortcuts::init(&app.handle());
+            notifications::start(&app.handle());
+            backup::start(&app.handle());
+            settings::init(&app.handle());
+            sandbox::init(&app.handle());
+            approvals::start(&app.handle());
+            audit::start(&app.handle());
+            // Opens the store and starts the backend once the data key is available
+            encryption::init(&app.handle());
+            deep_link::register();
+            let args: Vec<String> = std::env::args().skip(1).collect();
+            if let Some(link) = deep_link::find(&args) {
+                deep_link::handle(&app.handle(), link);
+            }
+            Ok(())
+        })
+        .invoke_handler(tauri::generate_handler![
+            backend::backend_url,
+            backend::backend_status,
+            backend::backend_restart,
+            api::backend_call,
+            chat::chat_stream,
+            chat::chat_cancel,
+            sanitize::render_markdown,
+            shortcuts::get_shortcuts,
+            shortcuts::set_shortcuts,
+            single_instance::read_launch_attachment,
+            store::list_conversations,
+            store::get_conversation,
+            store::create_conversation,
+            store::rename_conversation,
+            store::delete_conversation,
+            store::delete_all_conversations,
+            store::list_projects,
+            store::get_project,
+            store::create_project,
+            store::rename_project,
+            store::delete_project,
+            search::search_conversations,
+            export::export_conversations,
+            import::preview_import,
+            import::import_bundle,
+            encryption::data_lock_status,
+            encryption::unlock_data,
+            encryption::set_data_passphrase,
+            encryption::rotate_data_key,
+            encryption::export_unencrypted,
+            secrets::set_secret,
+            secrets::has_secret,
+            secrets::list_secrets,
+            secrets::delete_secret,
+            backup::get_backup_config,
+            backup::set_backup_config,
+            backup::list_backups,
+            backup::create_backup,
+            backup::preview_restore,
+            backup::restore_backup,
+            settings::get_settings,
+            settings::default_settings,
+            settings::validate_settings,
+            settings::set_settings,
+            mini::toggle_mini_window,
+            mini::get_mini_state,
+            mini::publish_mini_state,
+            mini::mini_ask,
+            overlay::show_gesture_overlay,
+            overlay::hide_gesture_overlay,
+            overlay::gesture_overlay_event,
+            deep_link::take_pending_deep_links,
+            deep_link::run_linked_routine,
+            sandbox::run_code,
+            approvals::get_tool_policy,
+            approvals::set_tool_policy,
+            approvals::forget_tool_decisions,
+            audit::audit_log,
+            audit::verify_audit_log
+        ])
+        .build(context)
+        .expect("error while building FRIDAY application");
+
+    app.run(|app_handle, event| {
+        if let RunEvent::Exit = event {
+            window_state::save(app_handle);
+            // Quitting (from the tray): let the backend run its shutdown hook
+            let supervisor = app_handle.state::<BackendSupervisor>().inner().clone();
+            tauri::async_runtime::block_on(supervisor.stop());
+        }
+    });
+}
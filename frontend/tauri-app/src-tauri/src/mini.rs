@@ -0,0 +1,126 @@
+// FRIDAY AI Assistant - Mini Assistant Window
+// Compact frameless, always-on-top window (mini.html) showing the listening
+// state, the last response and a text box. The main window stays the owner of
+// the conversation: it publishes `mini://state` and answers `mini://ask`.
+
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use tauri::{
+    AppHandle, Manager, PhysicalPosition, Position, State, Window, WindowBuilder, WindowUrl,
+};
+
+use crate::window_state;
+
+pub const MINI_WINDOW: &str = "mini";
+const SIZE: (f64, f64) = (380.0, 240.0);
+/// Logical pixels between the window and the screen edge on first open
+const MARGIN: f64 = 24.0;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ListenState {
+    #[default]
+    Idle,
+    /// Waiting for "Hey FRIDAY"
+    WakeWord,
+    /// Capturing a spoken command
+    Listening,
+    /// Waiting for or streaming the reply
+    Thinking,
+    Speaking,
+}
+
+/// What the mini window shows, published by the main window
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MiniState {
+    pub listening: ListenState,
+    pub last_response: Option<String>,
+    pub conversation_id: Option<String>,
+}
+
+#[derive(Default)]
+pub struct Mini {
+    state: Mutex<MiniState>,
+}
+
+/// Show the mini window, creating it on first use, or hide it
+pub fn toggle(app: &AppHandle) {
+    match app.get_window(MINI_WINDOW) {
+        Some(window) if window.is_visible().unwrap_or(false) => {
+            let _ = window.hide();
+        }
+        Some(window) => show(&window),
+        None => match build(app) {
+            Ok(window) => show(&window),
+            Err(e) => eprintln!("[mini] could not create window: {e}"),
+        },
+    }
+}
+
+fn show(window: &Window) {
+    let _ = window.show();
+    let _ = window.set_focus();
+}
+
+fn build(app: &AppHandle) -> tauri::Result<Window> {
+    let window = WindowBuilder::new(app, MINI_WINDOW, WindowUrl::App("mini.html".into()))
+        .title("FRIDAY Mini")
+        .inner_size(SIZE.0, SIZE.1)
+        .decorations(false)
+        .always_on_top(true)
+        .resizable(false)
+        .skip_taskbar(true)
+        .visible(false)
+        .build()?;
+
+    place_bottom_right(&window);
+    // A saved position from an earlier session wins over the default corner
+    window_state::restore(&window);
+    Ok(window)
+}
+
+fn place_bottom_right(window: &Window) {
+    let Ok(Some(monitor)) = window.primary_monitor() else {
+        return;
+    };
+    let scale = monitor.scale_factor();
+    let (width, height) = (
+        ((SIZE.0 + MARGIN) * scale) as i32,
+        ((SIZE.1 + MARGIN * 2.0) * scale) as i32,
+    );
+    let _ = window.set_position(Position::Physical(PhysicalPosition::new(
+        monitor.position().x + monitor.size().width as i32 - width,
+        monitor.position().y + monitor.size().height as i32 - height,
+    )));
+}
+
+#[tauri::command]
+pub fn toggle_mini_window(app: AppHandle) {
+    toggle(&app);
+}
+
+/// Current state, for the mini window when it opens
+#[tauri::command]
+pub fn get_mini_state(mini: State<'_, Mini>) -> MiniState {
+    mini.state.lock().unwrap().clone()
+}
+
+/// Called by the main window whenever listening or the conversation changes
+#[tauri::command]
+pub fn publish_mini_state(app: AppHandle, mini: State<'_, Mini>, state: MiniState) {
+    *mini.state.lock().unwrap() = state.clone();
+    let _ = app.emit_all("mini://state", state);
+}
+
+/// Text typed into the mini window, handed to the main window's chat
+#[tauri::command]
+pub fn mini_ask(app: AppHandle, text: String) -> Result<(), String> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Err("nothing to ask".into());
+    }
+    let _ = app.emit_all("mini://ask", text);
+    Ok(())
+}
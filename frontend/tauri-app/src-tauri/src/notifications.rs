@@ -0,0 +1,207 @@
+// FRIDAY AI Assistant - Native Notifications
+// Polls the backend for due reminders, finished timers and ringing alarms and
+// raises them as OS notifications with Snooze / Dismiss / Open buttons. The
+// user's choice goes back to the backend and out as `notification://action`.
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use tauri::{AppHandle, Manager};
+
+use crate::backend::{BackendStatus, BackendSupervisor};
+use crate::tray;
+
+const POLL_INTERVAL: Duration = Duration::from_secs(5);
+const SNOOZE_MINUTES: u32 = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DueKind {
+    Reminder,
+    Timer,
+    Alarm,
+}
+
+/// Something that came due, as returned by `/api/notifications/due`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DueEvent {
+    pub kind: DueKind,
+    pub id: String,
+    pub title: String,
+    #[serde(default)]
+    pub body: String,
+    pub conversation_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationAction {
+    Snooze,
+    Dismiss,
+    /// A click on the button or on the notification body
+    Open,
+}
+
+impl NotificationAction {
+    fn from_id(id: &str) -> Option<Self> {
+        match id {
+            "snooze" => Some(Self::Snooze),
+            "dismiss" => Some(Self::Dismiss),
+            "open" | "default" => Some(Self::Open),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Serialize)]
+struct ActionEvent<'a> {
+    action: NotificationAction,
+    #[serde(flatten)]
+    event: &'a DueEvent,
+}
+
+/// Poll for due events while the backend is up. Called once from `setup`.
+pub fn start(app: &AppHandle) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        loop {
+            tokio::time::sleep(POLL_INTERVAL).await;
+
+            let backend = app.state::<BackendSupervisor>();
+            if backend.status() != BackendStatus::Ready {
+                continue;
+            }
+
+            let url = format!("{}/api/notifications/due", backend.base_url());
+            let events = match backend.client().get(&url).send().await {
+                Ok(resp) => resp.json::<Vec<DueEvent>>().await,
+                Err(_) => continue,
+            };
+            match events {
+                Ok(events) => events.into_iter().for_each(|event| show(&app, event)),
+                Err(e) => eprintln!("[notifications] bad response from {url}: {e}"),
+            }
+        }
+    });
+}
+
+/// Act on the user's choice: tell the backend, focus the window for `Open`,
+/// and emit `notification://action` so the frontend can route to the item.
+fn handle_action(app: &AppHandle, event: &DueEvent, action: NotificationAction) {
+    let _ = app.emit_all("notification://action", ActionEvent { action, event });
+
+    let (path, body) = match action {
+        NotificationAction::Open => {
+            tray::show_main_window(app);
+            return;
+        }
+        NotificationAction::Snooze => (
+            "/api/notifications/snooze",
+            json!({
+                "kind": event.kind,
+                "id": event.id,
+                "title": event.title,
+                "minutes": SNOOZE_MINUTES,
+                // Snoozed timers and alarms come back as new timers
+                "conversation_id": event.conversation_id,
+            }),
+        ),
+        NotificationAction::Dismiss => (
+            "/api/notifications/dismiss",
+            json!({ "kind": event.kind, "id": event.id }),
+        ),
+    };
+
+    let backend = app.state::<BackendSupervisor>().inner().clone();
+    tauri::async_runtime::spawn(async move {
+        let url = format!("{}{path}", backend.base_url());
+        let result = backend
+            .client()
+            .post(&url)
+            .json(&body)
+            .send()
+            .await
+            .and_then(|r| r.error_for_status());
+        if let Err(e) = result {
+            eprintln!("[notifications] {path} failed: {e}");
+        }
+    });
+}
+
+fn open_label(event: &DueEvent) -> &'static str {
+    match (event.kind, &event.conversation_id) {
+        (_, Some(_)) => "Open conversation",
+        (DueKind::Timer, None) => "Open timers",
+        _ => "Open FRIDAY",
+    }
+}
+
+#[cfg(windows)]
+fn show(app: &AppHandle, event: DueEvent) {
+    use tauri_winrt_notification::Toast;
+
+    let handle = app.clone();
+    let title = event.title.clone();
+    let body = event.body.clone();
+    let open = open_label(&event);
+    let result = Toast::new(Toast::POWERSHELL_APP_ID)
+        .title(&title)
+        .text1(&body)
+        .add_button("Snooze", "snooze")
+        .add_button("Dismiss", "dismiss")
+        .add_button(open, "open")
+        .on_activated(move |action| {
+            // Clicking the toast body activates it without an argument
+            let action = action
+                .as_deref()
+                .and_then(NotificationAction::from_id)
+                .unwrap_or(NotificationAction::Open);
+            handle_action(&handle, &event, action);
+            Ok(())
+        })
+        .show();
+    if let Err(e) = result {
+        eprintln!("[notifications] could not show toast: {e}");
+    }
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+fn show(app: &AppHandle, event: DueEvent) {
+    let notification = notify_rust::Notification::new()
+        .appname("FRIDAY")
+        .summary(&event.title)
+        .body(&event.body)
+        .action("default", open_label(&event))
+        .action("snooze", "Snooze")
+        .action("dismiss", "Dismiss")
+        .action("open", open_label(&event))
+        .show();
+
+    match notification {
+        Ok(handle) => {
+            let app = app.clone();
+            // Blocks until the notification is acted on or closed
+            std::thread::spawn(move || {
+                handle.wait_for_action(|id| {
+                    if let Some(action) = NotificationAction::from_id(id) {
+                        handle_action(&app, &event, action);
+                    }
+                });
+            });
+        }
+        Err(e) => eprintln!("[notifications] could not show notification: {e}"),
+    }
+}
+
+/// macOS notifications from an unbundled process cannot carry action buttons
+#[cfg(target_os = "macos")]
+fn show(_app: &AppHandle, event: DueEvent) {
+    if let Err(e) = notify_rust::Notification::new()
+        .summary(&event.title)
+        .body(&event.body)
+        .show()
+    {
+        eprintln!("[notifications] could not show notification: {e}");
+    }
+}
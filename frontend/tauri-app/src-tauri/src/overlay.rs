@@ -0,0 +1,236 @@
+// FRIDAY AI Assistant - Gesture Overlay
+// One transparent, click-through, always-on-top window per monitor
+// (overlay.html) that draws the gesture cursor on top of other apps. Monitor
+// geometry comes from the backend's /api/display/monitors, the same rectangles
+// the OS-level cursor control uses; Tauri's own monitor list is the fallback
+// where that endpoint is unavailable.
+
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use tauri::{
+    AppHandle, Manager, Monitor, PhysicalPosition, PhysicalSize, Position, Size, State, Window,
+    WindowBuilder, WindowUrl,
+};
+
+use crate::backend::BackendSupervisor;
+
+const OVERLAY_PREFIX: &str = "overlay-";
+
+/// A monitor in virtual-desktop pixels, as reported by /api/display/monitors
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MonitorRect {
+    pub left: i32,
+    pub top: i32,
+    pub width: u32,
+    pub height: u32,
+    #[serde(default)]
+    pub is_primary: bool,
+    #[serde(default)]
+    pub device: String,
+}
+
+impl MonitorRect {
+    fn contains(&self, x: f64, y: f64) -> bool {
+        let (left, top) = (self.left as f64, self.top as f64);
+        x >= left && x < left + self.width as f64 && y >= top && y < top + self.height as f64
+    }
+
+    fn from_monitor(monitor: &Monitor, is_primary: bool) -> Self {
+        Self {
+            left: monitor.position().x,
+            top: monitor.position().y,
+            width: monitor.size().width,
+            height: monitor.size().height,
+            is_primary,
+            device: monitor.name().cloned().unwrap_or_default(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct MonitorsResponse {
+    success: bool,
+    #[serde(default)]
+    monitors: Vec<MonitorRect>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Gesture {
+    Move,
+    Pinch,
+    Grab,
+    Release,
+    Swipe,
+    /// The hand left the camera's view
+    Lost,
+}
+
+/// Sent by the gesture tracker in virtual-desktop pixels
+#[derive(Debug, Clone, Deserialize)]
+pub struct GestureEvent {
+    pub gesture: Gesture,
+    #[serde(default)]
+    pub x: f64,
+    #[serde(default)]
+    pub y: f64,
+    /// Extra context, e.g. the swipe direction
+    #[serde(default)]
+    pub detail: Option<String>,
+}
+
+/// What one overlay draws, relative to its own monitor
+#[derive(Debug, Clone, Serialize)]
+struct OverlayCursor {
+    gesture: Gesture,
+    x: f64,
+    y: f64,
+    detail: Option<String>,
+}
+
+#[derive(Default)]
+pub struct Overlay {
+    monitors: Mutex<Vec<MonitorRect>>,
+    /// Index of the monitor the cursor is currently drawn on
+    current: Mutex<Option<usize>>,
+}
+
+pub fn is_overlay(window: &Window) -> bool {
+    window.label().starts_with(OVERLAY_PREFIX)
+}
+
+fn label(index: usize) -> String {
+    format!("{OVERLAY_PREFIX}{index}")
+}
+
+async fn backend_monitors(supervisor: &BackendSupervisor) -> Option<Vec<MonitorRect>> {
+    let url = format!("{}/api/display/monitors", supervisor.base_url());
+    let resp = supervisor.client().get(&url).send().await.ok()?;
+    let body: MonitorsResponse = resp.json().await.ok()?;
+    (body.success && !body.monitors.is_empty()).then_some(body.monitors)
+}
+
+fn tauri_monitors(window: &Window) -> Vec<MonitorRect> {
+    let primary = window.primary_monitor().ok().flatten();
+    window
+        .available_monitors()
+        .unwrap_or_default()
+        .iter()
+        .map(|m| {
+            let is_primary = primary
+                .as_ref()
+                .is_some_and(|p| p.position() == m.position() && p.name() == m.name());
+            MonitorRect::from_monitor(m, is_primary)
+        })
+        .collect()
+}
+
+/// Size, place and show the overlay for monitor `index`, creating it the
+/// first time
+fn place(app: &AppHandle, index: usize, rect: &MonitorRect) -> tauri::Result<Window> {
+    let window = match app.get_window(&label(index)) {
+        Some(window) => window,
+        None => WindowBuilder::new(app, label(index), WindowUrl::App("overlay.html".into()))
+            .title("FRIDAY Gesture Overlay")
+            .transparent(true)
+            .decorations(false)
+            .always_on_top(true)
+            .skip_taskbar(true)
+            .resizable(false)
+            .focused(false)
+            .visible(false)
+            .build()?,
+    };
+
+    window.set_size(Size::Physical(PhysicalSize::new(rect.width, rect.height)))?;
+    window.set_position(Position::Physical(PhysicalPosition::new(
+        rect.left, rect.top,
+    )))?;
+    // Clicks go straight through to whatever is underneath
+    window.set_ignore_cursor_events(true)?;
+    window.show()?;
+    Ok(window)
+}
+
+/// Hide the overlays for monitor `first` onwards. They are kept rather than
+/// closed: a closed window holds its label until it is destroyed, so
+/// rebuilding it straight away would fail.
+fn hide_from(app: &AppHandle, first: usize) {
+    for (label, window) in app.windows() {
+        let index = label
+            .strip_prefix(OVERLAY_PREFIX)
+            .and_then(|index| index.parse::<usize>().ok());
+        if index.is_some_and(|index| index >= first) {
+            let _ = window.hide();
+        }
+    }
+}
+
+/// Show one overlay per monitor, reusing any that are already open.
+/// Returns the geometry the tracker should map the cursor into.
+#[tauri::command]
+pub async fn show_gesture_overlay(
+    app: AppHandle,
+    window: Window,
+    supervisor: State<'_, BackendSupervisor>,
+    overlay: State<'_, Overlay>,
+) -> Result<Vec<MonitorRect>, String> {
+    let monitors = match backend_monitors(&supervisor).await {
+        Some(monitors) => monitors,
+        None => tauri_monitors(&window),
+    };
+    if monitors.is_empty() {
+        return Err("no monitors found".into());
+    }
+
+    for (index, rect) in monitors.iter().enumerate() {
+        place(&app, index, rect).map_err(|e| e.to_string())?;
+    }
+    hide_from(&app, monitors.len());
+
+    *overlay.monitors.lock().unwrap() = monitors.clone();
+    *overlay.current.lock().unwrap() = None;
+    Ok(monitors)
+}
+
+#[tauri::command]
+pub fn hide_gesture_overlay(app: AppHandle, overlay: State<'_, Overlay>) {
+    hide_from(&app, 0);
+    overlay.monitors.lock().unwrap().clear();
+    *overlay.current.lock().unwrap() = None;
+}
+
+/// Route a cursor/gesture update to the overlay of the monitor it falls on
+#[tauri::command]
+pub fn gesture_overlay_event(app: AppHandle, overlay: State<'_, Overlay>, event: GestureEvent) {
+    let monitors = overlay.monitors.lock().unwrap();
+    let mut current = overlay.current.lock().unwrap();
+
+    let target = match event.gesture {
+        Gesture::Lost => None,
+        _ => monitors.iter().position(|m| m.contains(event.x, event.y)),
+    };
+
+    if *current != target {
+        if let Some(window) = current.and_then(|index| app.get_window(&label(index))) {
+            let _ = window.emit("overlay://leave", ());
+        }
+        *current = target;
+    }
+
+    let Some(index) = target else {
+        return;
+    };
+    let Some(window) = app.get_window(&label(index)) else {
+        return;
+    };
+    let rect = &monitors[index];
+    let cursor = OverlayCursor {
+        gesture: event.gesture,
+        x: event.x - rect.left as f64,
+        y: event.y - rect.top as f64,
+        detail: event.detail,
+    };
+    let _ = window.emit("overlay://gesture", cursor);
+}
@@ -0,0 +1,1324 @@
+// FRIDAY AI Assistant - Code Sandbox
+// Runs python/javascript/bash snippets in an isolated child process with a
+// scratch working dir, a clean environment and CPU, memory and wall-clock
+// limits. The app re-executes itself as `friday-ai-assistant --sandbox-exec`
+// (JSON request on stdin, JSON result on stdout) so the isolation is set up in
+// a fresh single-threaded process; the backend's code_executor tool does the
+// same through FRIDAY_SANDBOX_EXE.
+//
+// On Linux the snippet also gets new user/PID/network/IPC/mount namespaces
+// (everything outside the scratch dir read-only, home and app data hidden),
+// rlimits including a process cap, and a seccomp filter. On Windows it runs in
+// a job object with memory, CPU-time and process limits; on macOS it gets the
+// same rlimits as Linux. Neither can block the network, so snippets there only
+// run when they ask for it. `isolation` in the result lists what applied.
+
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Stdio};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager, State};
+use tokio::io::AsyncWriteExt;
+
+use crate::backend::BackendSupervisor;
+use crate::settings::Settings;
+
+/// First argument that switches the app binary into the sandbox runner
+pub const SUBCOMMAND: &str = "--sandbox-exec";
+/// Tells the backend which executable to hand snippets to
+const EXE_ENV: &str = "FRIDAY_SANDBOX_EXE";
+/// App data and config directories, hidden from snippets along with $HOME
+const HIDDEN_ENV: &str = "FRIDAY_SANDBOX_HIDE";
+
+/// Same bounds as `code_execution_timeout` in settings
+const MAX_TIMEOUT_SECS: u64 = 600;
+const DEFAULT_TIMEOUT_SECS: u64 = 30;
+const DEFAULT_MEMORY_MB: u64 = 512;
+/// Per stream; anything beyond is dropped and `truncated` is set
+const MAX_OUTPUT_BYTES: usize = 1 << 20;
+const MAX_FILE_BYTES: u64 = 64 << 20;
+/// Processes (and threads, on Linux) a snippet may have running at once
+const MAX_PROCESSES: u64 = 64;
+const POLL_INTERVAL: Duration = Duration::from_millis(20);
+/// How long to wait for the pipes to close once the snippet's processes are gone
+const PIPE_GRACE: Duration = Duration::from_secs(2);
+const NETWORK_NOT_BLOCKED: &str =
+    "network access cannot be blocked on this system; the snippet must ask for network to run";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Language {
+    Python,
+    #[serde(alias = "js")]
+    Javascript,
+    #[serde(alias = "shell", alias = "cmd")]
+    Bash,
+}
+
+impl Language {
+    fn name(self) -> &'static str {
+        match self {
+            Language::Python => "python",
+            Language::Javascript => "javascript",
+            Language::Bash => "bash",
+        }
+    }
+
+    /// Script file name and the interpreter command that runs it
+    fn interpreter(self) -> (&'static str, Vec<String>) {
+        match self {
+            Language::Python => {
+                let python = std::env::var("FRIDAY_SANDBOX_PYTHON")
+                    .unwrap_or_else(|_| if cfg!(windows) { "python" } else { "python3" }.into());
+                ("main.py", vec![python, "-I".into(), "main.py".into()])
+            }
+            Language::Javascript => ("main.js", vec!["node".into(), "main.js".into()]),
+            Language::Bash if cfg!(windows) => (
+                "main.ps1",
+                ["powershell", "-NoProfile", "-NonInteractive"]
+                    .into_iter()
+                    .chain(["-ExecutionPolicy", "Bypass", "-File", "main.ps1"])
+                    .map(String::from)
+                    .collect(),
+            ),
+            Language::Bash => ("main.sh", vec!["bash".into(), "main.sh".into()]),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SandboxRequest {
+    pub language: Language,
+    pub code: String,
+    /// Defaults to 30 s, capped at 600 s
+    #[serde(default)]
+    pub timeout_secs: Option<u64>,
+    #[serde(default)]
+    pub memory_mb: Option<u64>,
+    /// Network access is off unless asked for
+    #[serde(default)]
+    pub network: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SandboxResult {
+    pub language: Language,
+    pub stdout: String,
+    pub stderr: String,
+    /// None when the process was killed by a signal
+    pub exit_code: Option<i32>,
+    pub signal: Option<i32>,
+    pub timed_out: bool,
+    /// Output went over the per-stream limit
+    pub truncated: bool,
+    pub duration_ms: u64,
+    /// The protections that were actually in place, e.g. `net_ns`, `seccomp`
+    pub isolation: Vec<String>,
+}
+
+/// What the runner prints when it cannot run the snippet at all
+#[derive(Serialize, Deserialize)]
+struct RunnerError {
+    error: String,
+}
+
+struct Limits {
+    timeout: Duration,
+    memory_bytes: u64,
+    network: bool,
+    /// Hidden from the snippet where the platform can (Linux mount namespace)
+    hidden: Vec<PathBuf>,
+}
+
+/// Let the backend find the runner, and tell it which app directories to
+/// hide from snippets. Called once from `setup`, before the backend starts.
+pub fn init(app: &AppHandle) {
+    let supervisor = app.state::<BackendSupervisor>();
+    if let Ok(exe) = std::env::current_exe() {
+        supervisor.set_env(EXE_ENV, &exe.to_string_lossy());
+    }
+    let resolver = app.path_resolver();
+    let dirs: Vec<PathBuf> = [resolver.app_data_dir(), resolver.app_config_dir()]
+        .into_iter()
+        .flatten()
+        .collect();
+    if let Ok(dirs) = std::env::join_paths(dirs) {
+        std::env::set_var(HIDDEN_ENV, &dirs);
+        supervisor.set_env(HIDDEN_ENV, &dirs.to_string_lossy());
+    }
+}
+
+/// Entry point for `--sandbox-exec`; returns the process exit code
+pub fn run_subcommand() -> i32 {
+    let mut input = String::new();
+    let outcome = std::io::stdin()
+        .read_to_string(&mut input)
+        .map_err(|e| format!("could not read request: {e}"))
+        .and_then(|_| {
+            serde_json::from_str::<SandboxRequest>(&input)
+                .map_err(|e| format!("invalid request: {e}"))
+        })
+        .and_then(|request| execute(&request));
+
+    let (json, code) = match outcome {
+        Ok(result) => (serde_json::to_string(&result), 0),
+        Err(error) => (serde_json::to_string(&RunnerError { error }), 1),
+    };
+    let mut stdout = std::io::stdout().lock();
+    let _ = stdout.write_all(json.unwrap_or_default().as_bytes());
+    let _ = stdout.flush();
+    code
+}
+
+fn execute(request: &SandboxRequest) -> Result<SandboxResult, String> {
+    let limits = Limits {
+        timeout: Duration::from_secs(
+            request
+                .timeout_secs
+                .unwrap_or(DEFAULT_TIMEOUT_SECS)
+                .clamp(1, MAX_TIMEOUT_SECS),
+        ),
+        memory_bytes: request.memory_mb.unwrap_or(DEFAULT_MEMORY_MB).max(16) << 20,
+        network: request.network,
+        hidden: hidden_dirs(),
+    };
+
+    let scratch = std::env::temp_dir().join(format!("friday-sandbox-{}", uuid::Uuid::new_v4()));
+    let result = run_in(&scratch, request, &limits);
+    let _ = std::fs::remove_dir_all(&scratch);
+    result
+}
+
+/// $HOME and the app's own directories: keys, tokens and the encrypted store
+fn hidden_dirs() -> Vec<PathBuf> {
+    let home = std::env::var_os("HOME").map(PathBuf::from);
+    let app_dirs = std::env::var_os(HIDDEN_ENV)
+        .map(|dirs| std::env::split_paths(&dirs).collect::<Vec<_>>())
+        .unwrap_or_default();
+    home.into_iter()
+        .chain(app_dirs)
+        .filter(|dir| dir.is_absolute() && dir.is_dir())
+        .collect()
+}
+
+fn run_in(
+    scratch: &Path,
+    request: &SandboxRequest,
+    limits: &Limits,
+) -> Result<SandboxResult, String> {
+    create_private_dir(scratch).map_err(|e| format!("could not create scratch dir: {e}"))?;
+    let (script, argv) = request.language.interpreter();
+    std::fs::write(scratch.join(script), &request.code)
+        .map_err(|e| format!("could not write script: {e}"))?;
+
+    let mut isolation = vec![
+        "scratch_dir".to_string(),
+        "clean_env".into(),
+        "wall_clock".into(),
+    ];
+    let mut cmd = Command::new(&argv[0]);
+    cmd.args(&argv[1..])
+        .current_dir(scratch)
+        .env_clear()
+        .envs(sandbox_env(scratch))
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    isolation.extend(platform::confine(&mut cmd, limits, scratch)?);
+
+    let started = Instant::now();
+    let mut child = cmd.spawn().map_err(|e| match e.kind() {
+        std::io::ErrorKind::NotFound => format!("{} is not installed", argv[0]),
+        _ => format!("could not start {}: {e}", argv[0]),
+    })?;
+    let tree = match platform::ProcessTree::contain(&child, limits) {
+        Ok(tree) => tree,
+        Err(e) => {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Err(e);
+        }
+    };
+    let stdout = capture(child.stdout.take());
+    let stderr = capture(child.stderr.take());
+
+    let (status, timed_out) = wait(&mut child, &tree, limits.timeout)?;
+    // Anything the snippet left running would hold the pipes open
+    tree.kill(&mut child);
+    let deadline = Instant::now() + PIPE_GRACE;
+    let (stdout, stdout_truncated) = stdout.collect(deadline);
+    let (stderr, stderr_truncated) = stderr.collect(deadline);
+
+    Ok(SandboxResult {
+        language: request.language,
+        stdout,
+        stderr,
+        exit_code: status.code(),
+        signal: platform::signal(&status),
+        timed_out,
+        truncated: stdout_truncated || stderr_truncated,
+        duration_ms: started.elapsed().as_millis() as u64,
+        isolation,
+    })
+}
+
+fn create_private_dir(dir: &Path) -> std::io::Result<()> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::DirBuilderExt;
+        std::fs::DirBuilder::new().mode(0o700).create(dir)
+    }
+    #[cfg(not(unix))]
+    std::fs::create_dir(dir)
+}
+
+/// Only what interpreters need to start; HOME and temp point at the scratch dir
+fn sandbox_env(scratch: &Path) -> Vec<(String, String)> {
+    let scratch = scratch.to_string_lossy().to_string();
+    let mut env = vec![
+        ("HOME".to_string(), scratch.clone()),
+        ("TMPDIR".into(), scratch.clone()),
+        ("TEMP".into(), scratch.clone()),
+        ("TMP".into(), scratch.clone()),
+        ("LANG".into(), "C.UTF-8".into()),
+        ("PYTHONDONTWRITEBYTECODE".into(), "1".into()),
+        ("PYTHONIOENCODING".into(), "utf-8".into()),
+    ];
+    // Windows programs fail in odd ways without these
+    for name in ["PATH", "SYSTEMROOT", "WINDIR", "PATHEXT", "COMSPEC"] {
+        if let Ok(value) = std::env::var(name) {
+            env.push((name.to_string(), value));
+        }
+    }
+    env
+}
+
+/// One pipe's output so far (at most MAX_OUTPUT_BYTES) and its reader thread
+struct Capture {
+    output: Arc<Mutex<(Vec<u8>, bool)>>,
+    reader: JoinHandle<()>,
+}
+
+impl Capture {
+    /// The output once the pipe closes. A process that escaped the kill could
+    /// hold it open for good, so after `deadline` this takes what was read.
+    fn collect(self, deadline: Instant) -> (String, bool) {
+        while !self.reader.is_finished() && Instant::now() < deadline {
+            std::thread::sleep(POLL_INTERVAL);
+        }
+        let (kept, truncated) = &*self.output.lock().unwrap();
+        (String::from_utf8_lossy(kept).into_owned(), *truncated)
+    }
+}
+
+/// Read a pipe to the end on its own thread
+fn capture(pipe: Option<impl Read + Send + 'static>) -> Capture {
+    let output = Arc::new(Mutex::new((Vec::new(), false)));
+    let shared = output.clone();
+    let reader = std::thread::spawn(move || {
+        let Some(mut pipe) = pipe else {
+            return;
+        };
+        let mut buf = [0u8; 8192];
+        while let Ok(n) = pipe.read(&mut buf) {
+            if n == 0 {
+                break;
+            }
+            let (kept, truncated) = &mut *shared.lock().unwrap();
+            let room = MAX_OUTPUT_BYTES.saturating_sub(kept.len());
+            kept.extend_from_slice(&buf[..n.min(room)]);
+            *truncated |= n > room;
+        }
+    });
+    Capture { output, reader }
+}
+
+fn wait(
+    child: &mut Child,
+    tree: &platform::ProcessTree,
+    timeout: Duration,
+) -> Result<(std::process::ExitStatus, bool), String> {
+    let deadline = Instant::now() + timeout;
+    loop {
+        if let Some(status) = child.try_wait().map_err(|e| e.to_string())? {
+            return Ok((status, false));
+        }
+        if Instant::now() >= deadline {
+            tree.kill(child);
+            let status = child.wait().map_err(|e| e.to_string())?;
+            return Ok((status, true));
+        }
+        std::thread::sleep(POLL_INTERVAL);
+    }
+}
+
+/// Run a snippet through the runner subprocess
+pub async fn run(request: &SandboxRequest) -> Result<SandboxResult, String> {
+    let exe = std::env::current_exe().map_err(|e| e.to_string())?;
+    let mut cmd = tokio::process::Command::new(exe);
+    cmd.arg(SUBCOMMAND)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .kill_on_drop(true);
+    #[cfg(windows)]
+    {
+        const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+        cmd.creation_flags(CREATE_NO_WINDOW);
+    }
+    let mut runner = cmd
+        .spawn()
+        .map_err(|e| format!("could not start sandbox: {e}"))?;
+
+    let input = serde_json::to_vec(request).map_err(|e| e.to_string())?;
+    if let Some(mut stdin) = runner.stdin.take() {
+        stdin.write_all(&input).await.map_err(|e| e.to_string())?;
+    }
+
+    // The runner enforces the limit itself; this only guards against a hung runner
+    let timeout = request
+        .timeout_secs
+        .unwrap_or(DEFAULT_TIMEOUT_SECS)
+        .clamp(1, MAX_TIMEOUT_SECS);
+    let output = tokio::time::timeout(Duration::from_secs(timeout + 15), runner.wait_with_output())
+        .await
+        .map_err(|_| "sandbox did not respond".to_string())?
+        .map_err(|e| e.to_string())?;
+
+    match serde_json::from_slice::<SandboxResult>(&output.stdout) {
+        Ok(result) => Ok(result),
+        Err(_) => match serde_json::from_slice::<RunnerError>(&output.stdout) {
+            Ok(RunnerError { error }) => Err(error),
+            Err(e) => Err(format!("bad sandbox output: {e}")),
+        },
+    }
+}
+
+/// Run a snippet from the UI, with the same timeout and language list as the
+/// backend's code execution tool
+#[tauri::command]
+pub async fn run_code(
+    settings: State<'_, Settings>,
+    language: Language,
+    code: String,
+    network: Option<bool>,
+) -> Result<SandboxResult, String> {
+    let settings = settings.current();
+    let allowed = settings
+        .allowed_languages
+        .split(',')
+        .any(|lang| lang.trim().eq_ignore_ascii_case(language.name()));
+    if !allowed {
+        return Err(format!("{} is not in allowed_languages", language.name()));
+    }
+
+    run(&SandboxRequest {
+        language,
+        code,
+        timeout_secs: Some(settings.code_execution_timeout.into()),
+        memory_mb: None,
+        network: network.unwrap_or(false),
+    })
+    .await
+}
+
+#[cfg(target_os = "linux")]
+mod platform {
+    use std::ffi::CString;
+    use std::os::unix::ffi::OsStrExt;
+    use std::os::unix::process::{CommandExt, ExitStatusExt};
+    use std::path::{Path, PathBuf};
+    use std::process::{Child, Command, ExitStatus};
+
+    use super::{Limits, MAX_FILE_BYTES, MAX_PROCESSES, NETWORK_NOT_BLOCKED};
+
+    /// A directory to cover with an empty tmpfs
+    struct Hidden {
+        dir: CString,
+        /// Paths under `dir` to mount back on the tmpfs (the scratch dir, the
+        /// interpreter's install prefix), each with the directories leading to it
+        keep: Vec<(CString, Vec<CString>)>,
+    }
+
+    /// At most the scratch dir and the interpreter prefix are kept
+    const KEPT: usize = 2;
+
+    /// The user, network, IPC and UTS namespaces are entered by the runner
+    /// itself, before any threads exist, so the snippet inherits them. The
+    /// forked child then sets rlimits, makes the filesystem read-only outside
+    /// `scratch` in a new mount namespace, enters a PID namespace and forks once
+    /// more: it stays behind as a reaper while the grandchild, PID 1 of the new
+    /// namespace, installs the seccomp filter and execs the interpreter.
+    pub fn confine(
+        cmd: &mut Command,
+        limits: &Limits,
+        scratch: &Path,
+    ) -> Result<Vec<String>, String> {
+        let mut isolation = namespaces(limits.network);
+        let user_ns = !isolation.is_empty();
+        let kernel = kernel_version().unwrap_or((0, 0));
+
+        let pid_ns = user_ns;
+        if pid_ns {
+            isolation.push("pid_ns".into());
+        }
+        // mount_setattr, for a recursive read-only remount, arrived in 5.12
+        let mount_ns = user_ns && kernel >= (5, 12);
+        let hidden = if mount_ns {
+            hidden(&limits.hidden, scratch, interpreter_prefix(cmd))
+        } else {
+            Vec::new()
+        };
+        let scratch = CString::new(scratch.as_os_str().as_bytes()).map_err(|e| e.to_string())?;
+        let root = CString::new("/").expect("no interior nul");
+        if mount_ns {
+            isolation.push("mount_ns".into());
+        }
+        if !hidden.is_empty() {
+            isolation.push("hidden_home".into());
+        }
+        let filter = seccomp::filter(limits.network);
+        if filter.is_some() {
+            isolation.push("seccomp".into());
+        }
+        // Either the network namespace or the filter's socket rule cuts it off
+        if !limits.network && !isolation.iter().any(|i| i == "net_ns") && filter.is_none() {
+            return Err(NETWORK_NOT_BLOCKED.into());
+        }
+        isolation.push("rlimits".into());
+
+        let cpu_secs = limits.timeout.as_secs() + 1;
+        let memory = limits.memory_bytes;
+        // RLIMIT_NPROC is counted per user namespace since 5.14; before that
+        // it covers every process the user has, so leave room for those
+        let processes = if user_ns && kernel >= (5, 14) {
+            MAX_PROCESSES
+        } else {
+            processes_of(unsafe { libc::getuid() }) + MAX_PROCESSES
+        };
+        // SAFETY: only async-signal-safe calls; the filter and paths were built before fork
+        unsafe {
+            cmd.pre_exec(move || {
+                if libc::setsid() < 0 {
+                    return Err(std::io::Error::last_os_error());
+                }
+                set_limit(libc::RLIMIT_CPU, cpu_secs)?;
+                set_limit(libc::RLIMIT_DATA, memory)?;
+                set_limit(libc::RLIMIT_FSIZE, MAX_FILE_BYTES)?;
+                set_limit(libc::RLIMIT_CORE, 0)?;
+                set_limit(libc::RLIMIT_NPROC, processes)?;
+                if mount_ns {
+                    read_only_except(&root, &scratch, &hidden)?;
+                }
+                if pid_ns {
+                    enter_pid_namespace()?;
+                }
+                if let Some(filter) = &filter {
+                    seccomp::install(filter)?;
+                }
+                Ok(())
+            });
+        }
+        Ok(isolation)
+    }
+
+    /// Returns in the new namespace's PID 1. The calling process never
+    /// returns: it waits for that child and exits the same way, so the runner
+    /// sees the snippet's own status. When PID 1 exits the kernel kills
+    /// anything the snippet left running.
+    unsafe fn enter_pid_namespace() -> std::io::Result<()> {
+        if libc::unshare(libc::CLONE_NEWPID) < 0 {
+            return Err(std::io::Error::last_os_error());
+        }
+        let pid = libc::fork();
+        if pid < 0 {
+            return Err(std::io::Error::last_os_error());
+        }
+        if pid == 0 {
+            return Ok(());
+        }
+
+        // Drop our copy of std's exec-status pipe (and anything else) so
+        // `spawn` returns as soon as the grandchild execs
+        if libc::syscall(libc::SYS_close_range, 3u32, u32::MAX, 0u32) < 0 {
+            for fd in 3..1024 {
+                libc::close(fd);
+            }
+        }
+        let mut status = 0;
+        while libc::waitpid(pid, &mut status, 0) < 0 {
+            if *libc::__errno_location() != libc::EINTR {
+                libc::_exit(1);
+            }
+        }
+        if libc::WIFSIGNALED(status) {
+            let signal = libc::WTERMSIG(status);
+            libc::signal(signal, libc::SIG_DFL);
+            libc::kill(libc::getpid(), signal);
+        }
+        libc::_exit(libc::WEXITSTATUS(status));
+    }
+
+    /// In a new mount namespace, cover `hidden` with empty tmpfs mounts and
+    /// remount everything read-only except a bind mount of `scratch`. Mounts
+    /// stay private so nothing leaks back out.
+    unsafe fn read_only_except(
+        root: &CString,
+        scratch: &CString,
+        hidden: &[Hidden],
+    ) -> std::io::Result<()> {
+        let none = std::ptr::null();
+        check(libc::unshare(libc::CLONE_NEWNS))?;
+        check(libc::mount(
+            none,
+            root.as_ptr(),
+            none,
+            libc::MS_REC | libc::MS_PRIVATE,
+            none.cast(),
+        ))?;
+        for hidden in hidden {
+            cover(hidden)?;
+        }
+        check(libc::mount(
+            scratch.as_ptr(),
+            scratch.as_ptr(),
+            none,
+            libc::MS_BIND,
+            none.cast(),
+        ))?;
+        set_mount_attr(
+            root,
+            libc::AT_RECURSIVE as libc::c_uint,
+            libc::MOUNT_ATTR_RDONLY,
+            0,
+        )?;
+        set_mount_attr(
+            scratch,
+            0,
+            libc::MOUNT_ATTR_NOSUID | libc::MOUNT_ATTR_NODEV,
+            libc::MOUNT_ATTR_RDONLY,
+        )?;
+        // The working directory still points into the mount underneath
+        check(libc::chdir(scratch.as_ptr()))
+    }
+
+    /// Mount an empty tmpfs on `dir`. Kept paths are cloned first and moved
+    /// back onto the tmpfs, so the scratch dir and an interpreter installed
+    /// under $HOME stay reachable.
+    unsafe fn cover(Hidden { dir, keep }: &Hidden) -> std::io::Result<()> {
+        let mut trees = [-1; KEPT];
+        for ((path, _), tree) in keep.iter().zip(&mut trees) {
+            *tree = libc::syscall(
+                libc::SYS_open_tree,
+                libc::AT_FDCWD,
+                path.as_ptr(),
+                libc::OPEN_TREE_CLONE | libc::OPEN_TREE_CLOEXEC | libc::AT_RECURSIVE as u32,
+            ) as libc::c_int;
+            check(*tree)?;
+        }
+        let tmpfs = c"tmpfs";
+        check(libc::mount(
+            tmpfs.as_ptr(),
+            dir.as_ptr(),
+            tmpfs.as_ptr(),
+            libc::MS_NOSUID | libc::MS_NODEV | libc::MS_NOEXEC,
+            c"mode=0755".as_ptr().cast(),
+        ))?;
+        for ((path, parents), tree) in keep.iter().zip(trees) {
+            for parent in parents {
+                if libc::mkdir(parent.as_ptr(), 0o755) < 0
+                    && *libc::__errno_location() != libc::EEXIST
+                {
+                    return Err(std::io::Error::last_os_error());
+                }
+            }
+            check(libc::syscall(
+                libc::SYS_move_mount,
+                tree,
+                c"".as_ptr(),
+                libc::AT_FDCWD,
+                path.as_ptr(),
+                libc::MOVE_MOUNT_F_EMPTY_PATH,
+            ) as libc::c_int)?;
+            libc::close(tree);
+        }
+        Ok(())
+    }
+
+    /// The directories to hide, minus any already under another one, each with
+    /// whichever of `scratch` and the interpreter `prefix` lie inside it. A dir
+    /// inside either of those stays visible.
+    fn hidden(dirs: &[PathBuf], scratch: &Path, prefix: Option<PathBuf>) -> Vec<Hidden> {
+        let scratch = scratch
+            .canonicalize()
+            .unwrap_or_else(|_| scratch.to_path_buf());
+        let kept = [Some(&scratch), prefix.as_ref()];
+        let mut dirs: Vec<PathBuf> = dirs
+            .iter()
+            .filter_map(|dir| dir.canonicalize().ok())
+            .filter(|dir| dir != Path::new("/"))
+            .filter(|dir| !kept.iter().flatten().any(|path| dir.starts_with(path)))
+            .collect();
+        dirs.sort();
+        dirs.dedup();
+        let outer: Vec<&PathBuf> = dirs
+            .iter()
+            .filter(|dir| {
+                !dirs
+                    .iter()
+                    .any(|other| other != *dir && dir.starts_with(other))
+            })
+            .collect();
+        outer
+            .into_iter()
+            .filter_map(|dir| {
+                let keep = kept
+                    .into_iter()
+                    .flatten()
+                    .filter(|path| path.starts_with(dir) && *path != dir)
+                    .map(|path| {
+                        let parents = path
+                            .ancestors()
+                            .take_while(|parent| parent != dir)
+                            .collect::<Vec<_>>()
+                            .into_iter()
+                            .rev()
+                            .map(c_path)
+                            .collect::<Option<Vec<_>>>()?;
+                        Some((c_path(path)?, parents))
+                    })
+                    .collect::<Option<Vec<_>>>()?;
+                Some(Hidden {
+                    dir: c_path(dir)?,
+                    keep,
+                })
+            })
+            .collect()
+    }
+
+    /// Where the interpreter is installed: `<prefix>/bin/<name>`, found on PATH
+    fn interpreter_prefix(cmd: &Command) -> Option<PathBuf> {
+        let program = Path::new(cmd.get_program());
+        let exe = if program.components().count() > 1 {
+            program.canonicalize().ok()?
+        } else {
+            std::env::split_paths(&std::env::var_os("PATH")?)
+                .find_map(|dir| dir.join(program).canonicalize().ok())?
+        };
+        Some(exe.parent()?.parent()?.to_path_buf())
+    }
+
+    fn c_path(path: &Path) -> Option<CString> {
+        CString::new(path.as_os_str().as_bytes()).ok()
+    }
+
+    unsafe fn set_mount_attr(
+        path: &CString,
+        flags: libc::c_uint,
+        set: u64,
+        clear: u64,
+    ) -> std::io::Result<()> {
+        let attr = libc::mount_attr {
+            attr_set: set,
+            attr_clr: clear,
+            propagation: 0,
+            userns_fd: 0,
+        };
+        check(libc::syscall(
+            libc::SYS_mount_setattr,
+            libc::AT_FDCWD,
+            path.as_ptr(),
+            flags,
+            &attr as *const libc::mount_attr,
+            std::mem::size_of::<libc::mount_attr>(),
+        ) as libc::c_int)
+    }
+
+    fn check(result: libc::c_int) -> std::io::Result<()> {
+        if result < 0 {
+            Err(std::io::Error::last_os_error())
+        } else {
+            Ok(())
+        }
+    }
+
+    fn kernel_version() -> Option<(u32, u32)> {
+        parse_kernel_version(&std::fs::read_to_string("/proc/sys/kernel/osrelease").ok()?)
+    }
+
+    /// `6.8.0-45-generic` -> (6, 8)
+    fn parse_kernel_version(release: &str) -> Option<(u32, u32)> {
+        let mut parts = release.trim().split(|c: char| !c.is_ascii_digit());
+        Some((parts.next()?.parse().ok()?, parts.next()?.parse().ok()?))
+    }
+
+    /// Processes owned by `uid`, from /proc
+    fn processes_of(uid: libc::uid_t) -> u64 {
+        use std::os::unix::fs::MetadataExt;
+
+        std::fs::read_dir("/proc")
+            .into_iter()
+            .flatten()
+            .flatten()
+            .filter(|entry| entry.file_name().to_string_lossy().parse::<u32>().is_ok())
+            .filter(|entry| entry.metadata().is_ok_and(|meta| meta.uid() == uid))
+            .count() as u64
+    }
+
+    fn set_limit(resource: libc::__rlimit_resource_t, value: u64) -> std::io::Result<()> {
+        let limit = libc::rlimit {
+            rlim_cur: value,
+            rlim_max: value,
+        };
+        if unsafe { libc::setrlimit(resource, &limit) } < 0 {
+            return Err(std::io::Error::last_os_error());
+        }
+        Ok(())
+    }
+
+    /// Unprivileged user namespace, then IPC/UTS and (unless allowed) network.
+    /// Distros that disable unprivileged namespaces get none of these.
+    fn namespaces(network: bool) -> Vec<String> {
+        let uid = unsafe { libc::getuid() };
+        let gid = unsafe { libc::getgid() };
+        let mut flags = libc::CLONE_NEWUSER | libc::CLONE_NEWIPC | libc::CLONE_NEWUTS;
+        if !network {
+            flags |= libc::CLONE_NEWNET;
+        }
+        if unsafe { libc::unshare(flags) } < 0 {
+            eprintln!(
+                "[sandbox] namespaces unavailable: {}",
+                std::io::Error::last_os_error()
+            );
+            return Vec::new();
+        }
+
+        // Keep our own uid/gid inside the namespace so file ownership looks normal
+        let _ = write_proc("/proc/self/setgroups", "deny");
+        let _ = write_proc("/proc/self/uid_map", &format!("{uid} {uid} 1"));
+        let _ = write_proc("/proc/self/gid_map", &format!("{gid} {gid} 1"));
+
+        let mut isolation = vec!["user_ns".to_string(), "ipc_ns".into(), "uts_ns".into()];
+        if !network {
+            isolation.push("net_ns".into());
+        }
+        isolation
+    }
+
+    fn write_proc(path: &str, value: &str) -> std::io::Result<()> {
+        std::fs::write(path, value)
+    }
+
+    /// The snippet's process group. setsid made the child its leader, and the
+    /// PID namespace, where there is one, takes the rest with PID 1.
+    pub struct ProcessTree {
+        group: Option<libc::pid_t>,
+    }
+
+    impl ProcessTree {
+        pub fn contain(child: &Child, _limits: &Limits) -> Result<Self, String> {
+            Ok(Self {
+                group: libc::pid_t::try_from(child.id()).ok(),
+            })
+        }
+
+        pub fn kill(&self, child: &mut Child) {
+            if let Some(group) = self.group {
+                unsafe { libc::kill(-group, libc::SIGKILL) };
+            }
+            let _ = child.kill();
+        }
+    }
+
+    pub fn signal(status: &ExitStatus) -> Option<i32> {
+        status.signal()
+    }
+
+    mod seccomp {
+        use libc::{sock_filter, sock_fprog};
+
+        const LD_W_ABS: u16 = 0x20; // BPF_LD | BPF_W | BPF_ABS
+        const JEQ_K: u16 = 0x15; // BPF_JMP | BPF_JEQ | BPF_K
+        const JSET_K: u16 = 0x45; // BPF_JMP | BPF_JSET | BPF_K
+        const RET_K: u16 = 0x06; // BPF_RET | BPF_K
+
+        const RET_ALLOW: u32 = 0x7fff_0000;
+        const RET_ERRNO: u32 = 0x0005_0000;
+        const RET_KILL_PROCESS: u32 = 0x8000_0000;
+
+        // Offsets into struct seccomp_data
+        const NR: u32 = 0;
+        const ARCH: u32 = 4;
+        const ARG0: u32 = 16;
+
+        #[cfg(target_arch = "x86_64")]
+        const AUDIT_ARCH: Option<u32> = Some(0xC000_003E);
+        #[cfg(target_arch = "aarch64")]
+        const AUDIT_ARCH: Option<u32> = Some(0xC000_00B7);
+        #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+        const AUDIT_ARCH: Option<u32> = None;
+
+        /// x32 syscalls carry the x86_64 arch but set this bit in the number,
+        /// so they would slip past DENIED
+        #[cfg(target_arch = "x86_64")]
+        const X32_SYSCALL_BIT: Option<u32> = Some(0x4000_0000);
+        #[cfg(not(target_arch = "x86_64"))]
+        const X32_SYSCALL_BIT: Option<u32> = None;
+
+        /// Syscalls a snippet never needs: debugging other processes, kernel
+        /// modules, mounts, new namespaces, and io_uring (which bypasses the
+        /// socket check)
+        #[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+        const DENIED: &[libc::c_long] = &[
+            libc::SYS_ptrace,
+            libc::SYS_process_vm_readv,
+            libc::SYS_process_vm_writev,
+            libc::SYS_mount,
+            libc::SYS_umount2,
+            libc::SYS_pivot_root,
+            libc::SYS_chroot,
+            libc::SYS_setns,
+            libc::SYS_unshare,
+            libc::SYS_reboot,
+            libc::SYS_kexec_load,
+            libc::SYS_init_module,
+            libc::SYS_finit_module,
+            libc::SYS_delete_module,
+            libc::SYS_swapon,
+            libc::SYS_swapoff,
+            libc::SYS_bpf,
+            libc::SYS_perf_event_open,
+            libc::SYS_userfaultfd,
+            libc::SYS_keyctl,
+            libc::SYS_add_key,
+            libc::SYS_request_key,
+            libc::SYS_io_uring_setup,
+        ];
+        #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+        const DENIED: &[libc::c_long] = &[];
+
+        fn stmt(code: u16, k: u32) -> sock_filter {
+            sock_filter {
+                code,
+                jt: 0,
+                jf: 0,
+                k,
+            }
+        }
+
+        fn jump(k: u32, jt: u8, jf: u8) -> sock_filter {
+            sock_filter {
+                code: JEQ_K,
+                jt,
+                jf,
+                k,
+            }
+        }
+
+        fn jump_if_set(bits: u32, jt: u8, jf: u8) -> sock_filter {
+            sock_filter {
+                code: JSET_K,
+                jt,
+                jf,
+                k: bits,
+            }
+        }
+
+        /// BPF program killing other arches and x32, denying DENIED with EPERM
+        /// and, without network, every socket that is not AF_UNIX with EACCES.
+        /// None on unsupported arches.
+        pub fn filter(network: bool) -> Option<Vec<sock_filter>> {
+            let arch = AUDIT_ARCH?;
+            let mut program = vec![
+                stmt(LD_W_ABS, ARCH),
+                jump(arch, 1, 0),
+                stmt(RET_K, RET_KILL_PROCESS),
+                stmt(LD_W_ABS, NR),
+            ];
+            if let Some(bit) = X32_SYSCALL_BIT {
+                program.extend([jump_if_set(bit, 0, 1), stmt(RET_K, RET_KILL_PROCESS)]);
+            }
+
+            let socket_checks = if network { 0 } else { 4 };
+            for (i, nr) in DENIED.iter().enumerate() {
+                // Jump past the remaining checks, the socket block and ALLOW to DENY
+                let to_deny = DENIED.len() - i - 1 + socket_checks + 1;
+                program.push(jump(*nr as u32, to_deny as u8, 0));
+            }
+            if !network {
+                program.extend([
+                    jump(libc::SYS_socket as u32, 0, 3),
+                    stmt(LD_W_ABS, ARG0),
+                    jump(libc::AF_UNIX as u32, 1, 0),
+                    stmt(RET_K, RET_ERRNO | libc::EACCES as u32),
+                ]);
+            }
+            program.push(stmt(RET_K, RET_ALLOW));
+            program.push(stmt(RET_K, RET_ERRNO | libc::EPERM as u32));
+            Some(program)
+        }
+
+        pub fn install(filter: &[sock_filter]) -> std::io::Result<()> {
+            let program = sock_fprog {
+                len: filter.len() as u16,
+                filter: filter.as_ptr() as *mut sock_filter,
+            };
+            unsafe {
+                if libc::prctl(libc::PR_SET_NO_NEW_PRIVS, 1, 0, 0, 0) < 0 {
+                    return Err(std::io::Error::last_os_error());
+                }
+                if libc::prctl(
+                    libc::PR_SET_SECCOMP,
+                    libc::SECCOMP_MODE_FILTER,
+                    &program as *const sock_fprog,
+                ) < 0
+                {
+                    return Err(std::io::Error::last_os_error());
+                }
+            }
+            Ok(())
+        }
+
+        #[cfg(test)]
+        mod tests {
+            use std::os::unix::process::CommandExt;
+            use std::process::Command;
+
+            use super::*;
+
+            /// Run a filter the way the kernel would for one syscall
+            fn evaluate(program: &[sock_filter], arch: u32, nr: u32, arg0: u32) -> u32 {
+                let (mut pc, mut acc) = (0, 0);
+                loop {
+                    let insn = program[pc];
+                    pc += 1;
+                    match insn.code {
+                        LD_W_ABS => {
+                            acc = match insn.k {
+                                NR => nr,
+                                ARCH => arch,
+                                ARG0 => arg0,
+                                k => panic!("load from unexpected offset {k}"),
+                            }
+                        }
+                        JEQ_K | JSET_K => {
+                            let taken = if insn.code == JEQ_K {
+                                acc == insn.k
+                            } else {
+                                acc & insn.k != 0
+                            };
+                            pc += usize::from(if taken { insn.jt } else { insn.jf });
+                        }
+                        RET_K => return insn.k,
+                        code => panic!("unexpected instruction {code:#x}"),
+                    }
+                }
+            }
+
+            fn native(program: &[sock_filter], nr: libc::c_long, arg0: i32) -> u32 {
+                evaluate(program, AUDIT_ARCH.unwrap(), nr as u32, arg0 as u32)
+            }
+
+            const EPERM: u32 = RET_ERRNO | libc::EPERM as u32;
+            const EACCES: u32 = RET_ERRNO | libc::EACCES as u32;
+
+            #[test]
+            fn jumps_stay_inside_the_program() {
+                for network in [false, true] {
+                    let program = filter(network).unwrap();
+                    for (pc, insn) in program.iter().enumerate() {
+                        if matches!(insn.code, JEQ_K | JSET_K) {
+                            let target = pc + 1 + usize::from(insn.jt.max(insn.jf));
+                            assert!(target < program.len(), "jump at {pc} lands outside");
+                        }
+                    }
+                    assert_eq!(program.last().unwrap().code, RET_K);
+                }
+            }
+
+            #[test]
+            fn denied_syscalls_get_eperm() {
+                for network in [false, true] {
+                    let program = filter(network).unwrap();
+                    for nr in DENIED {
+                        assert_eq!(native(&program, *nr, 0), EPERM, "syscall {nr}");
+                    }
+                }
+            }
+
+            #[test]
+            fn ordinary_syscalls_are_allowed() {
+                for network in [false, true] {
+                    let program = filter(network).unwrap();
+                    for nr in [
+                        libc::SYS_read,
+                        libc::SYS_write,
+                        libc::SYS_execve,
+                        libc::SYS_clone,
+                    ] {
+                        assert_eq!(native(&program, nr, 0), RET_ALLOW, "syscall {nr}");
+                    }
+                }
+            }
+
+            #[test]
+            fn only_unix_sockets_without_network() {
+                let offline = filter(false).unwrap();
+                assert_eq!(native(&offline, libc::SYS_socket, libc::AF_INET), EACCES);
+                assert_eq!(native(&offline, libc::SYS_socket, libc::AF_INET6), EACCES);
+                assert_eq!(native(&offline, libc::SYS_socket, libc::AF_NETLINK), EACCES);
+                assert_eq!(native(&offline, libc::SYS_socket, libc::AF_UNIX), RET_ALLOW);
+
+                let online = filter(true).unwrap();
+                assert_eq!(native(&online, libc::SYS_socket, libc::AF_INET), RET_ALLOW);
+            }
+
+            #[test]
+            fn other_arches_are_killed() {
+                // AUDIT_ARCH_I386
+                let program = filter(false).unwrap();
+                assert_eq!(evaluate(&program, 0x4000_0003, 0, 0), RET_KILL_PROCESS);
+            }
+
+            #[cfg(target_arch = "x86_64")]
+            #[test]
+            fn x32_syscalls_are_killed() {
+                for network in [false, true] {
+                    let program = filter(network).unwrap();
+                    for nr in [libc::SYS_read, libc::SYS_ptrace, libc::SYS_socket] {
+                        let nr = nr | 0x4000_0000;
+                        assert_eq!(native(&program, nr, 0), RET_KILL_PROCESS, "syscall {nr}");
+                    }
+                }
+            }
+
+            #[test]
+            fn installed_filter_lets_a_shell_run() {
+                let program = filter(false).unwrap();
+                let mut cmd = Command::new("sh");
+                cmd.args(["-c", "exit 3"]);
+                // SAFETY: install only makes prctl calls
+                unsafe { cmd.pre_exec(move || install(&program)) };
+                assert_eq!(cmd.status().unwrap().code(), Some(3));
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn kernel_versions() {
+            assert_eq!(parse_kernel_version("6.8.0-45-generic\n"), Some((6, 8)));
+            assert_eq!(parse_kernel_version("5.12.19"), Some((5, 12)));
+            assert_eq!(parse_kernel_version("4.19-rc1"), Some((4, 19)));
+            assert_eq!(parse_kernel_version("unknown"), None);
+        }
+    }
+}
+
+#[cfg(windows)]
+mod platform {
+    use std::os::windows::io::AsRawHandle;
+    use std::os::windows::process::CommandExt;
+    use std::path::Path;
+    use std::process::{Child, Command, ExitStatus};
+
+    use windows_sys::Win32::Foundation::{CloseHandle, HANDLE, INVALID_HANDLE_VALUE};
+    use windows_sys::Win32::System::Diagnostics::ToolHelp::{
+        CreateToolhelp32Snapshot, Thread32First, Thread32Next, TH32CS_SNAPTHREAD, THREADENTRY32,
+    };
+    use windows_sys::Win32::System::JobObjects::{
+        AssignProcessToJobObject, CreateJobObjectW, JobObjectExtendedLimitInformation,
+        SetInformationJobObject, TerminateJobObject, JOBOBJECT_EXTENDED_LIMIT_INFORMATION,
+        JOB_OBJECT_LIMIT_ACTIVE_PROCESS, JOB_OBJECT_LIMIT_DIE_ON_UNHANDLED_EXCEPTION,
+        JOB_OBJECT_LIMIT_JOB_MEMORY, JOB_OBJECT_LIMIT_JOB_TIME, JOB_OBJECT_LIMIT_KILL_ON_JOB_CLOSE,
+    };
+    use windows_sys::Win32::System::Threading::{
+        OpenThread, ResumeThread, CREATE_NO_WINDOW, CREATE_SUSPENDED, THREAD_SUSPEND_RESUME,
+    };
+
+    use super::{Limits, MAX_PROCESSES, NETWORK_NOT_BLOCKED};
+
+    /// The child starts suspended so it is in its job object before it runs
+    pub fn confine(
+        cmd: &mut Command,
+        limits: &Limits,
+        _scratch: &Path,
+    ) -> Result<Vec<String>, String> {
+        if !limits.network {
+            return Err(NETWORK_NOT_BLOCKED.into());
+        }
+        cmd.creation_flags(CREATE_NO_WINDOW | CREATE_SUSPENDED);
+        Ok(vec!["job_object".into()])
+    }
+
+    /// A job object holding the snippet and everything it starts, with memory,
+    /// CPU-time and process limits. Closing it kills whatever is left.
+    pub struct ProcessTree {
+        job: HANDLE,
+    }
+
+    impl ProcessTree {
+        pub fn contain(child: &Child, limits: &Limits) -> Result<Self, String> {
+            let failed = |what: &str| format!("{what}: {}", std::io::Error::last_os_error());
+            // SAFETY: plain Win32 calls on handles we own; the child is still suspended
+            unsafe {
+                let job = CreateJobObjectW(std::ptr::null(), std::ptr::null());
+                if job.is_null() {
+                    return Err(failed("could not create a job object"));
+                }
+                let tree = Self { job };
+
+                let mut info: JOBOBJECT_EXTENDED_LIMIT_INFORMATION = std::mem::zeroed();
+                info.BasicLimitInformation.LimitFlags = JOB_OBJECT_LIMIT_KILL_ON_JOB_CLOSE
+                    | JOB_OBJECT_LIMIT_JOB_MEMORY
+                    | JOB_OBJECT_LIMIT_JOB_TIME
+                    | JOB_OBJECT_LIMIT_ACTIVE_PROCESS
+                    | JOB_OBJECT_LIMIT_DIE_ON_UNHANDLED_EXCEPTION;
+                // In 100 ns ticks, across every process in the job
+                info.BasicLimitInformation.PerJobUserTimeLimit =
+                    ((limits.timeout.as_secs() + 1) * 10_000_000) as i64;
+                info.BasicLimitInformation.ActiveProcessLimit = MAX_PROCESSES as u32;
+                info.JobMemoryLimit = usize::try_from(limits.memory_bytes).unwrap_or(usize::MAX);
+                if SetInformationJobObject(
+                    job,
+                    JobObjectExtendedLimitInformation,
+                    &info as *const JOBOBJECT_EXTENDED_LIMIT_INFORMATION as *const _,
+                    std::mem::size_of::<JOBOBJECT_EXTENDED_LIMIT_INFORMATION>() as u32,
+                ) == 0
+                {
+                    return Err(failed("could not set job limits"));
+                }
+                if AssignProcessToJobObject(job, child.as_raw_handle() as HANDLE) == 0 {
+                    return Err(failed("could not assign the snippet to its job"));
+                }
+                resume(child.id())?;
+                Ok(tree)
+            }
+        }
+
+        pub fn kill(&self, child: &mut Child) {
+            unsafe { TerminateJobObject(self.job, 1) };
+            let _ = child.kill();
+        }
+    }
+
+    impl Drop for ProcessTree {
+        fn drop(&mut self) {
+            unsafe { CloseHandle(self.job) };
+        }
+    }
+
+    /// Start the threads of a process created with CREATE_SUSPENDED
+    unsafe fn resume(pid: u32) -> Result<(), String> {
+        let snapshot = CreateToolhelp32Snapshot(TH32CS_SNAPTHREAD, 0);
+        if snapshot == INVALID_HANDLE_VALUE {
+            return Err(format!(
+                "could not list the snippet's threads: {}",
+                std::io::Error::last_os_error()
+            ));
+        }
+        let mut entry: THREADENTRY32 = std::mem::zeroed();
+        entry.dwSize = std::mem::size_of::<THREADENTRY32>() as u32;
+        let mut resumed = 0;
+        let mut more = Thread32First(snapshot, &mut entry) != 0;
+        while more {
+            if entry.th32OwnerProcessID == pid {
+                let thread = OpenThread(THREAD_SUSPEND_RESUME, 0, entry.th32ThreadID);
+                if !thread.is_null() {
+                    if ResumeThread(thread) != u32::MAX {
+                        resumed += 1;
+                    }
+                    CloseHandle(thread);
+                }
+            }
+            more = Thread32Next(snapshot, &mut entry) != 0;
+        }
+        CloseHandle(snapshot);
+        if resumed == 0 {
+            return Err("could not start the snippet".into());
+        }
+        Ok(())
+    }
+
+    pub fn signal(_status: &ExitStatus) -> Option<i32> {
+        None
+    }
+}
+
+#[cfg(all(unix, not(target_os = "linux")))]
+mod platform {
+    use std::os::unix::process::{CommandExt, ExitStatusExt};
+    use std::path::Path;
+    use std::process::{Child, Command, ExitStatus};
+
+    use super::{Limits, MAX_FILE_BYTES, NETWORK_NOT_BLOCKED};
+
+    /// The same rlimits as on Linux, except RLIMIT_NPROC: here it counts every
+    /// process the user has, so it cannot cap the snippet on its own
+    pub fn confine(
+        cmd: &mut Command,
+        limits: &Limits,
+        _scratch: &Path,
+    ) -> Result<Vec<String>, String> {
+        if !limits.network {
+            return Err(NETWORK_NOT_BLOCKED.into());
+        }
+        let cpu_secs = limits.timeout.as_secs() + 1;
+        let memory = limits.memory_bytes;
+        // SAFETY: only async-signal-safe calls between fork and exec
+        unsafe {
+            cmd.pre_exec(move || {
+                if libc::setsid() < 0 {
+                    return Err(std::io::Error::last_os_error());
+                }
+                set_limit(libc::RLIMIT_CPU, cpu_secs)?;
+                set_limit(libc::RLIMIT_DATA, memory)?;
+                set_limit(libc::RLIMIT_FSIZE, MAX_FILE_BYTES)?;
+                set_limit(libc::RLIMIT_CORE, 0)
+            });
+        }
+        Ok(vec!["process_group".into(), "rlimits".into()])
+    }
+
+    fn set_limit(resource: libc::c_int, value: u64) -> std::io::Result<()> {
+        let limit = libc::rlimit {
+            rlim_cur: value as libc::rlim_t,
+            rlim_max: value as libc::rlim_t,
+        };
+        if unsafe { libc::setrlimit(resource, &limit) } < 0 {
+            return Err(std::io::Error::last_os_error());
+        }
+        Ok(())
+    }
+
+    /// The snippet's process group, which setsid made the child lead
+    pub struct ProcessTree {
+        group: Option<libc::pid_t>,
+    }
+
+    impl ProcessTree {
+        pub fn contain(child: &Child, _limits: &Limits) -> Result<Self, String> {
+            Ok(Self {
+                group: libc::pid_t::try_from(child.id()).ok(),
+            })
+        }
+
+        pub fn kill(&self, child: &mut Child) {
+            if let Some(group) = self.group {
+                unsafe { libc::kill(-group, libc::SIGKILL) };
+            }
+            let _ = child.kill();
+        }
+    }
+
+    pub fn signal(status: &ExitStatus) -> Option<i32> {
+        status.signal()
+    }
+}
@@ -0,0 +1,60 @@
+// FRIDAY AI Assistant - Markdown sanitizer
+// Model output is untrusted: it is rendered to HTML here and passed through an
+// allowlist before the webview ever sees it, so a prompt-injected reply cannot
+// smuggle in script, event handlers or javascript: links.
+
+use std::borrow::Cow;
+use std::sync::OnceLock;
+
+use ammonia::Builder;
+use pulldown_cmark::{html, Event, Options, Parser};
+
+/// Render chat markdown to safe HTML. Raw HTML in the source is shown as
+/// text, single newlines break lines, and fenced code keeps its
+/// `language-*` class for highlighting.
+pub fn render(markdown: &str) -> String {
+    let options = Options::ENABLE_TABLES
+        | Options::ENABLE_STRIKETHROUGH
+        | Options::ENABLE_TASKLISTS
+        | Options::ENABLE_FOOTNOTES;
+    let parser = Parser::new_ext(markdown, options).map(|event| match event {
+        Event::Html(raw) | Event::InlineHtml(raw) => Event::Text(raw),
+        Event::SoftBreak => Event::HardBreak,
+        event => event,
+    });
+    let mut out = String::new();
+    html::push_html(&mut out, parser);
+    clean(&out)
+}
+
+/// Strip everything but formatting markup from an HTML fragment
+pub fn clean(html: &str) -> String {
+    cleaner().clean(html).to_string()
+}
+
+fn cleaner() -> &'static Builder<'static> {
+    static CLEANER: OnceLock<Builder<'static>> = OnceLock::new();
+    CLEANER.get_or_init(|| {
+        let mut builder = Builder::default();
+        builder
+            .add_tags(["input"])
+            .add_tag_attributes("code", ["class"])
+            .add_tag_attributes("input", ["type", "checked", "disabled"])
+            .attribute_filter(|element, attribute, value| match (element, attribute) {
+                ("code", "class") => value
+                    .split_whitespace()
+                    .find(|class| class.starts_with("language-"))
+                    .map(|class| Cow::Owned(class.to_string())),
+                // Task list checkboxes only
+                ("input", "type") => (value == "checkbox").then_some(Cow::Borrowed(value)),
+                _ => Some(Cow::Borrowed(value)),
+            });
+        builder
+    })
+}
+
+/// Markdown to sanitized HTML for the chat view
+#[tauri::command]
+pub fn render_markdown(text: String) -> String {
+    render(&text)
+}
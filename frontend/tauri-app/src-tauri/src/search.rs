@@ -0,0 +1,166 @@
+// FRIDAY AI Assistant - Conversation Search
+// Full-text index (SQLite FTS5) over every message in the store. Triggers keep
+// it in step with the messages table, so new messages are searchable as soon
+// as a conversation is synced.
+
+use rusqlite::params;
+use serde::{Deserialize, Serialize};
+use tauri::AppHandle;
+
+use crate::store::{self, Role, Store};
+
+/// Store migration adding the index; `rebuild` covers messages saved before it
+pub const SCHEMA: &str = "
+CREATE VIRTUAL TABLE IF NOT EXISTS messages_fts USING fts5(
+    content,
+    content = 'messages',
+    content_rowid = 'id',
+    tokenize = 'unicode61 remove_diacritics 2'
+);
+CREATE TRIGGER IF NOT EXISTS messages_fts_insert AFTER INSERT ON messages BEGIN
+    INSERT INTO messages_fts (rowid, content) VALUES (new.id, new.content);
+END;
+CREATE TRIGGER IF NOT EXISTS messages_fts_delete AFTER DELETE ON messages BEGIN
+    INSERT INTO messages_fts (messages_fts, rowid, content) VALUES ('delete', old.id, old.content);
+END;
+CREATE TRIGGER IF NOT EXISTS messages_fts_update AFTER UPDATE OF content ON messages BEGIN
+    INSERT INTO messages_fts (messages_fts, rowid, content) VALUES ('delete', old.id, old.content);
+    INSERT INTO messages_fts (rowid, content) VALUES (new.id, new.content);
+END;
+INSERT INTO messages_fts (messages_fts) VALUES ('rebuild');
+";
+
+const DEFAULT_LIMIT: u32 = 25;
+const SNIPPET_TOKENS: i32 = 16;
+// Private-use characters mark matches inside the snippet; SQLite inserts them
+// raw, so they are swapped for <mark> only after the text is HTML-escaped
+const MATCH_START: char = '\u{E000}';
+const MATCH_END: char = '\u{E001}';
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct SearchFilters {
+    /// Conversations belonging to this project
+    pub project_id: Option<String>,
+    pub role: Option<Role>,
+    /// Messages at or after this ISO date/time
+    pub from: Option<String>,
+    /// Messages up to and including this ISO date/time (a bare date covers the whole day)
+    pub to: Option<String>,
+    pub limit: Option<u32>,
+    pub offset: Option<u32>,
+}
+
+/// One matching message, best matches first
+#[derive(Debug, Clone, Serialize)]
+pub struct SearchHit {
+    pub conversation_id: String,
+    pub conversation_name: Option<String>,
+    pub project_id: Option<String>,
+    /// Index of the message within the conversation
+    pub position: u32,
+    pub role: String,
+    pub timestamp: String,
+    /// HTML-escaped excerpt with matches wrapped in `<mark>`
+    pub snippet: String,
+    /// Higher is more relevant (negated bm25)
+    pub score: f64,
+}
+
+impl Store {
+    pub fn search(&self, query: &str, filters: &SearchFilters) -> rusqlite::Result<Vec<SearchHit>> {
+        let Some(query) = fts_query(query) else {
+            return Ok(Vec::new());
+        };
+
+        let conn = self.conn();
+        let mut stmt = conn.prepare(
+            "SELECT m.conversation_id, c.name, c.project_id, m.position, m.role, m.timestamp,
+                    snippet(messages_fts, 0, ?7, ?8, '…', ?9),
+                    -bm25(messages_fts)
+             FROM messages_fts
+             JOIN messages m ON m.id = messages_fts.rowid
+             JOIN conversations c ON c.id = m.conversation_id
+             WHERE messages_fts MATCH ?1
+               AND (?2 IS NULL OR c.project_id = ?2 OR EXISTS (
+                    SELECT 1 FROM project_conversations pc
+                    WHERE pc.project_id = ?2 AND pc.conversation_id = c.id))
+               AND (?3 IS NULL OR m.role = ?3)
+               AND (?4 IS NULL OR m.timestamp >= ?4)
+               AND (?5 IS NULL OR substr(m.timestamp, 1, length(?5)) <= ?5)
+             ORDER BY bm25(messages_fts)
+             LIMIT ?6 OFFSET ?10",
+        )?;
+
+        let hits = stmt.query_map(
+            params![
+                query,
+                filters.project_id,
+                filters.role.map(Role::as_str),
+                filters.from,
+                filters.to,
+                filters.limit.unwrap_or(DEFAULT_LIMIT),
+                MATCH_START.to_string(),
+                MATCH_END.to_string(),
+                SNIPPET_TOKENS,
+                filters.offset.unwrap_or(0),
+            ],
+            |row| {
+                Ok(SearchHit {
+                    conversation_id: row.get(0)?,
+                    conversation_name: row.get(1)?,
+                    project_id: row.get(2)?,
+                    position: row.get(3)?,
+                    role: row.get(4)?,
+                    timestamp: row.get(5)?,
+                    snippet: highlight(&row.get::<_, String>(6)?),
+                    score: row.get(7)?,
+                })
+            },
+        )?;
+        hits.collect()
+    }
+}
+
+/// Turn what the user typed into an FTS5 query: every word must match, the
+/// last one as a prefix so results update while typing. Quoting each word
+/// keeps FTS5 operators and punctuation from being interpreted.
+fn fts_query(input: &str) -> Option<String> {
+    let words: Vec<String> = input
+        .split_whitespace()
+        .map(|word| format!("\"{}\"", word.replace('"', "\"\"")))
+        .collect();
+    if words.is_empty() {
+        return None;
+    }
+    Some(format!("{}*", words.join(" ")))
+}
+
+fn highlight(snippet: &str) -> String {
+    let mut html = String::with_capacity(snippet.len() + 32);
+    for c in snippet.chars() {
+        match c {
+            MATCH_START => html.push_str("<mark>"),
+            MATCH_END => html.push_str("</mark>"),
+            '&' => html.push_str("&amp;"),
+            '<' => html.push_str("&lt;"),
+            '>' => html.push_str("&gt;"),
+            '"' => html.push_str("&quot;"),
+            '\'' => html.push_str("&#39;"),
+            c => html.push(c),
+        }
+    }
+    html
+}
+
+/// Ranked full-text search over all messages, e.g. "trekking route"
+#[tauri::command]
+pub fn search_conversations(
+    app: AppHandle,
+    query: String,
+    filters: Option<SearchFilters>,
+) -> Result<Vec<SearchHit>, String> {
+    store::managed(&app)?
+        .search(&query, &filters.unwrap_or_default())
+        .map_err(|e| e.to_string())
+}
@@ -0,0 +1,225 @@
+// FRIDAY AI Assistant - Secrets Vault
+// Provider API keys, encrypted with the data key in <app data dir>/secrets.enc
+// instead of sitting in backend/.env as plaintext. The backend only ever sees
+// them as environment variables of the process the supervisor launches.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager, State};
+
+use crate::backend::{self, BackendSupervisor};
+use crate::encryption::{DataKey, Encryption};
+
+const VAULT_FILE: &str = "secrets.enc";
+/// Values the installer writes when no key was entered
+const PLACEHOLDER_PREFIX: &str = "your_";
+
+/// A credential config.py reads from the environment, named by its variable
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Secret {
+    GroqApiKey,
+    GoogleApiKey,
+    BingApiKey,
+    FirebaseProjectId,
+    FirebaseCredentialsJson,
+}
+
+impl Secret {
+    const ALL: [Secret; 5] = [
+        Secret::GroqApiKey,
+        Secret::GoogleApiKey,
+        Secret::BingApiKey,
+        Secret::FirebaseProjectId,
+        Secret::FirebaseCredentialsJson,
+    ];
+
+    pub fn env_var(self) -> &'static str {
+        match self {
+            Secret::GroqApiKey => "GROQ_API_KEY",
+            Secret::GoogleApiKey => "GOOGLE_API_KEY",
+            Secret::BingApiKey => "BING_API_KEY",
+            Secret::FirebaseProjectId => "FIREBASE_PROJECT_ID",
+            Secret::FirebaseCredentialsJson => "FIREBASE_CREDENTIALS_JSON",
+        }
+    }
+
+    fn from_env_var(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|secret| secret.env_var() == name)
+    }
+}
+
+type Vault = BTreeMap<Secret, String>;
+
+fn vault_path(app: &AppHandle) -> Result<PathBuf, String> {
+    app.path_resolver()
+        .app_data_dir()
+        .map(|dir| dir.join(VAULT_FILE))
+        .ok_or_else(|| "app data directory is unavailable".into())
+}
+
+fn load(app: &AppHandle, key: &DataKey) -> Result<Vault, String> {
+    let path = vault_path(app)?;
+    let data = match fs::read(&path) {
+        Ok(data) => data,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vault::new()),
+        Err(e) => return Err(e.to_string()),
+    };
+    let json = key.decrypt(&data)?;
+    serde_json::from_slice(&json).map_err(|e| format!("corrupt secrets vault: {e}"))
+}
+
+fn save(app: &AppHandle, key: &DataKey, vault: &Vault) -> Result<(), String> {
+    let path = vault_path(app)?;
+    let json = serde_json::to_vec(vault).map_err(|e| e.to_string())?;
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(".tmp");
+    fs::write(&tmp, key.encrypt(&json))
+        .and_then(|_| fs::rename(&tmp, &path))
+        .map_err(|e| format!("could not write {}: {e}", path.display()))
+}
+
+/// Re-encrypt the vault when the data key is rotated. A vault already under
+/// `new` is left alone, so an interrupted rotation can be undone by swapping keys.
+pub fn rekey(app: &AppHandle, old: &DataKey, new: &DataKey) -> Result<(), String> {
+    let vault = match load(app, old) {
+        Ok(vault) => vault,
+        Err(e) => return load(app, new).map(drop).map_err(|_| e),
+    };
+    if vault.is_empty() {
+        return Ok(());
+    }
+    save(app, new, &vault)
+}
+
+/// Hand the stored keys to the supervisor for the next backend launch. Keys
+/// found in backend/.env are moved into the vault first.
+pub fn inject(app: &AppHandle, key: &DataKey) -> Result<(), String> {
+    let mut vault = load(app, key)?;
+    migrate_dotenv(app, key, &mut vault)?;
+    apply(app, &vault);
+    Ok(())
+}
+
+fn apply(app: &AppHandle, vault: &Vault) {
+    let supervisor = app.state::<BackendSupervisor>();
+    for secret in Secret::ALL {
+        match vault.get(&secret) {
+            Some(value) => supervisor.set_env(secret.env_var(), value),
+            None => supervisor.remove_env(secret.env_var()),
+        }
+    }
+}
+
+/// Take provider keys out of backend/.env (written by INSTALL-FRIDAY.ps1),
+/// leaving a comment in their place. A live line in .env is newer than the
+/// vault (someone put it back), so it replaces the stored key. The vault is
+/// saved before .env is rewritten, so a failure never loses a key.
+fn migrate_dotenv(app: &AppHandle, key: &DataKey, vault: &mut Vault) -> Result<(), String> {
+    let Some(path) = backend::find_backend_dir().map(|dir| dir.join(".env")) else {
+        return Ok(());
+    };
+    let Ok(contents) = fs::read_to_string(&path) else {
+        return Ok(());
+    };
+
+    let mut moved = false;
+    let lines: Vec<String> = contents
+        .lines()
+        .map(|line| {
+            let Some((name, value)) = line.split_once('=') else {
+                return line.to_owned();
+            };
+            let name = name.trim();
+            let Some(secret) = Secret::from_env_var(name) else {
+                return line.to_owned();
+            };
+            let value = value.trim().trim_matches(|c| c == '"' || c == '\'');
+            if value.is_empty() || value.starts_with(PLACEHOLDER_PREFIX) {
+                return line.to_owned();
+            }
+            vault.insert(secret, value.to_owned());
+            moved = true;
+            format!("# {name} is kept in the FRIDAY desktop app's secrets vault")
+        })
+        .collect();
+
+    if moved {
+        save(app, key, vault)?;
+        fs::write(&path, lines.join("\n") + "\n")
+            .map_err(|e| format!("could not update {}: {e}", path.display()))?;
+        eprintln!(
+            "[secrets] moved provider keys from {} into the vault",
+            path.display()
+        );
+    }
+    Ok(())
+}
+
+fn unlocked_key(encryption: &Encryption) -> Result<Arc<DataKey>, String> {
+    encryption.key().ok_or_else(|| "data is locked".into())
+}
+
+/// Store a provider key. The backend picks it up at its next launch
+/// (`backend_restart` applies it straight away).
+#[tauri::command]
+pub fn set_secret(
+    app: AppHandle,
+    encryption: State<'_, Encryption>,
+    name: Secret,
+    value: String,
+) -> Result<(), String> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Err(format!("{} cannot be empty", name.env_var()));
+    }
+    let key = unlocked_key(&encryption)?;
+    let mut vault = load(&app, &key)?;
+    vault.insert(name, value.to_owned());
+    save(&app, &key, &vault)?;
+    apply(&app, &vault);
+    Ok(())
+}
+
+/// Whether a key is stored; values are never sent back to the frontend
+#[tauri::command]
+pub fn has_secret(
+    app: AppHandle,
+    encryption: State<'_, Encryption>,
+    name: Secret,
+) -> Result<bool, String> {
+    let key = unlocked_key(&encryption)?;
+    Ok(load(&app, &key)?.contains_key(&name))
+}
+
+/// Names of all stored keys
+#[tauri::command]
+pub fn list_secrets(
+    app: AppHandle,
+    encryption: State<'_, Encryption>,
+) -> Result<Vec<Secret>, String> {
+    let key = unlocked_key(&encryption)?;
+    Ok(load(&app, &key)?.into_keys().collect())
+}
+
+#[tauri::command]
+pub fn delete_secret(
+    app: AppHandle,
+    encryption: State<'_, Encryption>,
+    name: Secret,
+) -> Result<bool, String> {
+    let key = unlocked_key(&encryption)?;
+    let mut vault = load(&app, &key)?;
+    let removed = vault.remove(&name).is_some();
+    if removed {
+        save(&app, &key, &vault)?;
+        apply(&app, &vault);
+    }
+    Ok(removed)
+}
@@ -0,0 +1,428 @@
+// FRIDAY AI Assistant - Settings
+// Typed mirror of the `Settings` fields in backend/config.py, persisted in
+// <app config dir>/settings.json and passed to the backend as environment
+// variables, which take precedence over backend/.env. Provider keys live in the
+// secrets vault; storage paths, the port and reload stay with .env and the
+// supervisor because backups, encryption and import locate data/ the same way.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use tauri::{AppHandle, Manager, State};
+
+use crate::backend::{self, BackendStatus, BackendSupervisor};
+
+const CONFIG_FILE: &str = "settings.json";
+const PROVIDERS: &[&str] = &["groq", "gemini"];
+const LANGUAGES: &[&str] = &["python", "javascript", "bash"];
+const SEARCH_PROVIDERS: &[&str] = &["duckduckgo", "bing", "google"];
+const LOG_LEVELS: &[&str] = &["critical", "error", "warning", "info", "debug", "trace"];
+
+/// Field names and defaults match config.py; each field is passed as its
+/// upper-cased name, e.g. `max_conversation_history` -> `MAX_CONVERSATION_HISTORY`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BackendSettings {
+    // LLM
+    pub default_model: String,
+    pub gemini_model: String,
+    /// JSON object of language code -> provider
+    pub language_provider_map: String,
+
+    // Tools
+    pub enable_file_operations: bool,
+    pub enable_code_execution: bool,
+    pub enable_web_search: bool,
+    pub enable_os_automation: bool,
+    pub enable_hardware_monitoring: bool,
+    pub enable_local_tts: bool,
+
+    // Local LLM (llama.cpp)
+    pub enable_local_llm: bool,
+    pub local_llm_model_path: String,
+    pub local_llm_n_ctx: u32,
+    pub local_llm_threads: u32,
+    /// 0 = CPU only, -1 = offload every layer
+    pub local_llm_gpu_layers: i32,
+
+    // Code execution
+    /// Seconds
+    pub code_execution_timeout: u32,
+    pub sandbox_mode: bool,
+    /// Comma-separated
+    pub allowed_languages: String,
+
+    // Web search
+    pub web_search_provider: String,
+    pub google_cse_id: String,
+
+    // Memory
+    pub enable_vector_memory: bool,
+    pub embedding_model: String,
+    pub max_conversation_history: u32,
+    pub chunk_size: u32,
+    pub chunk_overlap: u32,
+
+    // Server (host and access token are set by the supervisor, see backend.rs)
+    pub log_level: String,
+    /// JSON array of origins
+    pub cors_origins: String,
+
+    // Cloud storage
+    pub use_firestore: bool,
+    pub firebase_credentials_path: String,
+
+    // Performance
+    pub max_workers: u32,
+    pub streaming_chunk_size: u32,
+    pub cache_enabled: bool,
+    /// Seconds
+    pub cache_ttl: u32,
+
+    // Advanced
+    pub debug_mode: bool,
+    pub enable_telemetry: bool,
+    pub auto_save_conversations: bool,
+    pub auto_cleanup_days: u32,
+}
+
+impl Default for BackendSettings {
+    fn default() -> Self {
+        Self {
+            default_model: "llama-3.3-70b-versatile".into(),
+            gemini_model: "gemini-2.5-flash".into(),
+            language_provider_map: r#"{"en-US": "groq", "ne-NP": "gemini", "hi-IN": "gemini"}"#
+                .into(),
+            enable_file_operations: true,
+            enable_code_execution: true,
+            enable_web_search: true,
+            enable_os_automation: true,
+            enable_hardware_monitoring: true,
+            enable_local_tts: true,
+            enable_local_llm: false,
+            local_llm_model_path: String::new(),
+            local_llm_n_ctx: 4096,
+            local_llm_threads: 4,
+            local_llm_gpu_layers: 0,
+            code_execution_timeout: 30,
+            sandbox_mode: true,
+            allowed_languages: "python,javascript,bash".into(),
+            web_search_provider: "duckduckgo".into(),
+            google_cse_id: String::new(),
+            enable_vector_memory: true,
+            embedding_model: "all-MiniLM-L6-v2".into(),
+            max_conversation_history: 100,
+            chunk_size: 1000,
+            chunk_overlap: 200,
+            log_level: "info".into(),
+            cors_origins: r#"["http://localhost:1420", "http://localhost:3000"]"#.into(),
+            use_firestore: false,
+            firebase_credentials_path: "firebase-credentials.json".into(),
+            max_workers: 4,
+            streaming_chunk_size: 64,
+            cache_enabled: true,
+            cache_ttl: 3600,
+            debug_mode: false,
+            enable_telemetry: false,
+            auto_save_conversations: true,
+            auto_cleanup_days: 30,
+        }
+    }
+}
+
+/// A field that failed validation
+#[derive(Debug, Clone, Serialize)]
+pub struct SettingError {
+    pub field: &'static str,
+    pub message: String,
+}
+
+impl BackendSettings {
+    pub fn validate(&self) -> Vec<SettingError> {
+        let mut errors = Vec::new();
+        let mut check = |ok: bool, field: &'static str, message: &str| {
+            if !ok {
+                errors.push(SettingError {
+                    field,
+                    message: message.into(),
+                });
+            }
+        };
+
+        check(
+            !self.default_model.trim().is_empty(),
+            "default_model",
+            "must not be empty",
+        );
+        check(
+            serde_json::from_str::<Map<String, Value>>(&self.language_provider_map).is_ok_and(
+                |map| {
+                    map.values()
+                        .all(|p| p.as_str().is_some_and(|p| PROVIDERS.contains(&p)))
+                },
+            ),
+            "language_provider_map",
+            "must be a JSON object mapping languages to groq or gemini",
+        );
+        check(
+            !self.enable_local_llm || Path::new(&self.local_llm_model_path).is_file(),
+            "local_llm_model_path",
+            "must point to a .gguf model file when the local LLM is enabled",
+        );
+        check(
+            self.local_llm_n_ctx >= 512,
+            "local_llm_n_ctx",
+            "must be at least 512",
+        );
+        check(
+            self.local_llm_threads > 0,
+            "local_llm_threads",
+            "must be at least 1",
+        );
+        check(
+            self.local_llm_gpu_layers >= -1,
+            "local_llm_gpu_layers",
+            "must be -1 (all), 0 (CPU only) or a layer count",
+        );
+        check(
+            (1..=600).contains(&self.code_execution_timeout),
+            "code_execution_timeout",
+            "must be between 1 and 600 seconds",
+        );
+        check(
+            self.allowed_languages
+                .split(',')
+                .all(|lang| LANGUAGES.contains(&lang.trim())),
+            "allowed_languages",
+            "may only list python, javascript and bash",
+        );
+        check(
+            SEARCH_PROVIDERS.contains(&self.web_search_provider.as_str()),
+            "web_search_provider",
+            "must be duckduckgo, bing or google",
+        );
+        check(
+            self.max_conversation_history > 0,
+            "max_conversation_history",
+            "must be at least 1",
+        );
+        check(self.chunk_size > 0, "chunk_size", "must be at least 1");
+        check(
+            self.chunk_overlap < self.chunk_size,
+            "chunk_overlap",
+            "must be smaller than chunk_size",
+        );
+        check(
+            LOG_LEVELS.contains(&self.log_level.as_str()),
+            "log_level",
+            "must be one of critical, error, warning, info, debug, trace",
+        );
+        check(
+            serde_json::from_str::<Vec<String>>(&self.cors_origins).is_ok(),
+            "cors_origins",
+            "must be a JSON array of origins",
+        );
+        check(self.max_workers > 0, "max_workers", "must be at least 1");
+        check(
+            self.streaming_chunk_size > 0,
+            "streaming_chunk_size",
+            "must be at least 1",
+        );
+        errors
+    }
+
+    fn fields(&self) -> Map<String, Value> {
+        match serde_json::to_value(self) {
+            Ok(Value::Object(fields)) => fields,
+            _ => Map::new(),
+        }
+    }
+
+    /// Seed from backend/.env so the first launch under the shell keeps what
+    /// was configured there
+    fn from_dotenv() -> Self {
+        let Some(contents) =
+            backend::find_backend_dir().and_then(|dir| fs::read_to_string(dir.join(".env")).ok())
+        else {
+            return Self::default();
+        };
+
+        let mut fields = Self::default().fields();
+        for line in contents.lines() {
+            let Some((name, raw)) = line.split_once('=') else {
+                continue;
+            };
+            let name = name.trim().to_ascii_lowercase();
+            let raw = raw.trim().trim_matches(|c| c == '"' || c == '\'');
+            let Some(field) = fields.get_mut(&name) else {
+                continue;
+            };
+            let value = match field {
+                Value::Bool(_) => Some(
+                    matches!(
+                        raw.to_ascii_lowercase().as_str(),
+                        "true" | "1" | "yes" | "on"
+                    )
+                    .into(),
+                ),
+                Value::Number(_) => raw.parse::<i64>().ok().map(Value::from),
+                _ => Some(Value::from(raw)),
+            };
+            if let Some(value) = value {
+                *field = value;
+            }
+        }
+        serde_json::from_value(Value::Object(fields)).unwrap_or_default()
+    }
+}
+
+fn env_value(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+#[derive(Default)]
+pub struct Settings {
+    current: Mutex<BackendSettings>,
+}
+
+impl Settings {
+    pub fn current(&self) -> BackendSettings {
+        self.current.lock().unwrap().clone()
+    }
+}
+
+/// Emitted as `settings://changed`
+#[derive(Debug, Clone, Serialize)]
+pub struct SettingsChange {
+    pub changed: Vec<String>,
+    /// Applied by restarting the backend rather than live
+    pub restarted: bool,
+}
+
+/// Load the saved settings and hand them to the supervisor. Called once from
+/// `setup`, before the backend can start.
+pub fn init(app: &AppHandle) {
+    let settings = config_path(app)
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_else(BackendSettings::from_dotenv);
+
+    apply_env(app, &settings);
+    *app.state::<Settings>().current.lock().unwrap() = settings;
+}
+
+fn config_path(app: &AppHandle) -> Option<PathBuf> {
+    app.path_resolver()
+        .app_config_dir()
+        .map(|dir| dir.join(CONFIG_FILE))
+}
+
+fn apply_env(app: &AppHandle, settings: &BackendSettings) {
+    let supervisor = app.state::<BackendSupervisor>();
+    for (name, value) in settings.fields() {
+        supervisor.set_env(&name.to_ascii_uppercase(), &env_value(&value));
+    }
+}
+
+#[derive(Deserialize)]
+struct ApplyResult {
+    restart_required: Vec<String>,
+}
+
+/// Ask the running backend to take `changed` live; true if it needs a restart
+async fn hot_apply(supervisor: &BackendSupervisor, changed: Map<String, Value>) -> bool {
+    let url = format!("{}/api/settings/apply", supervisor.base_url());
+    let result = supervisor.client().post(&url).json(&changed).send().await;
+    match result {
+        Ok(resp) => match resp.error_for_status() {
+            Ok(resp) => match resp.json::<ApplyResult>().await {
+                Ok(result) => !result.restart_required.is_empty(),
+                Err(_) => true,
+            },
+            Err(_) => true,
+        },
+        Err(_) => true,
+    }
+}
+
+#[tauri::command]
+pub fn get_settings(settings: State<'_, Settings>) -> BackendSettings {
+    settings.current.lock().unwrap().clone()
+}
+
+#[tauri::command]
+pub fn default_settings() -> BackendSettings {
+    BackendSettings::default()
+}
+
+#[tauri::command]
+pub fn validate_settings(settings: BackendSettings) -> Vec<SettingError> {
+    settings.validate()
+}
+
+/// Save new settings. Changes the backend reads at call time are applied
+/// live; anything else restarts it.
+#[tauri::command]
+pub async fn set_settings(
+    app: AppHandle,
+    settings: State<'_, Settings>,
+    new: BackendSettings,
+) -> Result<SettingsChange, String> {
+    let errors = new.validate();
+    if !errors.is_empty() {
+        return Err(errors
+            .iter()
+            .map(|e| format!("{}: {}", e.field, e.message))
+            .collect::<Vec<_>>()
+            .join("; "));
+    }
+
+    let old = settings.current.lock().unwrap().fields();
+    let changed: Map<String, Value> = new
+        .fields()
+        .into_iter()
+        .filter(|(name, value)| old.get(name) != Some(value))
+        .collect();
+
+    let path = config_path(&app).ok_or("app config directory is unavailable")?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+    }
+    let json = serde_json::to_string_pretty(&new).map_err(|e| e.to_string())?;
+    fs::write(path, json).map_err(|e| e.to_string())?;
+
+    apply_env(&app, &new);
+    *settings.current.lock().unwrap() = new;
+
+    let supervisor = app.state::<BackendSupervisor>().inner().clone();
+    let mut restarted = false;
+    if !changed.is_empty() {
+        match supervisor.status() {
+            // Picks the new environment up whenever it next starts
+            BackendStatus::Stopped | BackendStatus::Failed => {}
+            BackendStatus::Ready => {
+                if hot_apply(&supervisor, changed.clone()).await {
+                    supervisor.restart(app.clone());
+                    restarted = true;
+                }
+            }
+            BackendStatus::Starting | BackendStatus::Restarting => {
+                supervisor.restart(app.clone());
+                restarted = true;
+            }
+        }
+    }
+
+    let change = SettingsChange {
+        changed: changed.into_iter().map(|(name, _)| name).collect(),
+        restarted,
+    };
+    let _ = app.emit_all("settings://changed", &change);
+    Ok(change)
+}
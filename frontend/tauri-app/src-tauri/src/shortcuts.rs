@@ -0,0 +1,245 @@
+// FRIDAY AI Assistant - Global Shortcuts
+// System-wide hotkeys that work even when the webview is unfocused.
+// Bindings persist in <app config dir>/shortcuts.json; every press fires a
+// `shortcut://<action>` event for the frontend.
+
+use std::fs;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, ClipboardManager, GlobalShortcutManager, Manager, State};
+
+use crate::mini;
+use crate::tray;
+
+const CONFIG_FILE: &str = "shortcuts.json";
+
+/// Accelerator per action, e.g. `CmdOrCtrl+Shift+Space`. Empty disables it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ShortcutConfig {
+    pub toggle_window: String,
+    pub push_to_talk: String,
+    pub ask_clipboard: String,
+    pub toggle_mini: String,
+}
+
+impl Default for ShortcutConfig {
+    fn default() -> Self {
+        Self {
+            toggle_window: "CmdOrCtrl+Shift+Space".into(),
+            push_to_talk: "CmdOrCtrl+Shift+F".into(),
+            ask_clipboard: "CmdOrCtrl+Shift+C".into(),
+            toggle_mini: "CmdOrCtrl+Shift+M".into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ShortcutAction {
+    ToggleWindow,
+    PushToTalk,
+    AskClipboard,
+    ToggleMini,
+}
+
+impl ShortcutConfig {
+    fn bindings(&self) -> [(ShortcutAction, &str); 4] {
+        [
+            (ShortcutAction::ToggleWindow, &self.toggle_window),
+            (ShortcutAction::PushToTalk, &self.push_to_talk),
+            (ShortcutAction::AskClipboard, &self.ask_clipboard),
+            (ShortcutAction::ToggleMini, &self.toggle_mini),
+        ]
+    }
+}
+
+/// Outcome of registering one binding, returned to the UI and emitted as
+/// `shortcut://conflict` when registration fails
+#[derive(Debug, Clone, Serialize)]
+pub struct ShortcutStatus {
+    pub action: ShortcutAction,
+    pub accelerator: String,
+    pub registered: bool,
+    pub error: Option<String>,
+}
+
+#[derive(Default)]
+pub struct Shortcuts {
+    config: Mutex<ShortcutConfig>,
+    /// Global hotkeys only report key-down, so push-to-talk toggles
+    talking: AtomicBool,
+}
+
+/// Load the saved bindings and register them. Called once from `setup`.
+pub fn init(app: &AppHandle) {
+    let config = config_path(app)
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default();
+
+    apply(app, &config);
+    *app.state::<Shortcuts>().config.lock().unwrap() = config;
+}
+
+#[tauri::command]
+pub fn get_shortcuts(shortcuts: State<'_, Shortcuts>) -> ShortcutConfig {
+    shortcuts.config.lock().unwrap().clone()
+}
+
+/// Replace all bindings. Bindings that clash with each other are rejected up
+/// front; if another application owns one, the previous bindings are restored
+/// and nothing is saved.
+#[tauri::command]
+pub fn set_shortcuts(
+    app: AppHandle,
+    shortcuts: State<'_, Shortcuts>,
+    config: ShortcutConfig,
+) -> Result<Vec<ShortcutStatus>, String> {
+    let bindings = config.bindings();
+    let mut combos = Vec::new();
+    for (action, accelerator) in bindings {
+        if accelerator.is_empty() {
+            continue;
+        }
+        let combo = parse(accelerator)?;
+        if let Some((other, _)) = combos.iter().find(|(_, c)| *c == combo) {
+            return Err(format!(
+                "{accelerator} is assigned to both {other:?} and {action:?}"
+            ));
+        }
+        combos.push((action, combo));
+    }
+
+    let report = apply(&app, &config);
+    if report.iter().any(|status| !status.registered) {
+        // Keep the bindings that were working rather than saving a broken set
+        let previous = shortcuts.config.lock().unwrap().clone();
+        apply(&app, &previous);
+        let failed: Vec<_> = report
+            .iter()
+            .filter_map(|status| {
+                status
+                    .error
+                    .as_ref()
+                    .map(|e| format!("{}: {e}", status.accelerator))
+            })
+            .collect();
+        return Err(format!(
+            "could not register {}; previous shortcuts kept",
+            failed.join(", ")
+        ));
+    }
+
+    let path = config_path(&app).ok_or("app config directory is unavailable")?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+    }
+    let json = serde_json::to_string_pretty(&config).map_err(|e| e.to_string())?;
+    fs::write(path, json).map_err(|e| e.to_string())?;
+
+    *shortcuts.config.lock().unwrap() = config;
+    Ok(report)
+}
+
+const CTRL: u8 = 1;
+const SHIFT: u8 = 1 << 1;
+const ALT: u8 = 1 << 2;
+const SUPER: u8 = 1 << 3;
+
+/// Split an accelerator into a modifier set and its key so that
+/// `Shift+Ctrl+X` and `Control+Shift+x` compare equal
+fn parse(accelerator: &str) -> Result<(u8, String), String> {
+    let cmd_or_ctrl = if cfg!(target_os = "macos") {
+        SUPER
+    } else {
+        CTRL
+    };
+
+    let mut modifiers = 0;
+    let mut key = None;
+    for token in accelerator.split('+').map(str::trim) {
+        let modifier = match token.to_ascii_uppercase().as_str() {
+            "CTRL" | "CONTROL" => CTRL,
+            "SHIFT" => SHIFT,
+            "ALT" | "OPTION" => ALT,
+            "SUPER" | "CMD" | "COMMAND" | "META" => SUPER,
+            "CMDORCTRL" | "COMMANDORCONTROL" | "COMMANDORCTRL" | "CMDORCONTROL" => cmd_or_ctrl,
+            "" => return Err(format!("{accelerator} is not a valid shortcut")),
+            other => {
+                if key.replace(other.to_string()).is_some() {
+                    return Err(format!("{accelerator} has more than one key"));
+                }
+                continue;
+            }
+        };
+        modifiers |= modifier;
+    }
+
+    match key {
+        Some(key) => Ok((modifiers, key)),
+        None => Err(format!("{accelerator} has no key")),
+    }
+}
+
+/// Unregister everything we own and register `config`, reporting each binding
+fn apply(app: &AppHandle, config: &ShortcutConfig) -> Vec<ShortcutStatus> {
+    let mut manager = app.global_shortcut_manager();
+    let _ = manager.unregister_all();
+
+    let mut report = Vec::new();
+    for (action, accelerator) in config.bindings() {
+        if accelerator.is_empty() {
+            continue;
+        }
+
+        let handle = app.clone();
+        let result = manager.register(accelerator, move || trigger(&handle, action));
+        let status = ShortcutStatus {
+            action,
+            accelerator: accelerator.to_string(),
+            registered: result.is_ok(),
+            error: result.err().map(|e| e.to_string()),
+        };
+        if !status.registered {
+            eprintln!(
+                "[shortcuts] could not register {accelerator}: {:?}",
+                status.error
+            );
+            let _ = app.emit_all("shortcut://conflict", &status);
+        }
+        report.push(status);
+    }
+    report
+}
+
+fn trigger(app: &AppHandle, action: ShortcutAction) {
+    match action {
+        ShortcutAction::ToggleWindow => {
+            tray::toggle_main_window(app);
+            let _ = app.emit_all("shortcut://toggle-window", ());
+        }
+        ShortcutAction::PushToTalk => {
+            let talking = !app
+                .state::<Shortcuts>()
+                .talking
+                .fetch_xor(true, Ordering::SeqCst);
+            let _ = app.emit_all("shortcut://push-to-talk", talking);
+        }
+        ShortcutAction::AskClipboard => {
+            let text = app.clipboard_manager().read_text().ok().flatten();
+            tray::show_main_window(app);
+            let _ = app.emit_all("shortcut://ask-clipboard", text);
+        }
+        ShortcutAction::ToggleMini => mini::toggle(app),
+    }
+}
+
+fn config_path(app: &AppHandle) -> Option<PathBuf> {
+    app.path_resolver()
+        .app_config_dir()
+        .map(|dir| dir.join(CONFIG_FILE))
+}
@@ -0,0 +1,231 @@
+// FRIDAY AI Assistant - Single Instance
+// The first instance listens on a loopback socket whose port is recorded in
+// <app local data dir>/instance.port, next to an owner-only instance.token.
+// Later launches read both and hand their command line to it (e.g. `friday
+// "summarise this" --attach notes.txt`, or a friday:// link) and exit, so
+// there is only ever one webview and one backend. Forwards without the token
+// are dropped, so other users and processes that cannot read our files cannot
+// make FRIDAY read attachments or follow links.
+
+use std::fs;
+use std::io::{BufRead, BufReader, Write};
+use std::net::{Ipv4Addr, TcpListener, TcpStream};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine as _;
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Config, Manager, State};
+
+use crate::api::MAX_UPLOAD_BYTES;
+use crate::backend;
+use crate::deep_link;
+use crate::tray;
+
+const PORT_FILE: &str = "instance.port";
+const TOKEN_FILE: &str = "instance.token";
+const HANDSHAKE: &str = "friday-instance/1";
+const CONNECT_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// What a launch asked FRIDAY to do, parsed from its arguments
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct LaunchRequest {
+    /// Message to send, from `--message <text>` or the first bare argument
+    pub message: Option<String>,
+    /// Files to attach (`--attach <path>`), resolved against the launch directory
+    pub attachments: Vec<PathBuf>,
+    /// Raw arguments, for anything the frontend wants to interpret itself
+    pub args: Vec<String>,
+}
+
+impl LaunchRequest {
+    pub fn from_args(args: &[String], cwd: &Path) -> Self {
+        let mut request = LaunchRequest {
+            args: args.to_vec(),
+            ..Default::default()
+        };
+
+        let mut iter = args.iter();
+        while let Some(arg) = iter.next() {
+            match arg.as_str() {
+                "--message" | "-m" => request.message = iter.next().cloned(),
+                "--attach" | "-a" => {
+                    if let Some(path) = iter.next() {
+                        request.attachments.push(cwd.join(path));
+                    }
+                }
+                other if !other.starts_with('-') && request.message.is_none() => {
+                    request.message = Some(other.to_string());
+                }
+                _ => {}
+            }
+        }
+        request
+    }
+}
+
+/// Attachment paths from forwarded launches. The webview may read each of
+/// these once and nothing else.
+#[derive(Default)]
+pub struct LaunchAttachments(Mutex<Vec<PathBuf>>);
+
+#[derive(Serialize, Deserialize)]
+struct Forward {
+    handshake: String,
+    /// Contents of instance.token
+    token: String,
+    args: Vec<String>,
+    cwd: PathBuf,
+}
+
+/// Socket of the primary instance and the token forwards must carry
+pub struct Listener {
+    socket: TcpListener,
+    token: String,
+}
+
+pub enum Instance {
+    /// We are the first instance; keep the listener for `listen`
+    Primary(Listener),
+    /// Another instance took our arguments; exit now
+    Forwarded,
+}
+
+/// Hand our arguments to a running instance, or become the primary one.
+pub fn acquire(config: &Config) -> Instance {
+    let dir = tauri::api::path::app_local_data_dir(config);
+    let read = |name: &str| {
+        dir.as_ref()
+            .and_then(|dir| fs::read_to_string(dir.join(name)).ok())
+    };
+
+    if let (Some(port), Some(token)) = (
+        read(PORT_FILE).and_then(|port| port.trim().parse::<u16>().ok()),
+        read(TOKEN_FILE),
+    ) {
+        if forward(port, token.trim()).is_ok() {
+            return Instance::Forwarded;
+        }
+    }
+
+    let socket =
+        TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).expect("failed to bind single-instance socket");
+    let token = backend::generate_token();
+    if let (Some(dir), Ok(addr)) = (dir, socket.local_addr()) {
+        // The token goes first so a launch that sees the new port can use it
+        let written = fs::create_dir_all(&dir)
+            .and_then(|_| backend::write_private(&dir.join(TOKEN_FILE), &token))
+            .and_then(|_| fs::write(dir.join(PORT_FILE), addr.port().to_string()));
+        if let Err(e) = written {
+            eprintln!(
+                "[single-instance] could not record port and token in {}: {e}",
+                dir.display()
+            );
+        }
+    }
+    Instance::Primary(Listener { socket, token })
+}
+
+/// Accept forwarded launches, focusing the main window and emitting
+/// `single-instance://launch` with the parsed `LaunchRequest`. friday://
+/// links go to `deep_link` instead.
+pub fn listen(app: AppHandle, listener: Listener) {
+    std::thread::spawn(move || {
+        for stream in listener.socket.incoming().flatten() {
+            if let Some(forward) = receive(stream, &listener.token) {
+                if let Some(link) = deep_link::find(&forward.args) {
+                    deep_link::handle(&app, link);
+                    continue;
+                }
+                let request = LaunchRequest::from_args(&forward.args, &forward.cwd);
+                app.state::<LaunchAttachments>()
+                    .0
+                    .lock()
+                    .unwrap()
+                    .extend(request.attachments.iter().cloned());
+                tray::show_main_window(&app);
+                let _ = app.emit_all("single-instance://launch", request);
+            }
+        }
+    });
+}
+
+/// Read an attachment named by a `single-instance://launch` event, as base64
+#[tauri::command]
+pub fn read_launch_attachment(
+    attachments: State<'_, LaunchAttachments>,
+    path: PathBuf,
+) -> Result<String, String> {
+    {
+        let mut pending = attachments.0.lock().unwrap();
+        let index = pending
+            .iter()
+            .position(|pending| *pending == path)
+            .ok_or("not an attachment from a launch")?;
+        pending.swap_remove(index);
+    }
+
+    let size = fs::metadata(&path)
+        .map_err(|e| format!("{}: {e}", path.display()))?
+        .len();
+    if size > MAX_UPLOAD_BYTES as u64 {
+        return Err(format!(
+            "{} is larger than {} MB",
+            path.display(),
+            MAX_UPLOAD_BYTES / 1024 / 1024
+        ));
+    }
+    let bytes = fs::read(&path).map_err(|e| format!("{}: {e}", path.display()))?;
+    Ok(BASE64.encode(bytes))
+}
+
+fn forward(port: u16, token: &str) -> std::io::Result<()> {
+    let mut stream =
+        TcpStream::connect_timeout(&(Ipv4Addr::LOCALHOST, port).into(), CONNECT_TIMEOUT)?;
+    stream.set_read_timeout(Some(CONNECT_TIMEOUT * 4))?;
+
+    let message = Forward {
+        handshake: HANDSHAKE.into(),
+        token: token.into(),
+        args: std::env::args().skip(1).collect(),
+        cwd: std::env::current_dir().unwrap_or_default(),
+    };
+    serde_json::to_writer(&mut stream, &message)?;
+    stream.write_all(b"\n")?;
+
+    // Anything other than our ack means the port now belongs to someone else
+    let mut ack = String::new();
+    BufReader::new(stream).read_line(&mut ack)?;
+    if ack.trim() == HANDSHAKE {
+        Ok(())
+    } else {
+        Err(std::io::ErrorKind::InvalidData.into())
+    }
+}
+
+fn receive(mut stream: TcpStream, token: &str) -> Option<Forward> {
+    stream.set_read_timeout(Some(CONNECT_TIMEOUT * 4)).ok()?;
+
+    let mut line = String::new();
+    BufReader::new(&stream).read_line(&mut line).ok()?;
+    let forward: Forward = serde_json::from_str(&line).ok()?;
+    if forward.handshake != HANDSHAKE || !same_token(&forward.token, token) {
+        eprintln!("[single-instance] dropped a forward without the instance token");
+        return None;
+    }
+
+    stream.write_all(format!("{HANDSHAKE}\n").as_bytes()).ok()?;
+    Some(forward)
+}
+
+/// Compare without stopping at the first difference
+fn same_token(given: &str, expected: &str) -> bool {
+    given.len() == expected.len()
+        && given
+            .bytes()
+            .zip(expected.bytes())
+            .fold(0, |diff, (a, b)| diff | (a ^ b))
+            == 0
+}
@@ -0,0 +1,995 @@
+// FRIDAY AI Assistant - Conversation Store
+// Conversations and projects in an embedded SQLite database
+// (<app data dir>/friday.db) instead of loose JSON files under data/.
+// The first launch imports those files, keeping their IDs and timestamps.
+// The desktop UI lists, opens, renames and deletes through these commands.
+// The backend still writes chat turns; each one is pulled in when its stream
+// ends, and edits made here are mirrored back to the backend.
+
+use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard};
+use std::time::Duration;
+
+use rusqlite::backup::Backup;
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use tauri::{AppHandle, Manager, State};
+
+use crate::backend::{self, BackendSupervisor};
+use crate::encryption::DataKey;
+use crate::search;
+
+const DB_FILE: &str = "friday.db";
+/// Set in `meta` once data/conversations and data/projects have been imported
+const JSON_IMPORTED: &str = "json_imported_at";
+
+/// Applied in order; `user_version` records how many have run
+const MIGRATIONS: &[&str] = &[SCHEMA, search::SCHEMA];
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS meta (
+    key   TEXT PRIMARY KEY,
+    value TEXT NOT NULL
+);
+CREATE TABLE IF NOT EXISTS projects (
+    id          TEXT PRIMARY KEY,
+    name        TEXT NOT NULL,
+    description TEXT,
+    context     TEXT NOT NULL DEFAULT '{}',
+    created_at  TEXT NOT NULL,
+    updated_at  TEXT NOT NULL,
+    metadata    TEXT NOT NULL DEFAULT '{}'
+);
+CREATE TABLE IF NOT EXISTS project_conversations (
+    project_id      TEXT NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+    conversation_id TEXT NOT NULL,
+    position        INTEGER NOT NULL,
+    PRIMARY KEY (project_id, conversation_id)
+);
+CREATE TABLE IF NOT EXISTS conversations (
+    id         TEXT PRIMARY KEY,
+    name       TEXT,
+    project_id TEXT,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    metadata   TEXT NOT NULL DEFAULT '{}'
+);
+CREATE INDEX IF NOT EXISTS conversations_by_project ON conversations(project_id, updated_at);
+CREATE TABLE IF NOT EXISTS messages (
+    id              INTEGER PRIMARY KEY,
+    conversation_id TEXT NOT NULL REFERENCES conversations(id) ON DELETE CASCADE,
+    position        INTEGER NOT NULL,
+    role            TEXT NOT NULL CHECK (role IN ('user', 'assistant', 'system', 'tool')),
+    content         TEXT NOT NULL,
+    tool_calls      TEXT,
+    tool_call_id    TEXT,
+    timestamp       TEXT NOT NULL,
+    UNIQUE (conversation_id, position)
+);
+";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Role {
+    User,
+    Assistant,
+    System,
+    Tool,
+}
+
+impl Role {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Role::User => "user",
+            Role::Assistant => "assistant",
+            Role::System => "system",
+            Role::Tool => "tool",
+        }
+    }
+
+    fn parse(role: &str) -> Option<Self> {
+        Some(match role {
+            "user" => Role::User,
+            "assistant" => Role::Assistant,
+            "system" => Role::System,
+            "tool" => Role::Tool,
+            _ => return None,
+        })
+    }
+}
+
+/// Mirrors `Message` in backend/models/schemas.py
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Message {
+    pub role: Role,
+    pub content: String,
+    #[serde(default)]
+    pub tool_calls: Option<Vec<Value>>,
+    #[serde(default)]
+    pub tool_call_id: Option<String>,
+    #[serde(default = "now")]
+    pub timestamp: String,
+}
+
+/// Mirrors `Conversation` in backend/models/schemas.py
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Conversation {
+    pub id: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub project_id: Option<String>,
+    #[serde(default)]
+    pub messages: Vec<Message>,
+    #[serde(default = "now")]
+    pub created_at: String,
+    #[serde(default = "now")]
+    pub updated_at: String,
+    #[serde(default)]
+    pub metadata: Map<String, Value>,
+}
+
+/// Mirrors `Project` in backend/models/schemas.py
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Project {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub context: Map<String, Value>,
+    #[serde(default)]
+    pub conversation_ids: Vec<String>,
+    #[serde(default = "now")]
+    pub created_at: String,
+    #[serde(default = "now")]
+    pub updated_at: String,
+    #[serde(default)]
+    pub metadata: Map<String, Value>,
+}
+
+/// A conversation without its messages, for the sidebar
+#[derive(Debug, Clone, Serialize)]
+pub struct ConversationSummary {
+    pub id: String,
+    pub name: Option<String>,
+    pub project_id: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+    pub message_count: u32,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct JsonImport {
+    pub conversations: u32,
+    pub projects: u32,
+    /// Files that could not be read or parsed, with the reason
+    pub failed: Vec<(PathBuf, String)>,
+}
+
+pub struct Store {
+    conn: Mutex<Connection>,
+}
+
+/// Open (or create) the database, import the legacy JSON files on first run
+/// and put the store in managed state. Called once the data key is unlocked.
+pub fn init(app: &AppHandle, key: &DataKey) -> Result<(), Box<dyn std::error::Error>> {
+    let dir = app
+        .path_resolver()
+        .app_data_dir()
+        .ok_or("app data directory is unavailable")?;
+    fs::create_dir_all(&dir)?;
+    let path = dir.join(DB_FILE);
+    if is_plaintext(&path) {
+        encrypt_plaintext(&path, key)?;
+        eprintln!("[store] encrypted {}", path.display());
+    }
+    let store = Store::open(&path, key)?;
+
+    if !store.json_imported()? {
+        if let Some(data_dir) = backend::data_dir() {
+            let report = store.import_json(
+                &legacy_dir("CONVERSATIONS_DIR", &data_dir, "conversations"),
+                &legacy_dir("PROJECTS_DIR", &data_dir, "projects"),
+            )?;
+            eprintln!(
+                "[store] imported {} conversations and {} projects from {}",
+                report.conversations,
+                report.projects,
+                data_dir.display()
+            );
+            for (path, error) in &report.failed {
+                eprintln!("[store] skipped {}: {error}", path.display());
+            }
+        }
+    }
+
+    app.manage(store);
+    Ok(())
+}
+
+/// The managed store, or an error while the data is still locked
+pub fn managed(app: &AppHandle) -> Result<State<'_, Store>, String> {
+    app.try_state::<Store>()
+        .ok_or_else(|| "data is locked".into())
+}
+
+impl Store {
+    /// Open the SQLCipher database with `key`
+    pub fn open(path: &Path, key: &DataKey) -> rusqlite::Result<Self> {
+        let conn = Connection::open(path)?;
+        conn.execute_batch(&format!(
+            "PRAGMA key = \"{}\";",
+            key.sqlcipher_key().as_str()
+        ))?;
+        conn.pragma_update(None, "journal_mode", "WAL")?;
+        conn.pragma_update(None, "foreign_keys", true)?;
+
+        let version: usize = conn.pragma_query_value(None, "user_version", |row| row.get(0))?;
+        for (i, migration) in MIGRATIONS.iter().enumerate().skip(version) {
+            conn.execute_batch(migration)?;
+            conn.pragma_update(None, "user_version", i + 1)?;
+        }
+
+        Ok(Self {
+            conn: Mutex::new(conn),
+        })
+    }
+
+    /// Re-encrypt the database under `key`. SQLCipher cannot rekey in WAL mode,
+    /// so the journal is switched back to rollback for the duration.
+    pub fn rekey(&self, key: &DataKey) -> rusqlite::Result<()> {
+        let conn = self.conn();
+        conn.pragma_update(None, "journal_mode", "DELETE")?;
+        let rekeyed = conn.execute_batch(&format!(
+            "PRAGMA rekey = \"{}\";",
+            key.sqlcipher_key().as_str()
+        ));
+        conn.pragma_update(None, "journal_mode", "WAL")?;
+        rekeyed
+    }
+
+    /// Write a consistent copy to `path`, encrypted with `key`, for a backup
+    pub fn export_copy(&self, path: &Path, key: &DataKey) -> rusqlite::Result<()> {
+        let _ = fs::remove_file(path);
+        let conn = self.conn();
+        let version: i64 = conn.pragma_query_value(None, "user_version", |row| row.get(0))?;
+        conn.execute(
+            "ATTACH DATABASE ?1 AS snapshot KEY ?2",
+            params![path.to_string_lossy(), key.sqlcipher_key().as_str()],
+        )?;
+        let exported = conn
+            .query_row("SELECT sqlcipher_export('snapshot')", [], |_| Ok(()))
+            .and_then(|_| {
+                conn.execute_batch(&format!("PRAGMA snapshot.user_version = {version};"))
+            });
+        conn.execute_batch("DETACH DATABASE snapshot;")?;
+        exported
+    }
+
+    /// Replace every table with `other`'s contents in one step, for a restore
+    pub fn restore_from(&self, other: &Store) -> rusqlite::Result<()> {
+        let source = other.conn();
+        let mut conn = self.conn();
+        let backup = Backup::new(&source, &mut conn)?;
+        backup.run_to_completion(256, Duration::ZERO, None)
+    }
+
+    /// The connection, for modules that keep their own tables in this database
+    pub(crate) fn conn(&self) -> MutexGuard<'_, Connection> {
+        self.conn.lock().unwrap()
+    }
+
+    pub fn list_conversations(
+        &self,
+        project_id: Option<&str>,
+        limit: u32,
+    ) -> rusqlite::Result<Vec<ConversationSummary>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT c.id, c.name, c.project_id, c.created_at, c.updated_at,
+                    (SELECT COUNT(*) FROM messages m WHERE m.conversation_id = c.id)
+             FROM conversations c
+             WHERE ?1 IS NULL OR c.project_id = ?1
+             ORDER BY c.updated_at DESC
+             LIMIT ?2",
+        )?;
+        let rows = stmt.query_map(params![project_id, limit], |row| {
+            Ok(ConversationSummary {
+                id: row.get(0)?,
+                name: row.get(1)?,
+                project_id: row.get(2)?,
+                created_at: row.get(3)?,
+                updated_at: row.get(4)?,
+                message_count: row.get(5)?,
+            })
+        })?;
+        rows.collect()
+    }
+
+    pub fn conversation(&self, id: &str) -> rusqlite::Result<Option<Conversation>> {
+        let conn = self.conn.lock().unwrap();
+        let conversation = conn
+            .query_row(
+                "SELECT id, name, project_id, created_at, updated_at, metadata
+                 FROM conversations WHERE id = ?1",
+                [id],
+                |row| {
+                    Ok(Conversation {
+                        id: row.get(0)?,
+                        name: row.get(1)?,
+                        project_id: row.get(2)?,
+                        messages: Vec::new(),
+                        created_at: row.get(3)?,
+                        updated_at: row.get(4)?,
+                        metadata: json_column(row.get(5)?),
+                    })
+                },
+            )
+            .optional()?;
+        let Some(mut conversation) = conversation else {
+            return Ok(None);
+        };
+
+        let mut stmt = conn.prepare(
+            "SELECT role, content, tool_calls, tool_call_id, timestamp
+             FROM messages WHERE conversation_id = ?1 ORDER BY position",
+        )?;
+        let messages = stmt.query_map([id], |row| {
+            let role: String = row.get(0)?;
+            let tool_calls: Option<String> = row.get(2)?;
+            Ok(Message {
+                role: Role::parse(&role).unwrap_or(Role::User),
+                content: row.get(1)?,
+                tool_calls: tool_calls.and_then(|json| serde_json::from_str(&json).ok()),
+                tool_call_id: row.get(3)?,
+                timestamp: row.get(4)?,
+            })
+        })?;
+        conversation.messages = messages.collect::<rusqlite::Result<_>>()?;
+        Ok(Some(conversation))
+    }
+
+    /// Insert or replace a conversation and all of its messages
+    pub fn save_conversation(&self, conversation: &Conversation) -> rusqlite::Result<()> {
+        let mut conn = self.conn.lock().unwrap();
+        let tx = conn.transaction()?;
+        upsert_conversation(&tx, conversation)?;
+        tx.commit()
+    }
+
+    pub fn rename_conversation(&self, id: &str, name: &str) -> rusqlite::Result<bool> {
+        let conn = self.conn.lock().unwrap();
+        let changed = conn.execute(
+            "UPDATE conversations SET name = ?2, updated_at = ?3 WHERE id = ?1",
+            params![id, name, now()],
+        )?;
+        Ok(changed > 0)
+    }
+
+    pub fn delete_conversation(&self, id: &str) -> rusqlite::Result<bool> {
+        let mut conn = self.conn.lock().unwrap();
+        let tx = conn.transaction()?;
+        tx.execute(
+            "DELETE FROM project_conversations WHERE conversation_id = ?1",
+            [id],
+        )?;
+        let deleted = tx.execute("DELETE FROM conversations WHERE id = ?1", [id])?;
+        tx.commit()?;
+        Ok(deleted > 0)
+    }
+
+    /// Delete every conversation, returning how many there were
+    pub fn delete_all_conversations(&self) -> rusqlite::Result<usize> {
+        let mut conn = self.conn.lock().unwrap();
+        let tx = conn.transaction()?;
+        tx.execute("DELETE FROM project_conversations", [])?;
+        let deleted = tx.execute("DELETE FROM conversations", [])?;
+        tx.commit()?;
+        Ok(deleted)
+    }
+
+    pub fn projects(&self) -> rusqlite::Result<Vec<Project>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare("SELECT id FROM projects ORDER BY updated_at DESC")?;
+        let ids = stmt
+            .query_map([], |row| row.get::<_, String>(0))?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        ids.iter()
+            .filter_map(|id| load_project(&conn, id).transpose())
+            .collect()
+    }
+
+    pub fn project(&self, id: &str) -> rusqlite::Result<Option<Project>> {
+        load_project(&self.conn.lock().unwrap(), id)
+    }
+
+    /// Insert or replace a project and its ordered `conversation_ids`
+    pub fn save_project(&self, project: &Project) -> rusqlite::Result<()> {
+        let mut conn = self.conn.lock().unwrap();
+        let tx = conn.transaction()?;
+        upsert_project(&tx, project)?;
+        tx.commit()
+    }
+
+    pub fn rename_project(&self, id: &str, name: &str) -> rusqlite::Result<bool> {
+        let conn = self.conn.lock().unwrap();
+        let changed = conn.execute(
+            "UPDATE projects SET name = ?2, updated_at = ?3 WHERE id = ?1",
+            params![id, name, now()],
+        )?;
+        Ok(changed > 0)
+    }
+
+    /// Delete a project. Its conversations are kept and detached, as the backend does.
+    pub fn delete_project(&self, id: &str) -> rusqlite::Result<bool> {
+        let mut conn = self.conn.lock().unwrap();
+        let tx = conn.transaction()?;
+        tx.execute(
+            "UPDATE conversations SET project_id = NULL WHERE project_id = ?1",
+            [id],
+        )?;
+        let deleted = tx.execute("DELETE FROM projects WHERE id = ?1", [id])?;
+        tx.commit()?;
+        Ok(deleted > 0)
+    }
+
+    fn json_imported(&self) -> rusqlite::Result<bool> {
+        let conn = self.conn.lock().unwrap();
+        conn.query_row("SELECT 1 FROM meta WHERE key = ?1", [JSON_IMPORTED], |_| {
+            Ok(())
+        })
+        .optional()
+        .map(|row| row.is_some())
+    }
+
+    /// Import `<id>.json` files written by memory.py in one transaction.
+    /// Existing rows win, so re-running never clobbers newer data.
+    pub fn import_json(
+        &self,
+        conversations_dir: &Path,
+        projects_dir: &Path,
+    ) -> rusqlite::Result<JsonImport> {
+        let mut report = JsonImport::default();
+        let projects: Vec<Project> = read_json_dir(projects_dir, &mut report.failed);
+        let conversations: Vec<Conversation> = read_json_dir(conversations_dir, &mut report.failed);
+
+        let mut conn = self.conn.lock().unwrap();
+        let tx = conn.transaction()?;
+        let exists = |table: &str, id: &str| -> rusqlite::Result<bool> {
+            tx.query_row(
+                &format!("SELECT 1 FROM {table} WHERE id = ?1"),
+                [id],
+                |_| Ok(()),
+            )
+            .optional()
+            .map(|row| row.is_some())
+        };
+
+        for project in &projects {
+            if !exists("projects", &project.id)? {
+                upsert_project(&tx, project)?;
+                report.projects += 1;
+            }
+        }
+        for conversation in &conversations {
+            if !exists("conversations", &conversation.id)? {
+                upsert_conversation(&tx, conversation)?;
+                report.conversations += 1;
+            }
+        }
+        tx.execute(
+            "INSERT OR REPLACE INTO meta (key, value) VALUES (?1, ?2)",
+            params![JSON_IMPORTED, now()],
+        )?;
+        tx.commit()?;
+        Ok(report)
+    }
+}
+
+pub(crate) fn upsert_conversation(
+    conn: &Connection,
+    conversation: &Conversation,
+) -> rusqlite::Result<()> {
+    conn.execute(
+        "INSERT INTO conversations (id, name, project_id, created_at, updated_at, metadata)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+         ON CONFLICT(id) DO UPDATE SET
+             name = excluded.name, project_id = excluded.project_id,
+             created_at = excluded.created_at, updated_at = excluded.updated_at,
+             metadata = excluded.metadata",
+        params![
+            conversation.id,
+            conversation.name,
+            conversation.project_id,
+            normalize_timestamp(&conversation.created_at),
+            normalize_timestamp(&conversation.updated_at),
+            Value::Object(conversation.metadata.clone()).to_string(),
+        ],
+    )?;
+
+    // Keep the unchanged prefix so only new or edited messages are re-indexed
+    let kept = {
+        let mut stmt = conn.prepare(
+            "SELECT role, content FROM messages WHERE conversation_id = ?1 ORDER BY position",
+        )?;
+        let existing = stmt
+            .query_map([&conversation.id], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+            })?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        existing
+            .iter()
+            .zip(&conversation.messages)
+            .take_while(|((role, content), message)| {
+                role == message.role.as_str() && *content == message.content
+            })
+            .count()
+    };
+    conn.execute(
+        "DELETE FROM messages WHERE conversation_id = ?1 AND position >= ?2",
+        params![conversation.id, kept as i64],
+    )?;
+    let mut insert = conn.prepare(
+        "INSERT INTO messages
+             (conversation_id, position, role, content, tool_calls, tool_call_id, timestamp)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+    )?;
+    for (position, message) in conversation.messages.iter().enumerate().skip(kept) {
+        insert.execute(params![
+            conversation.id,
+            position as i64,
+            message.role.as_str(),
+            message.content,
+            message
+                .tool_calls
+                .as_ref()
+                .map(|calls| Value::from(calls.clone()).to_string()),
+            message.tool_call_id,
+            normalize_timestamp(&message.timestamp),
+        ])?;
+    }
+
+    // Like memory.py, a conversation joins its project's list when it is first seen
+    if let Some(project_id) = &conversation.project_id {
+        conn.execute(
+            "INSERT OR IGNORE INTO project_conversations (project_id, conversation_id, position)
+             SELECT id, ?2, (SELECT COUNT(*) FROM project_conversations WHERE project_id = ?1)
+             FROM projects WHERE id = ?1",
+            params![project_id, conversation.id],
+        )?;
+    }
+    Ok(())
+}
+
+pub(crate) fn upsert_project(conn: &Connection, project: &Project) -> rusqlite::Result<()> {
+    conn.execute(
+        "INSERT INTO projects (id, name, description, context, created_at, updated_at, metadata)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+         ON CONFLICT(id) DO UPDATE SET
+             name = excluded.name, description = excluded.description,
+             context = excluded.context, created_at = excluded.created_at,
+             updated_at = excluded.updated_at, metadata = excluded.metadata",
+        params![
+            project.id,
+            project.name,
+            project.description,
+            Value::Object(project.context.clone()).to_string(),
+            normalize_timestamp(&project.created_at),
+            normalize_timestamp(&project.updated_at),
+            Value::Object(project.metadata.clone()).to_string(),
+        ],
+    )?;
+
+    conn.execute(
+        "DELETE FROM project_conversations WHERE project_id = ?1",
+        [&project.id],
+    )?;
+    let mut insert = conn.prepare(
+        "INSERT OR IGNORE INTO project_conversations (project_id, conversation_id, position)
+         VALUES (?1, ?2, ?3)",
+    )?;
+    for (position, conversation_id) in project.conversation_ids.iter().enumerate() {
+        insert.execute(params![project.id, conversation_id, position as i64])?;
+    }
+    Ok(())
+}
+
+fn load_project(conn: &Connection, id: &str) -> rusqlite::Result<Option<Project>> {
+    let project = conn
+        .query_row(
+            "SELECT id, name, description, context, created_at, updated_at, metadata
+             FROM projects WHERE id = ?1",
+            [id],
+            |row| {
+                Ok(Project {
+                    id: row.get(0)?,
+                    name: row.get(1)?,
+                    description: row.get(2)?,
+                    context: json_column(row.get(3)?),
+                    conversation_ids: Vec::new(),
+                    created_at: row.get(4)?,
+                    updated_at: row.get(5)?,
+                    metadata: json_column(row.get(6)?),
+                })
+            },
+        )
+        .optional()?;
+    let Some(mut project) = project else {
+        return Ok(None);
+    };
+
+    let mut stmt = conn.prepare(
+        "SELECT conversation_id FROM project_conversations
+         WHERE project_id = ?1 ORDER BY position",
+    )?;
+    project.conversation_ids = stmt
+        .query_map([id], |row| row.get(0))?
+        .collect::<rusqlite::Result<_>>()?;
+    Ok(Some(project))
+}
+
+fn json_column(json: String) -> Map<String, Value> {
+    serde_json::from_str(&json).unwrap_or_default()
+}
+
+fn read_json_dir<T: serde::de::DeserializeOwned>(
+    dir: &Path,
+    failed: &mut Vec<(PathBuf, String)>,
+) -> Vec<T> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .filter_map(|path| {
+            let parsed = fs::read_to_string(&path)
+                .map_err(|e| e.to_string())
+                .and_then(|json| serde_json::from_str(&json).map_err(|e| e.to_string()));
+            parsed.map_err(|e| failed.push((path, e))).ok()
+        })
+        .collect()
+}
+
+/// Databases created before encryption start with the plain SQLite header
+fn is_plaintext(path: &Path) -> bool {
+    let mut header = [0u8; 16];
+    fs::File::open(path)
+        .and_then(|mut file| file.read_exact(&mut header))
+        .is_ok()
+        && &header == b"SQLite format 3\0"
+}
+
+/// Copy a plain database into an encrypted one with `sqlcipher_export` and swap it in
+fn encrypt_plaintext(path: &Path, key: &DataKey) -> Result<(), Box<dyn std::error::Error>> {
+    let encrypted = path.with_extension("db.encrypting");
+    let _ = fs::remove_file(&encrypted);
+    {
+        let conn = Connection::open(path)?;
+        conn.query_row("PRAGMA wal_checkpoint(TRUNCATE)", [], |_| Ok(()))?;
+        let version: i64 = conn.pragma_query_value(None, "user_version", |row| row.get(0))?;
+        conn.execute(
+            "ATTACH DATABASE ?1 AS encrypted KEY ?2",
+            params![encrypted.to_string_lossy(), key.sqlcipher_key().as_str()],
+        )?;
+        conn.query_row("SELECT sqlcipher_export('encrypted')", [], |_| Ok(()))?;
+        conn.execute_batch(&format!(
+            "PRAGMA encrypted.user_version = {version}; DETACH DATABASE encrypted;"
+        ))?;
+    }
+    fs::rename(&encrypted, path)?;
+    for suffix in ["-wal", "-shm"] {
+        let mut sidecar = path.as_os_str().to_owned();
+        sidecar.push(suffix);
+        let _ = fs::remove_file(sidecar);
+    }
+    Ok(())
+}
+
+/// Re-encrypt a copy of the database (a backup's) under `new`, opening it
+/// with whichever of `old` fits
+pub(crate) fn rekey_copy(path: &Path, old: &[&DataKey], new: &DataKey) -> Result<(), String> {
+    for key in old {
+        let conn = Connection::open(path).map_err(|e| e.to_string())?;
+        conn.execute_batch(&format!(
+            "PRAGMA key = \"{}\";",
+            key.sqlcipher_key().as_str()
+        ))
+        .map_err(|e| e.to_string())?;
+        if conn
+            .query_row("SELECT count(*) FROM sqlite_master", [], |_| Ok(()))
+            .is_ok()
+        {
+            return conn
+                .execute_batch(&format!(
+                    "PRAGMA rekey = \"{}\";",
+                    new.sqlcipher_key().as_str()
+                ))
+                .map_err(|e| e.to_string());
+        }
+    }
+    Err("the conversation store was encrypted with a different key".into())
+}
+
+/// config.py lets each directory be overridden; relative paths are from backend/
+fn legacy_dir(env: &str, data_dir: &Path, default: &str) -> PathBuf {
+    match (std::env::var(env), backend::find_backend_dir()) {
+        (Ok(dir), Some(backend_dir)) => backend_dir.join(dir),
+        (Ok(dir), None) => PathBuf::from(dir),
+        (Err(_), _) => data_dir.join(default),
+    }
+}
+
+/// Local time in the `isoformat()` shape the backend writes
+pub(crate) fn now() -> String {
+    chrono::Local::now()
+        .naive_local()
+        .format("%Y-%m-%dT%H:%M:%S%.6f")
+        .to_string()
+}
+
+/// memory.py dumps datetimes with `str()` ("2025-11-10 21:17:26.745944") while
+/// the API returns ISO 8601; store one shape so ordering by text works
+pub(crate) fn normalize_timestamp(timestamp: &str) -> String {
+    match timestamp.as_bytes().get(10) {
+        Some(b' ') => format!("{}T{}", &timestamp[..10], &timestamp[11..]),
+        _ => timestamp.to_string(),
+    }
+}
+
+/// Pull a conversation the backend just wrote to and store it, emitting
+/// `store://conversation` with its id. Called after each chat stream.
+pub async fn sync_from_backend(app: &AppHandle, conversation_id: &str) -> Result<(), String> {
+    let backend = app.state::<BackendSupervisor>();
+    let url = format!(
+        "{}/api/chat/conversations/{conversation_id}",
+        backend.base_url()
+    );
+    let mut conversation: Conversation = backend
+        .client()
+        .get(&url)
+        .send()
+        .await
+        .and_then(|r| r.error_for_status())
+        .map_err(|e| e.to_string())?
+        .json()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    // The backend only appends, so a copy missing some of the store's messages
+    // started over without them (an import it never adopted, or a lost file).
+    // Keep the store's history, add the new turn and hand the result back.
+    let local = managed(app)?
+        .conversation(conversation_id)
+        .map_err(|e| e.to_string())?;
+    if let Some(local) = local {
+        let kept = local
+            .messages
+            .iter()
+            .zip(&conversation.messages)
+            .take_while(|(ours, theirs)| ours.role == theirs.role && ours.content == theirs.content)
+            .count();
+        if kept < local.messages.len() {
+            let new = conversation.messages.split_off(kept);
+            conversation.messages = local.messages;
+            conversation.messages.extend(new);
+            conversation.name = conversation.name.or(local.name);
+            conversation.project_id = conversation.project_id.or(local.project_id);
+            conversation.created_at = local.created_at;
+            if let Err(e) = push_to_backend(app, &conversation).await {
+                eprintln!("[store] could not hand {conversation_id} back to the backend: {e}");
+            }
+        }
+    }
+
+    managed(app)?
+        .save_conversation(&conversation)
+        .map_err(|e| e.to_string())?;
+    let _ = app.emit_all("store://conversation", conversation_id);
+    Ok(())
+}
+
+/// Replace the backend's copy of a conversation with the store's, so the next
+/// message continues its history
+pub async fn push_to_backend(app: &AppHandle, conversation: &Conversation) -> Result<(), String> {
+    let backend = app.state::<BackendSupervisor>();
+    let url = format!(
+        "{}/api/chat/conversations/{}",
+        backend.base_url(),
+        conversation.id
+    );
+    backend
+        .client()
+        .put(&url)
+        .json(conversation)
+        .send()
+        .await
+        .and_then(|r| r.error_for_status())
+        .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// Apply a change to the backend's in-memory copy too, so it is not re-synced
+/// back. Best effort: the store is authoritative either way.
+fn mirror(request: reqwest::RequestBuilder) {
+    tauri::async_runtime::spawn(async move {
+        if let Err(e) = request.send().await.and_then(|r| r.error_for_status()) {
+            eprintln!("[store] could not mirror change to backend: {e}");
+        }
+    });
+}
+
+#[tauri::command]
+pub fn list_conversations(
+    app: AppHandle,
+    project_id: Option<String>,
+    limit: Option<u32>,
+) -> Result<Vec<ConversationSummary>, String> {
+    managed(&app)?
+        .list_conversations(project_id.as_deref(), limit.unwrap_or(50))
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn get_conversation(app: AppHandle, id: String) -> Result<Option<Conversation>, String> {
+    managed(&app)?.conversation(&id).map_err(|e| e.to_string())
+}
+
+/// Create an empty conversation. The backend adopts the id on the first message.
+#[tauri::command]
+pub fn create_conversation(
+    app: AppHandle,
+    name: Option<String>,
+    project_id: Option<String>,
+) -> Result<Conversation, String> {
+    let store = managed(&app)?;
+    let conversation = Conversation {
+        id: uuid::Uuid::new_v4().to_string(),
+        name,
+        project_id,
+        messages: Vec::new(),
+        created_at: now(),
+        updated_at: now(),
+        metadata: Map::new(),
+    };
+    store
+        .save_conversation(&conversation)
+        .map_err(|e| e.to_string())?;
+    Ok(conversation)
+}
+
+#[tauri::command]
+pub fn rename_conversation(
+    app: AppHandle,
+    backend: State<'_, BackendSupervisor>,
+    id: String,
+    name: String,
+) -> Result<(), String> {
+    let store = managed(&app)?;
+    if !store
+        .rename_conversation(&id, &name)
+        .map_err(|e| e.to_string())?
+    {
+        return Err(format!("conversation {id} not found"));
+    }
+    let url = format!("{}/api/chat/conversations/{id}/name", backend.base_url());
+    mirror(backend.client().put(url).query(&[("name", &name)]));
+    Ok(())
+}
+
+#[tauri::command]
+pub fn delete_conversation(
+    app: AppHandle,
+    backend: State<'_, BackendSupervisor>,
+    id: String,
+) -> Result<bool, String> {
+    let store = managed(&app)?;
+    let deleted = store.delete_conversation(&id).map_err(|e| e.to_string())?;
+    if deleted {
+        let url = format!("{}/api/chat/conversations/{id}", backend.base_url());
+        mirror(backend.client().delete(url));
+    }
+    Ok(deleted)
+}
+
+#[tauri::command]
+pub fn delete_all_conversations(
+    app: AppHandle,
+    backend: State<'_, BackendSupervisor>,
+) -> Result<usize, String> {
+    let deleted = managed(&app)?
+        .delete_all_conversations()
+        .map_err(|e| e.to_string())?;
+    let url = format!("{}/api/chat/conversations", backend.base_url());
+    mirror(backend.client().delete(url));
+    Ok(deleted)
+}
+
+#[tauri::command]
+pub fn list_projects(app: AppHandle) -> Result<Vec<Project>, String> {
+    managed(&app)?.projects().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn get_project(app: AppHandle, id: String) -> Result<Option<Project>, String> {
+    managed(&app)?.project(&id).map_err(|e| e.to_string())
+}
+
+/// Create a project. The backend creates it first so chats in the project
+/// find its context there; the store keeps the same id.
+#[tauri::command]
+pub async fn create_project(
+    app: AppHandle,
+    name: String,
+    description: Option<String>,
+    context: Option<Map<String, Value>>,
+) -> Result<Project, String> {
+    let backend = app.state::<BackendSupervisor>().inner().clone();
+    let project: Project = backend
+        .client()
+        .post(format!("{}/api/projects/", backend.base_url()))
+        .json(&serde_json::json!({
+            "name": name,
+            "description": description,
+            "initial_context": context,
+        }))
+        .send()
+        .await
+        .and_then(|r| r.error_for_status())
+        .map_err(|e| e.to_string())?
+        .json()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    managed(&app)?
+        .save_project(&project)
+        .map_err(|e| e.to_string())?;
+    Ok(project)
+}
+
+#[tauri::command]
+pub fn rename_project(
+    app: AppHandle,
+    backend: State<'_, BackendSupervisor>,
+    id: String,
+    name: String,
+) -> Result<(), String> {
+    let store = managed(&app)?;
+    if !store
+        .rename_project(&id, &name)
+        .map_err(|e| e.to_string())?
+    {
+        return Err(format!("project {id} not found"));
+    }
+    let url = format!("{}/api/projects/{id}", backend.base_url());
+    mirror(
+        backend
+            .client()
+            .put(url)
+            .json(&serde_json::json!({ "name": name })),
+    );
+    Ok(())
+}
+
+#[tauri::command]
+pub fn delete_project(
+    app: AppHandle,
+    backend: State<'_, BackendSupervisor>,
+    id: String,
+) -> Result<bool, String> {
+    let store = managed(&app)?;
+    let deleted = store.delete_project(&id).map_err(|e| e.to_string())?;
+    if deleted {
+        mirror(
+            backend
+                .client()
+                .delete(format!("{}/api/projects/{id}", backend.base_url())),
+        );
+    }
+    Ok(deleted)
+}
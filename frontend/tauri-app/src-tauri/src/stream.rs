@@ -0,0 +1,220 @@
+// FRIDAY AI Assistant - Chat stream protocol
+// Decodes the `data: {...}` server-sent events written by the chat endpoints
+// into the `StreamChunk` shapes defined in backend/models/schemas.py.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// One chunk of a streamed chat reply
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum StreamChunk {
+    Token {
+        content: Option<String>,
+    },
+    ToolCall {
+        data: Option<Value>,
+    },
+    ToolResult {
+        data: Option<Value>,
+    },
+    Error {
+        content: Option<String>,
+    },
+    Done,
+    /// Follow-up suggestions sent by `/api/chat/stream` before `done`
+    Followups {
+        data: Option<Value>,
+    },
+    /// Conversation id announced by `/api/chat/v2/stream`
+    ConversationId {
+        id: Option<String>,
+    },
+    #[serde(other)]
+    Unknown,
+}
+
+impl StreamChunk {
+    /// Event suffix used when re-emitting the chunk (`chat://<kind>`)
+    pub fn kind(&self) -> &'static str {
+        match self {
+            StreamChunk::Token { .. } => "token",
+            StreamChunk::ToolCall { .. } => "tool_call",
+            StreamChunk::ToolResult { .. } => "tool_result",
+            StreamChunk::Error { .. } => "error",
+            StreamChunk::Done => "done",
+            StreamChunk::Followups { .. } => "followups",
+            StreamChunk::ConversationId { .. } => "conversation_id",
+            StreamChunk::Unknown => "unknown",
+        }
+    }
+}
+
+/// Incremental SSE decoder; network chunks may split events anywhere
+#[derive(Default)]
+pub struct SseDecoder {
+    buf: Vec<u8>,
+}
+
+impl SseDecoder {
+    /// Feed raw bytes and return every chunk completed by them.
+    /// Payloads that are not valid JSON chunks are skipped.
+    pub fn push(&mut self, bytes: &[u8]) -> Vec<StreamChunk> {
+        self.buf.extend_from_slice(bytes);
+
+        let mut chunks = Vec::new();
+        while let Some(pos) = self.buf.iter().position(|&b| b == b'\n') {
+            let line: Vec<u8> = self.buf.drain(..=pos).collect();
+            let line = String::from_utf8_lossy(&line);
+            if let Some(chunk) = parse_line(line.trim_end_matches(['\r', '\n'])) {
+                chunks.push(chunk);
+            }
+        }
+        chunks
+    }
+
+    /// Flush a trailing event that was not newline-terminated
+    pub fn finish(&mut self) -> Option<StreamChunk> {
+        let rest = std::mem::take(&mut self.buf);
+        parse_line(String::from_utf8_lossy(&rest).trim())
+    }
+}
+
+/// Incremental decoder for endpoints that stream bare text (the local LLM);
+/// each push yields the complete UTF-8 it carried as one token
+#[derive(Default)]
+pub struct TextDecoder {
+    buf: Vec<u8>,
+}
+
+impl TextDecoder {
+    pub fn push(&mut self, bytes: &[u8]) -> Option<StreamChunk> {
+        self.buf.extend_from_slice(bytes);
+        let complete = match std::str::from_utf8(&self.buf) {
+            Ok(text) => text.len(),
+            // A character split across network chunks waits for the rest
+            Err(e) if e.error_len().is_none() => e.valid_up_to(),
+            Err(_) => self.buf.len(),
+        };
+        if complete == 0 {
+            return None;
+        }
+        let text: Vec<u8> = self.buf.drain(..complete).collect();
+        Some(token(&text))
+    }
+
+    pub fn finish(&mut self) -> Option<StreamChunk> {
+        let rest = std::mem::take(&mut self.buf);
+        (!rest.is_empty()).then(|| token(&rest))
+    }
+}
+
+fn token(text: &[u8]) -> StreamChunk {
+    StreamChunk::Token {
+        content: Some(String::from_utf8_lossy(text).into_owned()),
+    }
+}
+
+fn parse_line(line: &str) -> Option<StreamChunk> {
+    let payload = line.strip_prefix("data:")?.trim_start();
+    serde_json::from_str(payload).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn token_text(chunks: impl IntoIterator<Item = StreamChunk>) -> String {
+        chunks
+            .into_iter()
+            .map(|chunk| match chunk {
+                StreamChunk::Token { content } => content.unwrap_or_default(),
+                other => panic!("expected a token, got {other:?}"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn sse_decodes_each_kind() {
+        let mut decoder = SseDecoder::default();
+        let chunks = decoder.push(
+            b"data: {\"type\": \"token\", \"content\": \"Hi\"}\n\
+              data: {\"type\": \"tool_call\", \"data\": {\"name\": \"web_search\"}}\r\n\
+              \n\
+              data: {\"type\": \"conversation_id\", \"id\": \"c1\"}\n\
+              data: {\"type\": \"something_new\"}\n\
+              data: {\"type\": \"done\"}\n",
+        );
+        assert_eq!(
+            chunks,
+            vec![
+                StreamChunk::Token {
+                    content: Some("Hi".into())
+                },
+                StreamChunk::ToolCall {
+                    data: Some(serde_json::json!({ "name": "web_search" }))
+                },
+                StreamChunk::ConversationId {
+                    id: Some("c1".into())
+                },
+                StreamChunk::Unknown,
+                StreamChunk::Done,
+            ]
+        );
+        assert_eq!(decoder.finish(), None);
+    }
+
+    #[test]
+    fn sse_skips_comments_and_bad_json() {
+        let mut decoder = SseDecoder::default();
+        let chunks = decoder
+            .push(b": keep-alive\nevent: ping\ndata: not json\ndata: {\"type\": \"done\"}\n");
+        assert_eq!(chunks, vec![StreamChunk::Done]);
+    }
+
+    #[test]
+    fn sse_joins_events_split_across_chunks() {
+        let event = "data: {\"type\": \"token\", \"content\": \"naïve ☕\"}\n".as_bytes();
+        for split in 1..event.len() {
+            let mut decoder = SseDecoder::default();
+            let mut chunks = decoder.push(&event[..split]);
+            chunks.extend(decoder.push(&event[split..]));
+            assert_eq!(token_text(chunks), "naïve ☕", "split at {split}");
+        }
+    }
+
+    #[test]
+    fn sse_flushes_an_unterminated_event() {
+        let mut decoder = SseDecoder::default();
+        assert!(decoder.push(b"data: {\"type\": \"done\"}").is_empty());
+        assert_eq!(decoder.finish(), Some(StreamChunk::Done));
+    }
+
+    #[test]
+    fn text_holds_back_split_characters() {
+        let text = "ok ☕ नमस्ते".as_bytes();
+        for split in 1..text.len() {
+            let mut decoder = TextDecoder::default();
+            let mut chunks: Vec<_> = decoder.push(&text[..split]).into_iter().collect();
+            chunks.extend(decoder.push(&text[split..]));
+            chunks.extend(decoder.finish());
+            assert_eq!(token_text(chunks), "ok ☕ नमस्ते", "split at {split}");
+        }
+    }
+
+    #[test]
+    fn text_waits_for_a_whole_character() {
+        let mut decoder = TextDecoder::default();
+        let cup = "☕".as_bytes();
+        assert_eq!(decoder.push(&cup[..1]), None);
+        assert_eq!(decoder.push(&cup[1..2]), None);
+        assert_eq!(token_text(decoder.push(&cup[2..])), "☕");
+        assert_eq!(decoder.finish(), None);
+    }
+
+    #[test]
+    fn text_passes_invalid_bytes_through_lossily() {
+        let mut decoder = TextDecoder::default();
+        assert_eq!(token_text(decoder.push(b"a\xffb")), "a\u{fffd}b");
+    }
+}
@@ -0,0 +1,176 @@
+// FRIDAY AI Assistant - System Tray
+// Keeps FRIDAY reachable (wake word, reminders) while the window is hidden,
+// and mirrors the backend supervisor's state in the tray icon and tooltip.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use tauri::{
+    AppHandle, CustomMenuItem, Icon, Manager, SystemTray, SystemTrayEvent, SystemTrayMenu,
+    SystemTrayMenuItem, Window,
+};
+
+use crate::backend::{BackendStatus, BackendSupervisor, SystemHealth};
+use crate::encryption;
+
+const MAIN_WINDOW: &str = "main";
+const BASE_ICON: &[u8] = include_bytes!("../icons/32x32.png");
+
+const TOGGLE: &str = "toggle";
+const NEW_CONVERSATION: &str = "new_conversation";
+const MUTE: &str = "mute";
+const RESTART_BACKEND: &str = "restart_backend";
+const QUIT: &str = "quit";
+
+static MUTED: AtomicBool = AtomicBool::new(false);
+
+pub fn build() -> SystemTray {
+    let menu = SystemTrayMenu::new()
+        .add_item(CustomMenuItem::new(TOGGLE, "Hide FRIDAY"))
+        .add_item(CustomMenuItem::new(NEW_CONVERSATION, "New Conversation"))
+        .add_item(CustomMenuItem::new(MUTE, "Mute Voice"))
+        .add_native_item(SystemTrayMenuItem::Separator)
+        .add_item(CustomMenuItem::new(RESTART_BACKEND, "Restart Backend"))
+        .add_item(CustomMenuItem::new(QUIT, "Quit FRIDAY"));
+
+    SystemTray::new()
+        .with_menu(menu)
+        .with_tooltip("FRIDAY - Starting...")
+}
+
+/// Follow `backend://status` so the tray always shows the current backend state
+pub fn watch_backend(app: &AppHandle) {
+    let handle = app.clone();
+    app.listen_global("backend://status", move |_| refresh(&handle));
+    refresh(app);
+}
+
+pub fn handle_event(app: &AppHandle, event: SystemTrayEvent) {
+    match event {
+        SystemTrayEvent::LeftClick { .. } => toggle_main_window(app),
+        SystemTrayEvent::MenuItemClick { id, .. } => match id.as_str() {
+            TOGGLE => toggle_main_window(app),
+            NEW_CONVERSATION => {
+                show_main_window(app);
+                let _ = app.emit_all("tray://new-conversation", ());
+            }
+            MUTE => {
+                let muted = !MUTED.fetch_xor(true, Ordering::SeqCst);
+                let _ = app.tray_handle().get_item(MUTE).set_selected(muted);
+                let _ = app.emit_all("tray://mute-voice", muted);
+            }
+            RESTART_BACKEND if encryption::is_unlocked(app) => {
+                app.state::<BackendSupervisor>().restart(app.clone())
+            }
+            QUIT => app.exit(0),
+            _ => {}
+        },
+        _ => {}
+    }
+}
+
+/// Closing the main window hides it to the tray instead of quitting
+pub fn hide_on_close(window: &Window, api: &tauri::CloseRequestApi) {
+    if window.label() == MAIN_WINDOW {
+        api.prevent_close();
+        let _ = window.hide();
+        sync_toggle_title(&window.app_handle(), false);
+    }
+}
+
+pub fn toggle_main_window(app: &AppHandle) {
+    let Some(window) = app.get_window(MAIN_WINDOW) else {
+        return;
+    };
+    if window.is_visible().unwrap_or(false) {
+        let _ = window.hide();
+        sync_toggle_title(app, false);
+    } else {
+        show_main_window(app);
+    }
+}
+
+pub fn show_main_window(app: &AppHandle) {
+    if let Some(window) = app.get_window(MAIN_WINDOW) {
+        let _ = window.show();
+        let _ = window.unminimize();
+        let _ = window.set_focus();
+        sync_toggle_title(app, true);
+    }
+}
+
+fn sync_toggle_title(app: &AppHandle, visible: bool) {
+    let title = if visible {
+        "Hide FRIDAY"
+    } else {
+        "Show FRIDAY"
+    };
+    let _ = app.tray_handle().get_item(TOGGLE).set_title(title);
+}
+
+fn refresh(app: &AppHandle) {
+    let backend = app.state::<BackendSupervisor>();
+    let (label, color) = describe(backend.status(), backend.health());
+
+    let tray = app.tray_handle();
+    let _ = tray.set_tooltip(&format!("FRIDAY - {label}"));
+    if let Some(icon) = status_icon(color) {
+        let _ = tray.set_icon(icon);
+    }
+}
+
+/// Tooltip text and badge colour for a backend state
+fn describe(status: BackendStatus, health: SystemHealth) -> (&'static str, [u8; 3]) {
+    const GREEN: [u8; 3] = [0x22, 0xc5, 0x5e];
+    const AMBER: [u8; 3] = [0xf5, 0x9e, 0x0b];
+    const RED: [u8; 3] = [0xef, 0x44, 0x44];
+    const GREY: [u8; 3] = [0x9c, 0xa3, 0xaf];
+
+    match (status, health) {
+        (BackendStatus::Ready, SystemHealth::Healthy) => ("Online", GREEN),
+        (BackendStatus::Ready, SystemHealth::Degraded) => {
+            ("Degraded - some features disabled", AMBER)
+        }
+        (BackendStatus::Ready, SystemHealth::Unhealthy) => ("Unhealthy - check backend logs", RED),
+        (BackendStatus::Ready, SystemHealth::Unknown) => ("Online", GREEN),
+        (BackendStatus::Starting, _) => ("Starting...", GREY),
+        (BackendStatus::Restarting, _) => ("Restarting backend...", AMBER),
+        (BackendStatus::Stopped, _) => ("Backend stopped", GREY),
+        (BackendStatus::Failed, _) => ("Backend failed - use Restart Backend", RED),
+    }
+}
+
+/// The app icon with a status dot in the bottom-right corner
+fn status_icon(color: [u8; 3]) -> Option<Icon> {
+    let decoder = png::Decoder::new(BASE_ICON);
+    let mut reader = decoder.read_info().ok()?;
+    let mut buf = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut buf).ok()?;
+    let (width, height) = (info.width, info.height);
+
+    let mut rgba = match info.color_type {
+        png::ColorType::Rgba => buf[..info.buffer_size()].to_vec(),
+        png::ColorType::Rgb => buf[..info.buffer_size()]
+            .chunks_exact(3)
+            .flat_map(|px| [px[0], px[1], px[2], 0xff])
+            .collect(),
+        _ => return None,
+    };
+
+    let radius = width.min(height) as f32 / 5.0;
+    let (cx, cy) = (width as f32 - radius - 1.0, height as f32 - radius - 1.0);
+    for y in 0..height {
+        for x in 0..width {
+            let (dx, dy) = (x as f32 + 0.5 - cx, y as f32 + 0.5 - cy);
+            if dx * dx + dy * dy <= radius * radius {
+                let i = ((y * width + x) * 4) as usize;
+                rgba[i..i + 4].copy_from_slice(&[color[0], color[1], color[2], 0xff]);
+            }
+        }
+    }
+
+    Some(Icon::Rgba {
+        rgba,
+        width,
+        height,
+    })
+}
@@ -0,0 +1,180 @@
+// FRIDAY AI Assistant - Window State
+// Remembers each window's size, position, maximized state and monitor in
+// <app config dir>/window-state.json. Windows start hidden and are shown once
+// restored; a saved position that is no longer on any attached monitor falls
+// back to the centre of the saved monitor, or of the primary one.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use tauri::{
+    AppHandle, Manager, Monitor, PhysicalPosition, PhysicalSize, Position, Size, Window,
+    WindowEvent,
+};
+
+const STATE_FILE: &str = "window-state.json";
+/// How much of the title bar must be on a monitor for a position to count as visible
+const MIN_VISIBLE: (i32, i32) = (120, 40);
+
+/// Physical pixels, so positions stay meaningful across monitors with different scaling
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WindowState {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+    pub maximized: bool,
+    pub monitor: Option<String>,
+}
+
+#[derive(Default)]
+pub struct WindowStates {
+    saved: Mutex<HashMap<String, WindowState>>,
+}
+
+/// Load the saved states and restore every window from tauri.conf.json.
+/// Called once from `setup`.
+pub fn init(app: &AppHandle) {
+    let saved: HashMap<String, WindowState> = state_path(app)
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default();
+    *app.state::<WindowStates>().saved.lock().unwrap() = saved;
+
+    for window in app.windows().into_values() {
+        restore(&window);
+        let _ = window.show();
+    }
+}
+
+/// Apply the saved state for this window's label, if there is one
+pub fn restore(window: &Window) {
+    let Some(state) = window
+        .state::<WindowStates>()
+        .saved
+        .lock()
+        .unwrap()
+        .get(window.label())
+        .cloned()
+    else {
+        return;
+    };
+
+    let monitors = window.available_monitors().unwrap_or_default();
+    let saved_monitor = state
+        .monitor
+        .as_ref()
+        .and_then(|name| monitors.iter().find(|m| m.name() == Some(name)));
+
+    let (position, size) = if monitors.iter().any(|m| shows(m, &state)) {
+        (
+            PhysicalPosition::new(state.x, state.y),
+            PhysicalSize::new(state.width, state.height),
+        )
+    } else {
+        let monitor = saved_monitor
+            .cloned()
+            .or_else(|| window.primary_monitor().ok().flatten())
+            .or_else(|| monitors.first().cloned());
+        let Some(monitor) = monitor else {
+            return;
+        };
+        centered(&monitor, &state)
+    };
+
+    let _ = window.set_size(Size::Physical(size));
+    let _ = window.set_position(Position::Physical(position));
+    if state.maximized {
+        let _ = window.maximize();
+    }
+}
+
+/// Whether enough of the window's top edge would be on `monitor` to grab it
+fn shows(monitor: &Monitor, state: &WindowState) -> bool {
+    let (mx, my) = (monitor.position().x, monitor.position().y);
+    let (mw, mh) = (monitor.size().width as i32, monitor.size().height as i32);
+    let right = state.x.saturating_add(state.width as i32).min(mx + mw);
+    let left = state.x.max(mx);
+    right - left >= MIN_VISIBLE.0 && state.y >= my && state.y + MIN_VISIBLE.1 <= my + mh
+}
+
+/// The saved size, shrunk to fit if needed, centred on `monitor`
+fn centered(monitor: &Monitor, state: &WindowState) -> (PhysicalPosition<i32>, PhysicalSize<u32>) {
+    let area = monitor.size();
+    let size = PhysicalSize::new(state.width.min(area.width), state.height.min(area.height));
+    let position = PhysicalPosition::new(
+        monitor.position().x + (area.width - size.width) as i32 / 2,
+        monitor.position().y + (area.height - size.height) as i32 / 2,
+    );
+    (position, size)
+}
+
+/// Keep the in-memory state current; written to disk by `save`
+pub fn track(window: &Window, event: &WindowEvent) {
+    if !matches!(event, WindowEvent::Moved(_) | WindowEvent::Resized(_)) {
+        return;
+    }
+    if window.is_minimized().unwrap_or(false) || !window.is_visible().unwrap_or(false) {
+        return;
+    }
+
+    let maximized = window.is_maximized().unwrap_or(false);
+    let monitor = window
+        .current_monitor()
+        .ok()
+        .flatten()
+        .and_then(|m| m.name().cloned());
+
+    let states = window.state::<WindowStates>();
+    let mut saved = states.saved.lock().unwrap();
+    if maximized {
+        // Keep the restored bounds so un-maximizing next launch lands where it was
+        if let Some(state) = saved.get_mut(window.label()) {
+            state.maximized = true;
+            state.monitor = monitor;
+            return;
+        }
+    }
+
+    let (Ok(position), Ok(size)) = (window.outer_position(), window.inner_size()) else {
+        return;
+    };
+    saved.insert(
+        window.label().to_owned(),
+        WindowState {
+            x: position.x,
+            y: position.y,
+            width: size.width,
+            height: size.height,
+            maximized,
+            monitor,
+        },
+    );
+}
+
+/// Persist every window's state. Called when a window closes and on exit.
+pub fn save(app: &AppHandle) {
+    let Some(path) = state_path(app) else {
+        return;
+    };
+    let states = app.state::<WindowStates>();
+    let json = serde_json::to_string_pretty(&*states.saved.lock().unwrap());
+    let written = json.map_err(|e| e.to_string()).and_then(|json| {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+        }
+        fs::write(&path, json).map_err(|e| e.to_string())
+    });
+    if let Err(e) = written {
+        eprintln!("[window_state] could not save {}: {e}", path.display());
+    }
+}
+
+fn state_path(app: &AppHandle) -> Option<PathBuf> {
+    app.path_resolver()
+        .app_config_dir()
+        .map(|dir| dir.join(STATE_FILE))
+}
@@ -0,0 +1,116 @@
+// Model output reaches the chat view only through sanitize::render; nothing a
+// prompt-injected reply contains may run once it is in the DOM.
+
+#[allow(dead_code)]
+#[path = "../src/sanitize.rs"]
+mod sanitize;
+
+/// No executable markup survives: every remaining tag is checked for script
+/// elements, `on*` handler attributes and script URLs. Text may still mention
+/// them; it is escaped.
+fn assert_inert(html: &str) {
+    let lower = html.to_ascii_lowercase();
+    let mut rest = lower.as_str();
+    while let Some(start) = rest.find('<') {
+        let end = rest[start..]
+            .find('>')
+            .map_or(rest.len(), |end| start + end);
+        let tag = &rest[start + 1..end];
+        let name = tag.split_whitespace().next().unwrap_or_default();
+        assert!(
+            !matches!(
+                name.trim_start_matches('/'),
+                "script" | "iframe" | "object" | "embed" | "svg" | "style" | "form" | "body"
+            ),
+            "<{name}> survived in {html}"
+        );
+        assert!(
+            !tag.split_whitespace()
+                .skip(1)
+                .any(|attr| attr.starts_with("on")),
+            "event handler survived in <{tag}>"
+        );
+        assert!(
+            !tag.contains("javascript:"),
+            "script url survived in <{tag}>"
+        );
+        rest = &rest[end..];
+    }
+}
+
+#[test]
+fn script_tags_are_shown_as_text() {
+    let html = sanitize::render("Hello <script>alert('pwned')</script> world");
+    assert_inert(&html);
+    assert!(html.contains("&lt;script&gt;"), "{html}");
+}
+
+#[test]
+fn script_block_is_shown_as_text() {
+    let html =
+        sanitize::render("<script>\nfetch('http://127.0.0.1:8000/api/keyboard/send')\n</script>\n");
+    assert_inert(&html);
+}
+
+#[test]
+fn event_handlers_are_inert() {
+    for markdown in [
+        "<img src=x onerror=\"alert(1)\">",
+        "<div onmouseover='alert(1)'>hover me</div>",
+        "<a href=\"#\" onclick=\"alert(1)\">click</a>",
+        "<svg onload=alert(1)></svg>",
+        "<details open ontoggle=alert(1)>",
+        "<iframe srcdoc=\"<script>alert(1)</script>\"></iframe>",
+        "<body onload=alert(1)>",
+    ] {
+        assert_inert(&sanitize::render(markdown));
+    }
+}
+
+#[test]
+fn script_links_are_dropped() {
+    for markdown in [
+        "[click me](javascript:alert(1))",
+        "[click me](JaVaScRiPt:alert(1))",
+        "![x](javascript:alert(1))",
+        "<javascript:alert(1)>",
+        "[x](data:text/html;base64,PHNjcmlwdD5hbGVydCgxKTwvc2NyaXB0Pg==)",
+    ] {
+        let html = sanitize::render(markdown);
+        assert_inert(&html);
+        assert!(!html.contains("data:text/html"), "{html}");
+    }
+}
+
+#[test]
+fn html_from_other_renderers_is_cleaned() {
+    let html = sanitize::clean(
+        "<p onclick=\"alert(1)\">hi</p><script>alert(2)</script><a href=\"javascript:alert(3)\">x</a>",
+    );
+    assert_inert(&html);
+    assert!(html.contains("<p>hi</p>"), "{html}");
+}
+
+#[test]
+fn formatting_survives() {
+    let html = sanitize::render(
+        "# Title\n\n**bold** and `code`\nnext line\n\n```rust\nfn main() {}\n```\n\n| a | b |\n|---|---|\n| 1 | 2 |\n\n- [x] done\n\n[docs](https://example.com)",
+    );
+    assert!(html.contains("<h1>Title</h1>"), "{html}");
+    assert!(html.contains("<strong>bold</strong>"), "{html}");
+    assert!(html.contains("<br>"), "{html}");
+    assert!(html.contains("<code class=\"language-rust\">"), "{html}");
+    assert!(html.contains("<table>"), "{html}");
+    assert!(html.contains("type=\"checkbox\""), "{html}");
+    assert!(html.contains("href=\"https://example.com\""), "{html}");
+    assert!(html.contains("rel=\"noopener noreferrer\""), "{html}");
+}
+
+#[test]
+fn code_blocks_keep_only_language_classes() {
+    let html =
+        sanitize::clean("<code class=\"language-js evil\">x</code><span class=\"x\">y</span>");
+    assert!(html.contains("<code class=\"language-js\">"), "{html}");
+    assert!(!html.contains("evil"), "{html}");
+    assert!(!html.contains("class=\"x\""), "{html}");
+}
@@ -3,6 +3,8 @@
 // ============================================
 // Professional wake word with VAD, auto-submit, and smooth UX
 
+import { backendCall } from './backend_api.js';
+
 class AdvancedWakeWordSystem {
     constructor() {
         this.isActive = false;
@@ -449,18 +451,11 @@ class AdvancedWakeWordSystem {
         try {
             console.log('🎙️ Executing voice command:', command);
             
-            const API_BASE_URL = import.meta.env.VITE_API_URL || 'http://localhost:8000';
-            const response = await fetch(`${API_BASE_URL}/api/voice/execute`, {
-                method: 'POST',
-                headers: { 'Content-Type': 'application/json' },
-                body: JSON.stringify({
-                    command: command,
-                    language: window.fridayState?.language || 'en-US'
-                })
+            const result = await backendCall('voice_command', {
+                command: command,
+                language: window.fridayState?.language || 'en-US'
             });
             
-            const result = await response.json();
-            
             if (result.success) {
                 console.log('✅ Voice command executed:', result);
                 
@@ -498,8 +493,6 @@ class AdvancedWakeWordSystem {
 
     async executeSystemCommand(command) {
         try {
-            const API_BASE_URL = import.meta.env.VITE_API_URL || 'http://localhost:8000';
-            
             const commandMap = {
                 'sleep_pc': { gesture: 'sleep_pc' },
                 'lock_pc': { gesture: 'lock_pc' },
@@ -509,13 +502,7 @@ class AdvancedWakeWordSystem {
             const cmd = commandMap[command];
             if (!cmd) return;
             
-            const response = await fetch(`${API_BASE_URL}/api/gesture/execute`, {
-                method: 'POST',
-                headers: { 'Content-Type': 'application/json' },
-                body: JSON.stringify(cmd)
-            });
-            
-            const result = await response.json();
+            const result = await backendCall('gesture', cmd);
             console.log('✅ System command executed:', result);
             
         } catch (error) {
@@ -3,6 +3,13 @@
  * Echo Show-inspired visual display for timers, lists, and notifications
  */
 
+// List items come from speech and the backend; show them as text only
+function escapeHtml(text) {
+    const div = document.createElement('div');
+    div.textContent = String(text ?? '');
+    return div.innerHTML;
+}
+
 class AlexaVisualFeedback {
     constructor() {
         this.createStyles();
@@ -262,7 +269,7 @@ class AlexaVisualFeedback {
         const listHtml = todos.map(todo => `
             <div class="alexa-list-item">
                 <div class="alexa-list-checkbox ${todo.completed ? 'checked' : ''}"></div>
-                <div class="alexa-list-text">${todo.text}</div>
+                <div class="alexa-list-text">${escapeHtml(todo.text)}</div>
             </div>
         `).join('');
 
@@ -277,7 +284,7 @@ class AlexaVisualFeedback {
         const listHtml = items.map(item => `
             <div class="alexa-list-item">
                 <div class="alexa-list-checkbox ${item.purchased ? 'checked' : ''}"></div>
-                <div class="alexa-list-text">${item.quantity} ${item.item}</div>
+                <div class="alexa-list-text">${escapeHtml(item.quantity)} ${escapeHtml(item.item)}</div>
             </div>
         `).join('');
 
@@ -292,7 +299,7 @@ class AlexaVisualFeedback {
         const stepsHtml = steps.map((step, index) => `
             <div class="alexa-routine-step" id="routine-step-${index}">
                 <div class="alexa-routine-step-icon">${step.icon || '▶️'}</div>
-                <div class="alexa-routine-step-text">${step.text}</div>
+                <div class="alexa-routine-step-text">${escapeHtml(step.text)}</div>
             </div>
         `).join('');
 
@@ -312,11 +319,13 @@ class AlexaVisualFeedback {
         card.className = 'alexa-card';
         card.innerHTML = `
             <div class="alexa-card-header">
-                <div class="alexa-card-title">${title}</div>
-                <button class="alexa-card-close" onclick="window.alexaVisualFeedback.closeCard('${id}')">×</button>
+                <div class="alexa-card-title"></div>
+                <button class="alexa-card-close">×</button>
             </div>
             <div class="alexa-card-content">${content}</div>
         `;
+        card.querySelector('.alexa-card-title').textContent = title;
+        card.querySelector('.alexa-card-close').addEventListener('click', () => this.closeCard(id));
 
         this.container.appendChild(card);
         return card;
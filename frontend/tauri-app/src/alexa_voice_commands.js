@@ -3,7 +3,7 @@
  * Handles timers, lists, routines, and smart queries
  */
 
-const API_BASE_URL = import.meta.env.VITE_API_URL || 'http://localhost:8000';
+import { backendCall } from './backend_api.js';
 
 class AlexaVoiceCommands {
     constructor() {
@@ -134,13 +134,7 @@ class AlexaVoiceCommands {
                 return true;
             }
 
-            const response = await fetch(`${API_BASE_URL}/api/alexa/timer/set`, {
-                method: 'POST',
-                headers: { 'Content-Type': 'application/json' },
-                body: JSON.stringify({ duration, name: 'Timer' })
-            });
-
-            const result = await response.json();
+            const result = await backendCall('set_timer', { duration, name: 'Timer' });
             
             if (result.success) {
                 this.speak(result.message);
@@ -166,8 +160,7 @@ class AlexaVoiceCommands {
 
     async handleCheckTimers() {
         try {
-            const response = await fetch(`${API_BASE_URL}/api/alexa/timers`);
-            const result = await response.json();
+            const result = await backendCall('timers');
 
             if (result.count === 0) {
                 this.speak("You don't have any active timers");
@@ -187,11 +180,7 @@ class AlexaVoiceCommands {
 
     async handleCancelTimer() {
         try {
-            const response = await fetch(`${API_BASE_URL}/api/alexa/timer/cancel`, {
-                method: 'POST'
-            });
-
-            const result = await response.json();
+            const result = await backendCall('cancel_timer');
             this.speak(result.message);
             return true;
         } catch (error) {
@@ -214,13 +203,7 @@ class AlexaVoiceCommands {
                 return true;
             }
 
-            const response = await fetch(`${API_BASE_URL}/api/alexa/todo/add`, {
-                method: 'POST',
-                headers: { 'Content-Type': 'application/json' },
-                body: JSON.stringify({ item: task, list_name: 'default' })
-            });
-
-            const result = await response.json();
+            const result = await backendCall('add_todo', { item: task, list_name: 'default' });
             
             if (result.success) {
                 this.speak(result.message);
@@ -236,8 +219,7 @@ class AlexaVoiceCommands {
 
     async handleGetTodos() {
         try {
-            const response = await fetch(`${API_BASE_URL}/api/alexa/todos?list_name=default`);
-            const result = await response.json();
+            const result = await backendCall('todos', { list_name: 'default' });
 
             if (result.count === 0) {
                 this.speak("Your to-do list is empty. Great job!");
@@ -263,13 +245,7 @@ class AlexaVoiceCommands {
         try {
             const task = this.extractTaskFromCommand(command);
             
-            const response = await fetch(`${API_BASE_URL}/api/alexa/todo/complete`, {
-                method: 'POST',
-                headers: { 'Content-Type': 'application/json' },
-                body: JSON.stringify({ item_text: task, list_name: 'default' })
-            });
-
-            const result = await response.json();
+            const result = await backendCall('complete_todo', { item_text: task, list_name: 'default' });
             this.speak(result.message);
             return true;
         } catch (error) {
@@ -291,13 +267,7 @@ class AlexaVoiceCommands {
                 return true;
             }
 
-            const response = await fetch(`${API_BASE_URL}/api/alexa/shopping/add`, {
-                method: 'POST',
-                headers: { 'Content-Type': 'application/json' },
-                body: JSON.stringify({ item, quantity: '1' })
-            });
-
-            const result = await response.json();
+            const result = await backendCall('add_shopping', { item, quantity: '1' });
             
             if (result.success) {
                 this.speak(result.message);
@@ -313,8 +283,7 @@ class AlexaVoiceCommands {
 
     async handleGetShopping() {
         try {
-            const response = await fetch(`${API_BASE_URL}/api/alexa/shopping`);
-            const result = await response.json();
+            const result = await backendCall('shopping');
 
             if (result.count === 0) {
                 this.speak("Your shopping list is empty");
@@ -342,13 +311,7 @@ class AlexaVoiceCommands {
 
     async handleRunRoutine(routineName) {
         try {
-            const response = await fetch(`${API_BASE_URL}/api/alexa/routine/run`, {
-                method: 'POST',
-                headers: { 'Content-Type': 'application/json' },
-                body: JSON.stringify({ routine_name: routineName })
-            });
-
-            const result = await response.json();
+            const result = await backendCall('run_routine', { routine_name: routineName });
             
             if (result.success) {
                 this.speak(`Running ${routineName} routine`);
@@ -379,8 +342,7 @@ class AlexaVoiceCommands {
 
         this.timerCheckInterval = setInterval(async () => {
             try {
-                const response = await fetch(`${API_BASE_URL}/api/alexa/timers`);
-                const result = await response.json();
+                const result = await backendCall('timers');
                 
                 // Store current timers
                 this.activeTimers = result.timers || [];
@@ -0,0 +1,22 @@
+// ============================================
+// FRIDAY Backend Bridge
+// The webview has no HTTP access to the backend (see the CSP in
+// tauri.conf.json); every request is one `backend_call` that Rust validates
+// and forwards. Call names and arguments match `BackendCall` in api.rs.
+// ============================================
+
+import { invoke } from '@tauri-apps/api/tauri';
+
+export function backendCall(call, args = {}) {
+    return invoke('backend_call', { call: { call, ...args } });
+}
+
+// Files and recordings cross IPC as base64
+export function toBase64(blob) {
+    return new Promise((resolve, reject) => {
+        const reader = new FileReader();
+        reader.onload = () => resolve(reader.result.slice(reader.result.indexOf(',') + 1));
+        reader.onerror = () => reject(reader.error);
+        reader.readAsDataURL(blob);
+    });
+}
@@ -3,6 +3,8 @@
 // ============================================
 // Advanced wake word with multiple variations and real-life usability
 
+import { backendCall } from './backend_api.js';
+
 class EnhancedWakeWordSystem {
     constructor() {
         this.isActive = false;
@@ -341,8 +343,6 @@ class EnhancedWakeWordSystem {
     
     async executeSystemCommand(command) {
         try {
-            const API_BASE_URL = 'http://localhost:8000';
-            
             const commandMap = {
                 'sleep_pc': { gesture: 'sleep_pc' },
                 'lock_pc': { gesture: 'lock_pc' },
@@ -352,13 +352,7 @@ class EnhancedWakeWordSystem {
             const cmd = commandMap[command];
             if (!cmd) return;
             
-            const response = await fetch(`${API_BASE_URL}/api/gesture/execute`, {
-                method: 'POST',
-                headers: { 'Content-Type': 'application/json' },
-                body: JSON.stringify(cmd)
-            });
-            
-            const result = await response.json();
+            const result = await backendCall('gesture', cmd);
             console.log('✅ System command executed:', result);
             
         } catch (error) {
@@ -2,11 +2,12 @@
 // ADVANCED ERROR HANDLER WITH AUTO-RECONNECTION
 // ============================================
 
+import { backendCall } from './backend_api.js';
+
 class ErrorHandler {
     constructor() {
         this.maxRetries = 3;
         this.retryDelay = 2000;
-        this.backendUrl = import.meta.env?.VITE_API_URL || 'http://localhost:8000';
         this.isBackendOnline = true;
         this.reconnectionAttempts = 0;
         
@@ -53,8 +54,8 @@ class ErrorHandler {
     handlePromiseRejection(event) {
         console.error('❌ Unhandled promise rejection:', event.reason);
         
-        // Check if it's a network error
-        if (event.reason && event.reason.message && event.reason.message.includes('fetch')) {
+        // Backend calls reject with a plain message when the server is down
+        if (String(event.reason?.message ?? event.reason).includes('backend is not running')) {
             this.handleNetworkError();
         }
     }
@@ -85,25 +86,19 @@ class ErrorHandler {
         console.log(`🔄 Reconnection attempt ${this.reconnectionAttempts}...`);
         
         try {
-            const response = await fetch(`${this.backendUrl}/health`, {
-                method: 'GET',
-                signal: AbortSignal.timeout(5000)
-            });
+            await backendCall('health');
+            this.isBackendOnline = true;
+            this.reconnectionAttempts = 0;
             
-            if (response.ok) {
-                this.isBackendOnline = true;
-                this.reconnectionAttempts = 0;
-                
-                if (window.notificationSystem) {
-                    window.notificationSystem.show(
-                        '✅ Backend reconnected successfully!',
-                        'success'
-                    );
-                }
-                
-                console.log('✅ Backend reconnected');
-                return true;
+            if (window.notificationSystem) {
+                window.notificationSystem.show(
+                    '✅ Backend reconnected successfully!',
+                    'success'
+                );
             }
+            
+            console.log('✅ Backend reconnected');
+            return true;
         } catch (error) {
             console.log('❌ Reconnection failed');
         }
@@ -136,14 +131,9 @@ class ErrorHandler {
         // Check backend health every 30 seconds
         setInterval(async () => {
             try {
-                const response = await fetch(`${this.backendUrl}/health`, {
-                    method: 'GET',
-                    signal: AbortSignal.timeout(3000)
-                });
+                await backendCall('health');
                 
-                if (!response.ok && this.isBackendOnline) {
-                    this.handleNetworkError();
-                } else if (response.ok && !this.isBackendOnline) {
+                if (!this.isBackendOnline) {
                     // Backend came back online
                     this.isBackendOnline = true;
                     this.reconnectionAttempts = 0;
@@ -163,38 +153,6 @@ class ErrorHandler {
         }, 30000);
     }
 
-    // ============================================
-    // API CALL WRAPPER WITH RETRY
-    // ============================================
-
-    async fetchWithRetry(url, options = {}, retries = 3) {
-        for (let i = 0; i < retries; i++) {
-            try {
-                const response = await fetch(url, {
-                    ...options,
-                    signal: AbortSignal.timeout(options.timeout || 30000)
-                });
-                
-                if (!response.ok) {
-                    throw new Error(`HTTP ${response.status}: ${response.statusText}`);
-                }
-                
-                return response;
-                
-            } catch (error) {
-                console.log(`Attempt ${i + 1}/${retries} failed:`, error.message);
-                
-                if (i === retries - 1) {
-                    // Last retry failed
-                    throw error;
-                }
-                
-                // Wait before retrying (exponential backoff)
-                await new Promise(resolve => setTimeout(resolve, 1000 * Math.pow(2, i)));
-            }
-        }
-    }
-
     // ============================================
     // SPECIFIC ERROR HANDLERS
     // ============================================
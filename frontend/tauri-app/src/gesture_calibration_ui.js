@@ -3,6 +3,8 @@
 // Advanced calibration for different lighting/camera conditions
 // ============================================
 
+import { backendCall } from './backend_api.js';
+
 class GestureCalibrationUI {
     constructor() {
         this.isCalibrating = false;
@@ -25,7 +27,7 @@ class GestureCalibrationUI {
             <div class="modal-content" style="max-width: 700px;">
                 <div class="modal-header">
                     <h2>🖐️ Gesture Control Calibration</h2>
-                    <button class="modal-close" onclick="this.closest('.modal-overlay').remove()">×</button>
+                    <button class="modal-close" data-dismiss>×</button>
                 </div>
                 <div class="modal-body">
                     <div class="settings-section">
@@ -89,13 +91,13 @@ class GestureCalibrationUI {
                     <div class="settings-section">
                         <h3>Quick Presets</h3>
                         <div style="display: grid; grid-template-columns: repeat(3, 1fr); gap: 0.5rem;">
-                            <button class="friday-btn-secondary" onclick="window.gestureCalibration.applyPreset('precise')" style="padding: 0.75rem;">
+                            <button class="friday-btn-secondary" data-preset="precise" style="padding: 0.75rem;">
                                 🎯 Precise
                             </button>
-                            <button class="friday-btn-secondary" onclick="window.gestureCalibration.applyPreset('balanced')" style="padding: 0.75rem;">
+                            <button class="friday-btn-secondary" data-preset="balanced" style="padding: 0.75rem;">
                                 ⚖️ Balanced
                             </button>
-                            <button class="friday-btn-secondary" onclick="window.gestureCalibration.applyPreset('fast')" style="padding: 0.75rem;">
+                            <button class="friday-btn-secondary" data-preset="fast" style="padding: 0.75rem;">
                                 ⚡ Fast
                             </button>
                         </div>
@@ -111,13 +113,13 @@ class GestureCalibrationUI {
                     </div>
                 </div>
                 <div class="modal-footer">
-                    <button class="friday-btn" onclick="window.gestureCalibration.saveCalibration()">
+                    <button class="friday-btn" id="calibration-save-btn">
                         ✅ Save & Apply
                     </button>
-                    <button class="friday-btn-secondary" onclick="window.gestureCalibration.testCalibration()">
+                    <button class="friday-btn-secondary" id="calibration-test-btn">
                         🧪 Test Settings
                     </button>
-                    <button class="friday-btn-secondary" onclick="this.closest('.modal-overlay').remove()">
+                    <button class="friday-btn-secondary" data-dismiss>
                         Cancel
                     </button>
                 </div>
@@ -178,6 +180,17 @@ class GestureCalibrationUI {
                 this.applyCameraOptimization(e.target.value);
             });
         }
+        
+        // Buttons
+        const modal = document.getElementById('calibration-modal');
+        modal.querySelectorAll('[data-preset]').forEach(btn => {
+            btn.addEventListener('click', () => this.applyPreset(btn.dataset.preset));
+        });
+        modal.querySelectorAll('[data-dismiss]').forEach(btn => {
+            btn.addEventListener('click', () => modal.remove());
+        });
+        document.getElementById('calibration-save-btn').addEventListener('click', () => this.saveCalibration());
+        document.getElementById('calibration-test-btn').addEventListener('click', () => this.testCalibration());
     }
 
     applyPreset(presetName) {
@@ -304,18 +317,11 @@ class GestureCalibrationUI {
             }
             
             // Send to backend
-            const API_BASE_URL = import.meta.env.VITE_API_URL || 'http://localhost:8000';
-            const response = await fetch(`${API_BASE_URL}/api/gesture/calibrate`, {
-                method: 'POST',
-                headers: { 'Content-Type': 'application/json' },
-                body: JSON.stringify({
-                    smoothing: this.calibrationData.smoothing,
-                    speed: this.calibrationData.speed
-                })
+            const result = await backendCall('calibrate_gestures', {
+                smoothing: this.calibrationData.smoothing,
+                speed: this.calibrationData.speed
             });
             
-            const result = await response.json();
-            
             if (result.success) {
                 if (window.notificationSystem) {
                     window.notificationSystem.show(
@@ -98,7 +98,7 @@ class GestureOverlayUI {
             </div>
             
             <!-- Gesture Help Toggle (Bottom Right) -->
-            <button id="gesture-help-toggle" onclick="window.gestureOverlay.toggleHelp()"
+            <button id="gesture-help-toggle"
                     style="position: absolute; bottom: 20px; right: 20px;
                            background: rgba(0, 217, 255, 0.2);
                            border: 1px solid rgba(0, 217, 255, 0.4);
@@ -113,6 +113,7 @@ class GestureOverlayUI {
         
         document.body.appendChild(overlay);
         this.overlayElement = overlay;
+        overlay.querySelector('#gesture-help-toggle').addEventListener('click', () => this.toggleHelp());
         
         // Add gesture indicator styles
         this.injectStyles();
@@ -253,7 +254,7 @@ class GestureOverlayUI {
             <div class="modal-content" style="max-width: 800px;">
                 <div class="modal-header">
                     <h2>🖐️ Gesture Control Guide</h2>
-                    <button class="modal-close" onclick="this.closest('.modal-overlay').remove()">×</button>
+                    <button class="modal-close" data-dismiss>×</button>
                 </div>
                 <div class="modal-body">
                     <div class="settings-section">
@@ -349,10 +350,10 @@ class GestureOverlayUI {
                     </div>
                 </div>
                 <div class="modal-footer">
-                    <button class="friday-btn" onclick="window.gestureCalibration.showCalibrationModal(); this.closest('.modal-overlay').remove();">
+                    <button class="friday-btn" id="gesture-help-calibrate-btn">
                         ⚙️ Open Calibration
                     </button>
-                    <button class="friday-btn-secondary" onclick="this.closest('.modal-overlay').remove();">
+                    <button class="friday-btn-secondary" data-dismiss>
                         Close
                     </button>
                 </div>
@@ -360,6 +361,14 @@ class GestureOverlayUI {
         `;
         
         document.body.appendChild(helpModal);
+        
+        helpModal.querySelectorAll('[data-dismiss]').forEach(btn => {
+            btn.addEventListener('click', () => helpModal.remove());
+        });
+        helpModal.querySelector('#gesture-help-calibrate-btn').addEventListener('click', () => {
+            window.gestureCalibration.showCalibrationModal();
+            helpModal.remove();
+        });
     }
 }
 
@@ -0,0 +1,116 @@
+// ============================================
+// FRIDAY Gesture Overlay (per-monitor window)
+// Draws the hand cursor over other apps. Positions arrive from Rust in this
+// monitor's physical pixels; the window itself ignores all mouse input.
+// ============================================
+
+import { appWindow } from '@tauri-apps/api/window';
+
+const COLORS = {
+    move: '#00d9ff',
+    release: '#00d9ff',
+    pinch: '#00ff88',
+    grab: '#ffaa00',
+    swipe: '#00ffff'
+};
+const TRAIL_LENGTH = 12;
+const SWIPE_LABEL_MS = 700;
+const ARROWS = {
+    left: '←', right: '→', up: '↑', down: '↓',
+    'up-left': '↖', 'up-right': '↗', 'down-left': '↙', 'down-right': '↘'
+};
+
+const canvas = document.getElementById('overlay-canvas');
+const ctx = canvas.getContext('2d');
+
+let cursor = null;   // { x, y, gesture } in CSS pixels
+let held = null;     // 'pinch' | 'grab' while the hand stays closed
+let trail = [];
+let swipe = null;    // { label, until }
+
+function resize() {
+    const ratio = window.devicePixelRatio || 1;
+    canvas.width = window.innerWidth * ratio;
+    canvas.height = window.innerHeight * ratio;
+    canvas.style.width = `${window.innerWidth}px`;
+    canvas.style.height = `${window.innerHeight}px`;
+    ctx.setTransform(ratio, 0, 0, ratio, 0, 0);
+}
+
+function onGesture({ payload }) {
+    const ratio = window.devicePixelRatio || 1;
+    const x = payload.x / ratio;
+    const y = payload.y / ratio;
+
+    switch (payload.gesture) {
+        case 'pinch':
+        case 'grab':
+            held = payload.gesture;
+            break;
+        case 'release':
+            held = null;
+            break;
+        case 'swipe':
+            swipe = { label: ARROWS[payload.detail] || payload.detail || '', until: performance.now() + SWIPE_LABEL_MS };
+            break;
+    }
+
+    cursor = { x, y };
+    trail.push({ x, y });
+    if (trail.length > TRAIL_LENGTH) trail.shift();
+}
+
+function onLeave() {
+    cursor = null;
+    held = null;
+    trail = [];
+}
+
+function draw() {
+    ctx.clearRect(0, 0, window.innerWidth, window.innerHeight);
+
+    if (cursor) {
+        const color = COLORS[held || 'move'];
+
+        // Fading trail
+        trail.forEach((point, i) => {
+            ctx.beginPath();
+            ctx.globalAlpha = (i + 1) / trail.length * 0.35;
+            ctx.fillStyle = color;
+            ctx.arc(point.x, point.y, 4, 0, Math.PI * 2);
+            ctx.fill();
+        });
+        ctx.globalAlpha = 1;
+
+        // Cursor ring, tighter while pinching
+        const radius = held === 'pinch' ? 14 : 20;
+        ctx.beginPath();
+        ctx.lineWidth = 3;
+        ctx.strokeStyle = color;
+        ctx.shadowColor = color;
+        ctx.shadowBlur = 16;
+        ctx.arc(cursor.x, cursor.y, radius, 0, Math.PI * 2);
+        ctx.stroke();
+
+        ctx.beginPath();
+        ctx.fillStyle = color;
+        ctx.arc(cursor.x, cursor.y, 3, 0, Math.PI * 2);
+        ctx.fill();
+        ctx.shadowBlur = 0;
+
+        if (swipe && performance.now() < swipe.until) {
+            ctx.font = '600 32px "Segoe UI", sans-serif';
+            ctx.fillStyle = COLORS.swipe;
+            ctx.textAlign = 'center';
+            ctx.fillText(swipe.label, cursor.x, cursor.y - 36);
+        }
+    }
+
+    requestAnimationFrame(draw);
+}
+
+window.addEventListener('resize', resize);
+resize();
+appWindow.listen('overlay://gesture', onGesture);
+appWindow.listen('overlay://leave', onLeave);
+requestAnimationFrame(draw);
@@ -3,6 +3,8 @@
 // Makes gesture tracking blazing fast and efficient
 // ============================================
 
+import { backendCall } from './backend_api.js';
+
 class GesturePerformanceOptimizer {
     constructor() {
         // Performance monitoring
@@ -80,17 +82,9 @@ class GesturePerformanceOptimizer {
         
         // Send only the latest position
         try {
-            const API_BASE_URL = import.meta.env.VITE_API_URL || 'http://localhost:8000';
-            await fetch(`${API_BASE_URL}/api/gesture/cursor`, {
-                method: 'POST',
-                headers: { 'Content-Type': 'application/json' },
-                body: JSON.stringify({
-                    x: latestUpdate.x,
-                    y: latestUpdate.y,
-                    smooth: true
-                }),
-                // Use keepalive for better performance
-                keepalive: true
+            await backendCall('move_cursor', {
+                x: latestUpdate.x,
+                y: latestUpdate.y
             });
         } catch (error) {
             // Silent fail for performance
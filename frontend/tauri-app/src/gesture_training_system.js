@@ -24,7 +24,7 @@ class GestureTrainingSystem {
             <div class="modal-content" style="max-width: 700px;">
                 <div class="modal-header">
                     <h2>🎓 Gesture Training</h2>
-                    <button class="modal-close" onclick="this.closest('.modal-overlay').remove()">×</button>
+                    <button class="modal-close" data-dismiss>×</button>
                 </div>
                 <div class="modal-body">
                     <div class="settings-section">
@@ -72,7 +72,7 @@ class GestureTrainingSystem {
                             </label>
                         </div>
                         
-                        <button id="start-recording-btn" class="friday-btn" onclick="window.gestureTraining.startRecording()" style="width: 100%; margin-top: 1rem;">
+                        <button id="start-recording-btn" class="friday-btn" style="width: 100%; margin-top: 1rem;">
                             🔴 Start Recording Gesture
                         </button>
                         
@@ -102,7 +102,7 @@ class GestureTrainingSystem {
                     </div>
                 </div>
                 <div class="modal-footer">
-                    <button class="friday-btn-secondary" onclick="this.closest('.modal-overlay').remove();">
+                    <button class="friday-btn-secondary" data-dismiss>
                         Close
                     </button>
                 </div>
@@ -111,6 +111,11 @@ class GestureTrainingSystem {
         
         document.body.appendChild(modal);
         
+        modal.querySelectorAll('[data-dismiss]').forEach(btn => {
+            btn.addEventListener('click', () => modal.remove());
+        });
+        document.getElementById('start-recording-btn').onclick = () => this.startRecording();
+        
         // Setup event listeners
         this.setupTrainingListeners();
         
@@ -281,16 +286,14 @@ class GestureTrainingSystem {
             return;
         }
         
-        list.innerHTML = gestures.map(gesture => `
-            <div style="padding: 0.75rem; background: rgba(0, 217, 255, 0.1); border-radius: 8px; margin-bottom: 0.5rem;
+        list.innerHTML = gestures.map(() => `
+            <div class="custom-gesture-item" style="padding: 0.75rem; background: rgba(0, 217, 255, 0.1); border-radius: 8px; margin-bottom: 0.5rem;
                         display: flex; justify-content: space-between; align-items: center;">
                 <div>
-                    <strong>${gesture.name}</strong>
-                    <div style="font-size: 0.85rem; color: var(--jarvis-text-dim); margin-top: 0.25rem;">
-                        Action: ${gesture.action} | Samples: ${gesture.samples.length}
-                    </div>
+                    <strong class="custom-gesture-name"></strong>
+                    <div class="custom-gesture-details" style="font-size: 0.85rem; color: var(--jarvis-text-dim); margin-top: 0.25rem;"></div>
                 </div>
-                <button onclick="window.gestureTraining.deleteGesture('${gesture.name}')"
+                <button class="custom-gesture-delete"
                         style="background: rgba(255, 51, 102, 0.2); border: 1px solid var(--jarvis-error);
                                color: var(--jarvis-error); padding: 0.5rem 1rem; border-radius: 6px;
                                cursor: pointer; font-weight: 600;">
@@ -298,6 +301,16 @@ class GestureTrainingSystem {
                 </button>
             </div>
         `).join('');
+        
+        // Names and commands are user input, so fill them in as text
+        list.querySelectorAll('.custom-gesture-item').forEach((item, i) => {
+            const gesture = gestures[i];
+            item.querySelector('.custom-gesture-name').textContent = gesture.name;
+            item.querySelector('.custom-gesture-details').textContent =
+                `Action: ${gesture.action} | Samples: ${gesture.samples.length}`;
+            item.querySelector('.custom-gesture-delete')
+                .addEventListener('click', () => this.deleteGesture(gesture.name));
+        });
     }
 
     deleteGesture(gestureName) {
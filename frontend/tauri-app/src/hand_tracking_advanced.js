@@ -3,7 +3,7 @@
 // ============================================
 // Full device control with hand gestures
 
-const API_BASE_URL = import.meta.env.VITE_API_URL || 'http://localhost:8000';
+import { backendCall } from './backend_api.js';
 
 class AdvancedHandTrackingController {
     constructor() {
@@ -466,20 +466,10 @@ class AdvancedHandTrackingController {
             console.log('⚙️ Executing gesture command:', command, params);
             
             // Execute via FRIDAY gesture control API
-            const response = await fetch(`${API_BASE_URL}/api/gesture/execute`, {
-                method: 'POST',
-                headers: { 'Content-Type': 'application/json' },
-                body: JSON.stringify({
-                    gesture: command,
-                    params: params
-                })
+            const result = await backendCall('gesture', {
+                gesture: command,
+                params: params
             });
-            
-            if (!response.ok) {
-                throw new Error(`HTTP error! status: ${response.status}`);
-            }
-            
-            const result = await response.json();
             console.log('✅ Gesture command result:', result);
             
             // Show feedback
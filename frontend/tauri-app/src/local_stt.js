@@ -1,3 +1,5 @@
+import { backendCall, toBase64 } from './backend_api.js';
+
 class LocalSTTClient {
     constructor() {
         this.mediaRecorder = null;
@@ -18,16 +20,10 @@ class LocalSTTClient {
             if (!this.isActive) return;
             if (e.data && e.data.size > 0) {
                 try {
-                    const blob = e.data;
-                    const form = new FormData();
-                    form.append('file', blob, 'audio.webm');
                     // Non-streaming small-chunk transcription
-                    const API_BASE_URL = import.meta.env.VITE_API_URL || 'http://localhost:8000';
-                    const resp = await fetch(`${API_BASE_URL}/api/stt/transcribe`, {
-                        method: 'POST',
-                        body: form
+                    const data = await backendCall('transcribe', {
+                        data: await toBase64(e.data)
                     });
-                    const data = await resp.json();
                     if (data?.success && data.text && this.onResult) {
                         this.onResult(data.text, false);
                     }
@@ -3,9 +3,14 @@
  * Handles all frontend interactions and API communication
  */
 
-// Backend API Configuration
-// Use environment variable if available, otherwise fallback to localhost
-const API_BASE_URL = import.meta.env.VITE_API_URL || 'http://localhost:8000';
+import { invoke } from '@tauri-apps/api/tauri';
+import { listen } from '@tauri-apps/api/event';
+import { backendCall, toBase64 } from './backend_api.js';
+import { renderMarkdown } from './markdown.js';
+
+// Backend requests go through Rust (backend_api.js); chat replies stream in
+// as chat://* events
+const CHAT_EVENTS = ['token', 'tool_call', 'tool_result', 'followups', 'error', 'done'];
 
 // State Management
 // ============================================
@@ -71,18 +76,6 @@ document.addEventListener('DOMContentLoaded', async () => {
     checkBackendStatus();
     startSystemMonitoring();
     
-    // Configure marked for markdown rendering
-    marked.setOptions({
-        highlight: function(code, lang) {
-            if (lang && hljs.getLanguage(lang)) {
-                return hljs.highlight(code, { language: lang }).value;
-            }
-            return hljs.highlightAuto(code).value;
-        },
-        breaks: true,
-        gfm: true
-    });
-    
     console.log('✅ JARVIS online');
 });
 
@@ -138,6 +131,29 @@ function initializeEventListeners() {
         });
     });
     
+    // Welcome suggestions are re-rendered by startNewChat, so listen on the chat area
+    document.getElementById('chat-messages').addEventListener('click', (e) => {
+        const btn = e.target.closest('.suggestion-btn');
+        if (!btn) return;
+        if (btn.dataset.suggestion) {
+            useSuggestion(btn.dataset.suggestion);
+        } else if (btn.dataset.action) {
+            handleQuickAction(btn.dataset.action);
+        }
+    });
+    
+    // Sidebar footer
+    document.getElementById('screenshot-btn')?.addEventListener('click', captureScreen);
+    document.getElementById('clipboard-monitor-btn')?.addEventListener('click', toggleClipboardMonitor);
+    
+    // Gesture tools load as separate modules
+    document.getElementById('gesture-calibrate-btn')?.addEventListener('click', () => {
+        window.gestureCalibration?.showCalibrationModal();
+    });
+    document.getElementById('gesture-train-btn')?.addEventListener('click', () => {
+        window.gestureTraining?.showTrainingUI();
+    });
+    
     // Settings button
     const settingsBtn = document.querySelector('.settings-btn');
     if (settingsBtn) {
@@ -414,7 +430,8 @@ function initializeKeyboardShortcuts() {
         
         // Escape: Close modals
         if (e.key === 'Escape') {
-            const modal = document.querySelector('.modal-overlay');
+            // The unlock prompt cannot be dismissed
+            const modal = document.querySelector('.modal-overlay:not(#unlock-modal)');
             if (modal) {
                 modal.remove();
             }
@@ -506,14 +523,8 @@ function addMessage(role, content, isStreaming = false) {
         textDiv.textContent = '';
         textDiv.dataset.messageId = Date.now();
     } else {
-        // Render markdown
-        textDiv.innerHTML = marked.parse(content);
-        
-        // Highlight code blocks and add copy buttons
-        textDiv.querySelectorAll('pre code').forEach((block) => {
-            hljs.highlightElement(block);
-            addCopyButton(block.parentElement);
-        });
+        // Render sanitized markdown, then add copy buttons to code blocks
+        showMarkdown(textDiv, content);
     }
     
     contentDiv.appendChild(textDiv);
@@ -527,8 +538,22 @@ function addMessage(role, content, isStreaming = false) {
     return textDiv;
 }
 
+function showMarkdown(element, text) {
+    return renderMarkdown(element, text)
+        .then(rendered => {
+            if (rendered) {
+                element.querySelectorAll('pre code').forEach(block => addCopyButton(block.parentElement));
+            }
+        })
+        .catch(error => {
+            console.error('Markdown rendering failed:', error);
+            element.textContent = text;
+        });
+}
+
 async function streamChatResponse(message) {
     state.isStreaming = true;
+    publishMiniState({ listening: 'thinking' });
     
     // ============================================
     // RESET STREAMING TTS FOR NEW RESPONSE
@@ -627,27 +652,102 @@ async function streamChatResponse(message) {
         console.log('━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━');
         
         // V2: Send FULL conversation history
-        const endpoint = state.provider === 'local'
-            ? `${API_BASE_URL}/api/llm/local_stream`
-            : `${API_BASE_URL}/api/chat/v2/stream`;
-        const payload = (state.provider === 'local')
+        const endpoint = state.provider === 'local' ? 'local' : 'v2';
+        const body = (state.provider === 'local')
             ? { messages: state.conversationMessages, temperature: state.temperature }
             : { messages: state.conversationMessages, conversation_id: state.currentConversationId, language: state.language, temperature: state.temperature };
 
-        const response = await fetch(endpoint, {
-            method: 'POST',
-            headers: {
-                'Content-Type': 'application/json'
-            },
-            body: JSON.stringify(payload)
-        });
-        
-        if (!response.ok) {
-            throw new Error(`HTTP error! status: ${response.status}`);
+        const handleChunk = (data) => {
+            if (data.type === 'token') {
+                fullResponse += data.content;
+                showMarkdown(responseElement, fullResponse).then(() => {
+                    // Scroll to bottom
+                    const chatMessages = document.getElementById('chat-messages');
+                    chatMessages.scrollTop = chatMessages.scrollHeight;
+                });
+                
+                // ============================================
+                // STREAMING TTS - Play audio as text streams
+                // ============================================
+                if (voiceOutputEnabled && window.streamingTTS && data.content) {
+                    // Add chunk to TTS queue (parallel processing)
+                    window.streamingTTS.addChunk(data.content);
+                }
+            }
+            else if (data.type === 'tool_call') {
+                console.log('Tool called:', data.data);
+                addToolCallIndicator(data.data);
+            }
+            else if (data.type === 'tool_result') {
+                console.log('Tool result:', data.data);
+                // Display tool result indicator (Perplexity-style)
+                if (data.data && data.data.reason) {
+                    const toolIndicator = document.createElement('div');
+                    toolIndicator.className = 'tool-result-badge';
+                    const badge = document.createElement('div');
+                    badge.style.cssText = 'display: inline-flex; align-items: center; gap: 0.3rem; padding: 0.3rem 0.6rem; background: rgba(0, 217, 255, 0.15); border: 1px solid rgba(0, 217, 255, 0.3); border-radius: 12px; font-size: 0.75rem; margin: 0.3rem 0;';
+                    badge.textContent = `🔍 ${data.data.reason}`;
+                    toolIndicator.appendChild(badge);
+                    const chatMessages = document.getElementById('chat-messages');
+                    chatMessages.appendChild(toolIndicator);
+                }
+            }
+            else if (data.type === 'followups') {
+                console.log('💡 Follow-up suggestions:', data.data);
+                if (data.data && data.data.suggestions) {
+                    displayFollowupSuggestions(data.data.suggestions);
+                }
+            }
+            else if (data.type === 'error') {
+                console.error('Stream error:', data.content);
+                fullResponse += `\n\n❌ Error: ${data.content}`;
+                showMarkdown(responseElement, fullResponse);
+            }
+            else if (data.type === 'done') {
+                console.log('Stream complete. Full response length:', fullResponse.length);
+                
+                // V2: Add assistant response to conversation history
+                if (fullResponse) {
+                    state.conversationMessages.push({
+                        role: 'assistant',
+                        content: fullResponse
+                    });
+                    
+                    // Save to localStorage
+                    saveConversationState();
+                    
+                    console.log('✅ V2: Response added to history');
+                    console.log('   Total messages:', state.conversationMessages.length);
+                }
+                
+                // ============================================
+                // FINALIZE STREAMING TTS
+                // ============================================
+                if (voiceOutputEnabled && window.streamingTTS) {
+                    console.log('✅ Finalizing streaming TTS');
+                    window.streamingTTS.finalize();
+                }
+            }
+        };
+
+        // Only chunks tagged with this request's id belong to this reply
+        const requestId = crypto.randomUUID();
+        const unlisteners = await Promise.all(CHAT_EVENTS.map(kind =>
+            listen(`chat://${kind}`, ({ payload }) => {
+                if (payload.request_id === requestId) {
+                    handleChunk(payload);
+                }
+            })
+        ));
+        let outcome;
+        try {
+            outcome = await invoke('chat_stream', { requestId, endpoint, body });
+        } finally {
+            unlisteners.forEach(unlisten => unlisten());
         }
         
-        // Extract conversation_id from headers and MAINTAIN it
-        const conversationId = response.headers.get('X-Conversation-ID');
+        // The conversation the backend saved this exchange to - MAINTAIN it
+        const conversationId = outcome.conversation_id;
         if (conversationId) {
             conversationIdFromResponse = conversationId;  // Store for later use
             
@@ -681,103 +781,6 @@ async function streamChatResponse(message) {
             console.log('━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━');
         }
         
-        const reader = response.body.getReader();
-        const decoder = new TextDecoder();
-        
-        while (true) {
-            const { value, done } = await reader.read();
-            if (done) break;
-            
-            const chunk = decoder.decode(value);
-            const lines = chunk.split('\n');
-            
-            for (const line of lines) {
-                if (line.startsWith('data: ')) {
-                    const data = JSON.parse(line.slice(6));
-                    
-                    if (data.type === 'token') {
-                        fullResponse += data.content;
-                        responseElement.innerHTML = marked.parse(fullResponse);
-                        
-                        // Highlight code blocks and add copy buttons
-                        responseElement.querySelectorAll('pre code').forEach((block) => {
-                            hljs.highlightElement(block);
-                            addCopyButton(block.parentElement);
-                        });
-                        
-                        // ============================================
-                        // STREAMING TTS - Play audio as text streams
-                        // ============================================
-                        if (voiceOutputEnabled && window.streamingTTS && data.content) {
-                            // Add chunk to TTS queue (parallel processing)
-                            window.streamingTTS.addChunk(data.content);
-                        }
-                        
-                        // Scroll to bottom
-                        const chatMessages = document.getElementById('chat-messages');
-                        chatMessages.scrollTop = chatMessages.scrollHeight;
-                    }
-                    else if (data.type === 'tool_call') {
-                        console.log('Tool called:', data.data);
-                        addToolCallIndicator(data.data);
-                    }
-                    else if (data.type === 'tool_result') {
-                        console.log('Tool result:', data.data);
-                        // Display tool result indicator (Perplexity-style)
-                        if (data.data && data.data.reason) {
-                            const toolIndicator = document.createElement('div');
-                            toolIndicator.className = 'tool-result-badge';
-                            toolIndicator.innerHTML = `
-                                <div style="display: inline-flex; align-items: center; gap: 0.3rem; padding: 0.3rem 0.6rem; background: rgba(0, 217, 255, 0.15); border: 1px solid rgba(0, 217, 255, 0.3); border-radius: 12px; font-size: 0.75rem; margin: 0.3rem 0;">
-                                    🔍 ${data.data.reason}
-                                </div>
-                            `;
-                            const chatMessages = document.getElementById('chat-messages');
-                            chatMessages.appendChild(toolIndicator);
-                        }
-                    }
-                    else if (data.type === 'followups') {
-                        console.log('💡 Follow-up suggestions:', data.data);
-                        if (data.data && data.data.suggestions) {
-                            displayFollowupSuggestions(data.data.suggestions);
-                        }
-                    }
-                    else if (data.type === 'error') {
-                        console.error('Stream error:', data.content);
-                        fullResponse += `\n\n❌ Error: ${data.content}`;
-                        responseElement.innerHTML = marked.parse(fullResponse);
-                    }
-                    else if (data.type === 'done') {
-                        console.log('Stream complete. Full response length:', fullResponse.length);
-                        
-                        // V2: Add assistant response to conversation history
-                        if (fullResponse) {
-                            state.conversationMessages.push({
-                                role: 'assistant',
-                                content: fullResponse
-                            });
-                            
-                            // Save to localStorage
-                            saveConversationState();
-                            
-                            console.log('✅ V2: Response added to history');
-                            console.log('   Total messages:', state.conversationMessages.length);
-                        }
-                        
-                        // ============================================
-                        // FINALIZE STREAMING TTS
-                        // ============================================
-                        if (voiceOutputEnabled && window.streamingTTS) {
-                            console.log('✅ Finalizing streaming TTS');
-                            window.streamingTTS.finalize();
-                        }
-                        
-                        break;
-                    }
-                }
-            }
-        }
-        
         // CRITICAL: Conversation sidebar management
         // RULE: Only update sidebar ONCE when conversation is FIRST created
         console.log('━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━');
@@ -804,21 +807,16 @@ async function streamChatResponse(message) {
                 // Auto-generate name WITHOUT reloading conversations again
                 console.log('   📝 Auto-generating conversation name...');
                 try {
-                    const nameResponse = await fetch(`${API_BASE_URL}/api/chat/conversations/${state.currentConversationId}/generate-name`, {
-                        method: 'POST'
-                    });
+                    const nameData = await backendCall('generate_conversation_name', { id: state.currentConversationId });
+                    await invoke('rename_conversation', { id: state.currentConversationId, name: nameData.name });
+                    console.log('   ✅ Name generated:', nameData.name);
                     
-                    if (nameResponse.ok) {
-                        const nameData = await nameResponse.json();
-                        console.log('   ✅ Name generated:', nameData.name);
-                        
-                        // Update local state WITHOUT reloading
-                        const conv = state.conversations.find(c => c.id === state.currentConversationId);
-                        if (conv) {
-                            conv.name = nameData.name;
-                            // Re-render sidebar with updated name (lightweight)
-                            renderConversations();
-                        }
+                    // Update local state WITHOUT reloading
+                    const conv = state.conversations.find(c => c.id === state.currentConversationId);
+                    if (conv) {
+                        conv.name = nameData.name;
+                        // Re-render sidebar with updated name (lightweight)
+                        renderConversations();
                     }
                 } catch (error) {
                     console.error('   ⚠️ Name generation failed:', error);
@@ -832,9 +830,17 @@ async function streamChatResponse(message) {
         
     } catch (error) {
         console.error('Error streaming response:', error);
-        responseElement.innerHTML = `<span style="color: var(--jarvis-error);">Error: ${error.message}</span>`;
+        const errorText = document.createElement('span');
+        errorText.style.color = 'var(--jarvis-error)';
+        errorText.textContent = `Error: ${error.message || error}`;
+        responseElement.replaceChildren(errorText);
     } finally {
         state.isStreaming = false;
+        publishMiniState({
+            listening: wakeWordEnabled ? 'wake_word' : 'idle',
+            last_response: fullResponse || null,
+            conversation_id: state.currentConversationId || null
+        });
     }
 }
 
@@ -845,9 +851,10 @@ function addToolCallIndicator(toolData) {
     indicator.className = 'tool-indicator';
     indicator.innerHTML = `
         <div style="padding: 0.5rem; background: rgba(0, 217, 255, 0.1); border-left: 3px solid var(--jarvis-primary); margin: 0.5rem 0; border-radius: 3px;">
-            🔧 Executing: <strong>${toolData.name}</strong>
+            🔧 Executing: <strong></strong>
         </div>
     `;
+    indicator.querySelector('strong').textContent = toolData.name;
     
     chatMessages.appendChild(indicator);
     chatMessages.scrollTop = chatMessages.scrollHeight;
@@ -874,21 +881,25 @@ function displayFollowupSuggestions(suggestions) {
             <div style="font-size: 0.85rem; color: var(--jarvis-text-dim); margin-bottom: 0.5rem; font-weight: 500;">
                 💡 Continue the conversation:
             </div>
-            <div class="followup-buttons">
-                ${suggestions.map(suggestion => `
-                    <button class="followup-btn" onclick="handleFollowupClick('${suggestion.replace(/'/g, "\\'")}')">
-                        ${suggestion}
-                    </button>
-                `).join('')}
-            </div>
+            <div class="followup-buttons"></div>
         </div>
     `;
     
+    // Suggestions come from the model: text only, never markup
+    const buttons = container.querySelector('.followup-buttons');
+    suggestions.forEach(suggestion => {
+        const button = document.createElement('button');
+        button.className = 'followup-btn';
+        button.textContent = suggestion;
+        button.addEventListener('click', () => handleFollowupClick(suggestion));
+        buttons.appendChild(button);
+    });
+    
     chatMessages.appendChild(container);
     chatMessages.scrollTop = chatMessages.scrollHeight;
 }
 
-window.handleFollowupClick = function(suggestion) {
+function handleFollowupClick(suggestion) {
     /**
      * Handle click on follow-up suggestion
      */
@@ -905,7 +916,7 @@ window.handleFollowupClick = function(suggestion) {
     setTimeout(() => {
         handleSendMessage();
     }, 300);
-};
+}
 
 // ============================================
 // Quick Actions
@@ -944,8 +955,7 @@ function handleQuickAction(action) {
 
 async function loadConversations() {
     try {
-        const response = await fetch(`${API_BASE_URL}/api/chat/conversations`);
-        const conversations = await response.json();
+        const conversations = await invoke('list_conversations');
         
         state.conversations = conversations;
         renderConversations();
@@ -1006,8 +1016,7 @@ function renderConversations() {
 
 async function loadProjects() {
     try {
-        const response = await fetch(`${API_BASE_URL}/api/projects/`);
-        const projects = await response.json();
+        const projects = await invoke('list_projects');
         
         state.projects = projects;
         renderProjects();
@@ -1075,19 +1084,19 @@ function startNewChat() {
         <p class="developer-credit">Developed by Dipesh</p>
             
             <div class="suggestions-container">
-                <button class="suggestion-btn" onclick="useSuggestion('What can you help me with?')">
+                <button class="suggestion-btn" data-suggestion="What can you help me with?">
                     <span class="suggestion-icon">❓</span>
                     <span>What can you help me with?</span>
                 </button>
-                <button class="suggestion-btn" onclick="captureScreen()">
+                <button class="suggestion-btn" data-action="screenshot">
                     <span class="suggestion-icon">📸</span>
                     <span>Take a screenshot and analyze</span>
                 </button>
-                <button class="suggestion-btn" onclick="useSuggestion('What\\'s my system status?')">
+                <button class="suggestion-btn" data-suggestion="What's my system status?">
                     <span class="suggestion-icon">💻</span>
                     <span>What's my system status?</span>
                 </button>
-                <button class="suggestion-btn" onclick="useSuggestion('Search the web for latest AI news')">
+                <button class="suggestion-btn" data-suggestion="Search the web for latest AI news">
                     <span class="suggestion-icon">🔍</span>
                     <span>Search the web</span>
                 </button>
@@ -1109,8 +1118,10 @@ async function loadConversation(conversationId) {
             synthesis.cancel();
         }
         
-        const response = await fetch(`${API_BASE_URL}/api/chat/conversations/${conversationId}`);
-        const conversation = await response.json();
+        const conversation = await invoke('get_conversation', { id: conversationId });
+        if (!conversation) {
+            throw new Error(`Conversation ${conversationId} not found`);
+        }
         
         // V2: Set as active and load messages into state
         state.currentConversationId = conversationId;
@@ -1156,13 +1167,7 @@ async function deleteConversation(conversationId) {
     }
     
     try {
-        const response = await fetch(`${API_BASE_URL}/api/chat/conversations/${conversationId}`, {
-            method: 'DELETE'
-        });
-        
-        if (!response.ok) {
-            throw new Error('Failed to delete conversation');
-        }
+        await invoke('delete_conversation', { id: conversationId });
         
         // If we're viewing the deleted conversation, start a new chat
         if (state.currentConversationId === conversationId) {
@@ -1194,15 +1199,7 @@ async function deleteAllChats() {
     }
     
     try {
-        const response = await fetch(`${API_BASE_URL}/api/chat/conversations`, {
-            method: 'DELETE'
-        });
-        
-        if (!response.ok) {
-            throw new Error('Failed to delete all conversations');
-        }
-        
-        const result = await response.json();
+        const deleted = await invoke('delete_all_conversations');
         
         // V2: Start fresh
         console.log('🗑️ V2: All conversations deleted - fresh start');
@@ -1220,8 +1217,8 @@ async function deleteAllChats() {
         // Reload conversations list (should be empty)
         await loadConversations();
         
-        showNotification(`✅ Deleted ${result.count} conversations`, 'success');
-        console.log('Deleted all conversations:', result);
+        showNotification(`✅ Deleted ${deleted} conversations`, 'success');
+        console.log('Deleted all conversations:', deleted);
     } catch (error) {
         console.error('Error deleting all conversations:', error);
         showNotification('Failed to delete all conversations', 'error');
@@ -1236,13 +1233,7 @@ async function renameConversation(conversationId, currentName) {
     }
     
     try {
-        const response = await fetch(`${API_BASE_URL}/api/chat/conversations/${conversationId}/name?name=${encodeURIComponent(newName.trim())}`, {
-            method: 'PUT'
-        });
-        
-        if (!response.ok) {
-            throw new Error('Failed to rename conversation');
-        }
+        await invoke('rename_conversation', { id: conversationId, name: newName.trim() });
         
         // Reload conversations list
         await loadConversations();
@@ -1257,15 +1248,8 @@ async function renameConversation(conversationId, currentName) {
 
 async function autoGenerateConversationName(conversationId) {
     try {
-        const response = await fetch(`${API_BASE_URL}/api/chat/conversations/${conversationId}/generate-name`, {
-            method: 'POST'
-        });
-        
-        if (!response.ok) {
-            return;
-        }
-        
-        const data = await response.json();
+        const data = await backendCall('generate_conversation_name', { id: conversationId });
+        await invoke('rename_conversation', { id: conversationId, name: data.name });
         console.log('✅ Auto-generated smart name from content:', data.name);
         
         // Update local state immediately
@@ -1283,8 +1267,10 @@ async function autoGenerateConversationName(conversationId) {
 
 async function loadProject(projectId) {
     try {
-        const response = await fetch(`${API_BASE_URL}/api/projects/${projectId}`);
-        const project = await response.json();
+        const project = await invoke('get_project', { id: projectId });
+        if (!project) {
+            throw new Error(`Project ${projectId} not found`);
+        }
         
         state.currentProjectId = projectId;
         console.log('Loaded project:', project.name);
@@ -1302,8 +1288,7 @@ async function loadProject(projectId) {
 
 async function updateSystemStats() {
     try {
-        const response = await fetch(`${API_BASE_URL}/api/system/info`);
-        const info = await response.json();
+        const info = await backendCall('system_info');
         
         // Update CPU
         const cpuPercent = info.cpu_percent || info.data?.cpu?.usage_percent || 0;
@@ -1344,8 +1329,7 @@ function startSystemMonitoring() {
 
 async function checkBackendStatus() {
     try {
-        const response = await fetch(`${API_BASE_URL}/health`);
-        const data = await response.json();
+        const data = await backendCall('health');
         
         console.log('Backend status:', data.status);
         
@@ -1390,10 +1374,13 @@ function showNotification(message, type = 'info') {
         'warning': '⚠'
     }[type] || 'ℹ';
     
-    toast.innerHTML = `
-        <span class="toast-icon">${icon}</span>
-        <span class="toast-message">${message}</span>
-    `;
+    const iconSpan = document.createElement('span');
+    iconSpan.className = 'toast-icon';
+    iconSpan.textContent = icon;
+    const messageSpan = document.createElement('span');
+    messageSpan.className = 'toast-message';
+    messageSpan.textContent = message;
+    toast.append(iconSpan, messageSpan);
     
     document.body.appendChild(toast);
     
@@ -1492,7 +1479,7 @@ function openSettings() {
             <div class="modal-content">
                 <div class="modal-header">
                     <h2>⚙️ Settings</h2>
-                    <button class="modal-close" onclick="closeSettings()">×</button>
+                    <button class="modal-close" id="settings-close-btn">×</button>
                 </div>
                 <div class="modal-body">
                     <div class="settings-section">
@@ -1536,7 +1523,7 @@ function openSettings() {
                             </div>
                         </div>
                         
-                        <button class="friday-btn-secondary" onclick="window.testVoice()" style="width: 100%; margin-top: 1rem;">
+                        <button class="friday-btn-secondary" id="settings-test-voice-btn" style="width: 100%; margin-top: 1rem;">
                             🔊 Test Voice
                         </button>
                     </div>
@@ -1552,6 +1539,16 @@ function openSettings() {
                         </p>
                     </div>
                     
+                    <div class="settings-section">
+                        <h3>Data Encryption</h3>
+                        <p style="font-size: 0.85rem; color: var(--jarvis-text-dim);">
+                            Conversations and provider keys are encrypted on disk with a key kept in the OS credential store or behind a passphrase
+                        </p>
+                        <button class="friday-btn-secondary" id="settings-passphrase-btn" style="width: 100%; margin-top: 1rem;">
+                            🔑 Change Data Protection
+                        </button>
+                    </div>
+                    
                     <div class="settings-section">
                         <h3>Keyboard Shortcuts</h3>
                         <div style="color: var(--jarvis-text-dim); font-size: 0.9rem; line-height: 1.8;">
@@ -1574,8 +1571,8 @@ function openSettings() {
                     </div>
                 </div>
                 <div class="modal-footer">
-                    <button class="friday-btn" onclick="window.saveSettings()">Save Settings</button>
-                    <button class="friday-btn-secondary" onclick="window.closeSettings()">Cancel</button>
+                    <button class="friday-btn" id="settings-save-btn">Save Settings</button>
+                    <button class="friday-btn-secondary" id="settings-cancel-btn">Cancel</button>
                 </div>
             </div>
         </div>
@@ -1583,6 +1580,15 @@ function openSettings() {
     
     document.body.insertAdjacentHTML('beforeend', settingsHTML);
     
+    document.getElementById('settings-close-btn').addEventListener('click', closeSettings);
+    document.getElementById('settings-cancel-btn').addEventListener('click', closeSettings);
+    document.getElementById('settings-save-btn').addEventListener('click', saveSettings);
+    document.getElementById('settings-test-voice-btn').addEventListener('click', testVoice);
+    document.getElementById('settings-passphrase-btn').addEventListener('click', () => {
+        closeSettings();
+        openDataProtection();
+    });
+    
     // Set current model if exists
     const modelSelect = document.getElementById('model-select');
     if (state.model) {
@@ -1694,17 +1700,7 @@ function createNewProject() {
         return;
     }
     
-    fetch(`${API_BASE_URL}/api/projects/`, {
-        method: 'POST',
-        headers: {
-            'Content-Type': 'application/json'
-        },
-        body: JSON.stringify({
-            name: projectName.trim(),
-            description: ''
-        })
-    })
-    .then(response => response.json())
+    invoke('create_project', { name: projectName.trim(), description: '' })
     .then(project => {
         state.currentProjectId = project.id;
         loadProjects();
@@ -1731,57 +1727,10 @@ function handleAttachFile() {
         if (!file) return;
         
         try {
-            const fileExt = file.name.split('.').pop().toLowerCase();
-            const isDocument = ['pdf', 'docx', 'doc'].includes(fileExt);
-            const isImage = ['png', 'jpg', 'jpeg', 'gif', 'bmp', 'webp'].includes(fileExt);
-            const isText = ['txt', 'md', 'py', 'js', 'json', 'csv', 'html', 'css'].includes(fileExt);
-            
-            // Show uploading notification
-            showNotification(`Uploading ${file.name}...`, 'info');
-            
-            // Upload to backend
-            const formData = new FormData();
-            formData.append('file', file);
-            
-            const response = await fetch(`${API_BASE_URL}/api/files/upload`, {
-                method: 'POST',
-                body: formData
-            });
-            
-            if (!response.ok) {
-                throw new Error(`Upload failed: ${response.statusText}`);
+            const prompt = await uploadAttachment(file.name, await toBase64(file));
+            if (prompt) {
+                document.getElementById('chat-input').value = prompt;
             }
-            
-            const result = await response.json();
-            const chatInput = document.getElementById('chat-input');
-            
-            // Handle different file types
-            if (isImage) {
-                // For images, show inline
-                const imageData = result.processed_data?.data?.base64 || '';
-                const prompt = `I've uploaded an image: ${file.name}\n\n`;
-                chatInput.value = prompt;
-                
-                // Add image preview to chat
-                if (imageData) {
-                    addImagePreview(imageData, file.name);
-                }
-            } else if (isDocument) {
-                // For PDFs and Word docs, include extracted text
-                const content = result.processed_data?.data?.content || '';
-                const truncated = content.length > 2000 ? content.substring(0, 2000) + '...' : content;
-                const prompt = `I've uploaded a ${fileExt.toUpperCase()} document: ${file.name}\n\nExtracted content:\n\`\`\`\n${truncated}\n\`\`\`\n\nPlease analyze this document.`;
-                chatInput.value = prompt;
-            } else if (isText) {
-                // For text files, include full content
-                const content = result.processed_data?.data?.content || '';
-                const prompt = `I've uploaded a file: ${file.name}\n\nContent:\n\`\`\`\n${content}\n\`\`\`\n\nPlease analyze this file.`;
-                chatInput.value = prompt;
-            }
-            
-            showNotification(`File processed: ${file.name}`, 'success');
-            console.log('File upload result:', result);
-            
         } catch (error) {
             console.error('Error uploading file:', error);
             showNotification(`Failed to upload file: ${error.message}`, 'error');
@@ -1791,6 +1740,46 @@ function handleAttachFile() {
     input.click();
 }
 
+// Upload a file (base64 `data`) and return the chat prompt that carries it
+async function uploadAttachment(fileName, data) {
+    const fileExt = fileName.split('.').pop().toLowerCase();
+    const isDocument = ['pdf', 'docx', 'doc'].includes(fileExt);
+    const isImage = ['png', 'jpg', 'jpeg', 'gif', 'bmp', 'webp'].includes(fileExt);
+    const isText = ['txt', 'md', 'py', 'js', 'json', 'csv', 'html', 'css'].includes(fileExt);
+    
+    // Show uploading notification
+    showNotification(`Uploading ${fileName}...`, 'info');
+    
+    // Upload to backend
+    const result = await backendCall('upload_file', { file_name: fileName, data });
+    let prompt = '';
+    
+    // Handle different file types
+    if (isImage) {
+        // For images, show inline
+        const imageData = result.processed_data?.data?.base64 || '';
+        prompt = `I've uploaded an image: ${fileName}\n\n`;
+        
+        // Add image preview to chat
+        if (imageData) {
+            addImagePreview(imageData, fileName);
+        }
+    } else if (isDocument) {
+        // For PDFs and Word docs, include extracted text
+        const content = result.processed_data?.data?.content || '';
+        const truncated = content.length > 2000 ? content.substring(0, 2000) + '...' : content;
+        prompt = `I've uploaded a ${fileExt.toUpperCase()} document: ${fileName}\n\nExtracted content:\n\`\`\`\n${truncated}\n\`\`\`\n\nPlease analyze this document.`;
+    } else if (isText) {
+        // For text files, include full content
+        const content = result.processed_data?.data?.content || '';
+        prompt = `I've uploaded a file: ${fileName}\n\nContent:\n\`\`\`\n${content}\n\`\`\`\n\nPlease analyze this file.`;
+    }
+    
+    showNotification(`File processed: ${fileName}`, 'success');
+    console.log('File upload result:', result);
+    return prompt;
+}
+
 function addImagePreview(base64Data, filename) {
     const chatMessages = document.getElementById('chat-messages');
     
@@ -1799,8 +1788,8 @@ function addImagePreview(base64Data, filename) {
     previewDiv.innerHTML = `
         <div class="image-container" style="margin: 1rem 0; padding: 1rem; background: rgba(0, 217, 255, 0.05); border: 1px solid var(--jarvis-border); border-radius: 8px; position: relative;">
             <div style="display: flex; justify-content: space-between; align-items: center; margin-bottom: 0.5rem;">
-                <p style="color: var(--jarvis-primary); margin: 0; font-size: 0.9rem;">📷 ${filename}</p>
-                <button class="image-download-btn" onclick="downloadImage('${base64Data}', '${filename}')" title="Download image">
+                <p class="image-filename" style="color: var(--jarvis-primary); margin: 0; font-size: 0.9rem;"></p>
+                <button class="image-download-btn" title="Download image">
                     <svg width="16" height="16" viewBox="0 0 16 16" fill="currentColor">
                         <path d="M8 12l-4-4h3V4h2v4h3l-4 4z"/>
                         <path d="M14 14H2v-2h12v2z"/>
@@ -1808,10 +1797,18 @@ function addImagePreview(base64Data, filename) {
                     Download
                 </button>
             </div>
-            <img src="${base64Data}" style="max-width: 100%; max-height: 400px; border-radius: 5px; border: 1px solid var(--jarvis-border); cursor: zoom-in;" alt="${filename}" onclick="openImageModal(this.src, '${filename}')">
+            <img style="max-width: 100%; max-height: 400px; border-radius: 5px; border: 1px solid var(--jarvis-border); cursor: zoom-in;">
         </div>
     `;
     
+    const img = previewDiv.querySelector('img');
+    img.src = base64Data;
+    img.alt = filename;
+    img.addEventListener('click', () => openImageModal(base64Data, filename));
+    previewDiv.querySelector('.image-filename').textContent = `📷 ${filename}`;
+    previewDiv.querySelector('.image-download-btn')
+        .addEventListener('click', () => downloadImage(base64Data, filename));
+    
     chatMessages.appendChild(previewDiv);
     chatMessages.scrollTop = chatMessages.scrollHeight;
 }
@@ -1830,19 +1827,28 @@ function openImageModal(src, filename) {
     modal.innerHTML = `
         <div class="image-modal-content">
             <div class="modal-header">
-                <h3>${filename}</h3>
-                <button class="modal-close" onclick="this.closest('.modal-overlay').remove()">×</button>
+                <h3></h3>
+                <button class="modal-close">×</button>
             </div>
             <div class="image-modal-body">
-                <img src="${src}" style="max-width: 90vw; max-height: 80vh; object-fit: contain;" alt="${filename}">
+                <img style="max-width: 90vw; max-height: 80vh; object-fit: contain;">
             </div>
             <div class="modal-footer">
-                <button class="jarvis-btn" onclick="downloadImage('${src}', '${filename}')">Download</button>
-                <button class="jarvis-btn-secondary" onclick="this.closest('.modal-overlay').remove()">Close</button>
+                <button class="jarvis-btn">Download</button>
+                <button class="jarvis-btn-secondary">Close</button>
             </div>
         </div>
     `;
     
+    modal.querySelector('h3').textContent = filename;
+    const img = modal.querySelector('img');
+    img.src = src;
+    img.alt = filename;
+    modal.querySelector('.jarvis-btn').addEventListener('click', () => downloadImage(src, filename));
+    modal.querySelectorAll('.modal-close, .jarvis-btn-secondary').forEach(btn => {
+        btn.addEventListener('click', () => modal.remove());
+    });
+    
     document.body.appendChild(modal);
 }
 
@@ -2002,6 +2008,7 @@ function toggleVoiceInput() {
             voiceBtn.style.background = 'rgba(255, 51, 102, 0.2)';
             voiceBtn.style.borderColor = 'var(--jarvis-error)';
             showNotification('Listening...', 'info');
+            publishMiniState({ listening: 'listening' });
         };
         
         recognition.onresult = (event) => {
@@ -2016,6 +2023,9 @@ function toggleVoiceInput() {
             voiceBtn.classList.remove('listening');
             voiceBtn.style.background = '';
             voiceBtn.style.borderColor = '';
+            if (!state.isStreaming) {
+                publishMiniState({ listening: wakeWordEnabled ? 'wake_word' : 'idle' });
+            }
         };
         
         recognition.onerror = (event) => {
@@ -2112,6 +2122,9 @@ function toggleWakeWord() {
         console.log('⏸️ Wake word detection stopped');
     }
     
+    if (!state.isStreaming) {
+        publishMiniState({ listening: wakeWordEnabled ? 'wake_word' : 'idle' });
+    }
     saveUserPreferences();
 }
 
@@ -2520,24 +2533,12 @@ async function speakWithBackendTTS(text) {
         console.log(`📤 Sending TTS request: ${cleanText.length} chars`);
         
         // Call backend TTS API
-        const response = await fetch(`${API_BASE_URL}/api/tts`, {
-            method: 'POST',
-            headers: {
-                'Content-Type': 'application/json'
-            },
-            body: JSON.stringify({
-                text: cleanText,
-                language_code: state.language,
-                slow: false
-            })
+        const data = await backendCall('tts', {
+            text: cleanText,
+            language_code: state.language,
+            slow: false
         });
         
-        if (!response.ok) {
-            throw new Error('TTS API request failed');
-        }
-        
-        const data = await response.json();
-        
         if (data.success && data.audio) {
             console.log('✅ Received ultra-natural neural voice audio');
             
@@ -3167,7 +3168,7 @@ function showSmartSuggestions() {
     ];
     
     const suggestionsHTML = suggestions.map(s => `
-        <button class="suggestion-btn" onclick="useSuggestion('${s.text}')">
+        <button class="suggestion-btn" data-suggestion="${s.text}">
             <span class="suggestion-icon">${s.icon}</span>
             <span>${s.text}</span>
         </button>
@@ -3235,7 +3236,7 @@ window.jarvis = {
     }
 };
 
-// Make functions globally available for inline onclick handlers AND wake word system
+// Make functions available to the other modules (wake word, gestures, shortcuts)
 window.captureScreen = captureScreen;
 window.toggleClipboardMonitor = toggleClipboardMonitor;
 window.toggleWakeWord = toggleWakeWord;
@@ -3262,6 +3263,365 @@ window.fridayState = state;  // Expose state for language detection
 
 console.log('✅ Core functions exposed to window for wake word integration');
 
+// ============================================
+// MINI ASSISTANT WINDOW BRIDGE (desktop app only)
+// ============================================
+// The main window owns the conversation: it publishes what the mini window
+// shows and answers whatever is typed there.
+
+const miniState = { listening: 'idle', last_response: null, conversation_id: null };
+const tauriApi = window.__TAURI_IPC__ ? import('@tauri-apps/api') : null;
+
+function publishMiniState(patch) {
+    Object.assign(miniState, patch);
+    if (!tauriApi) return;
+    tauriApi
+        .then(({ invoke }) => invoke('publish_mini_state', { state: miniState }))
+        .catch(error => console.error('Mini window state not published:', error));
+}
+
+if (tauriApi) {
+    tauriApi.then(({ event }) => event.listen('mini://ask', async ({ payload }) => {
+        if (state.isStreaming) {
+            showNotification('FRIDAY is still answering - try again in a moment', 'info');
+            return;
+        }
+        addMessage('user', payload);
+        await streamChatResponse(payload);
+    }));
+}
+
+// ============================================
+// TRAY & BACKEND STATUS (desktop app only)
+// ============================================
+
+const BACKEND_STATUS_TEXT = {
+    starting: 'STARTING',
+    ready: 'ONLINE',
+    restarting: 'RESTARTING',
+    stopped: 'OFFLINE',
+    failed: 'FAILED'
+};
+
+function showBackendStatus(status) {
+    const statusDot = document.querySelector('.status-dot');
+    const statusText = document.querySelector('.status-text');
+    const color = {
+        ready: '',
+        starting: 'var(--jarvis-warning)',
+        restarting: 'var(--jarvis-warning)'
+    }[status] ?? 'var(--jarvis-error)';
+    
+    statusDot.classList.toggle('online', status === 'ready');
+    statusDot.style.background = color;
+    statusText.style.color = color;
+    statusText.textContent = BACKEND_STATUS_TEXT[status] || status.toUpperCase();
+}
+
+if (tauriApi) {
+    tauriApi.then(async ({ event, invoke }) => {
+        await event.listen('tray://new-conversation', () => startNewChat());
+        await event.listen('tray://mute-voice', ({ payload: muted }) => {
+            if (muted === voiceOutputEnabled) {
+                toggleVoiceOutput();
+            }
+        });
+        await event.listen('backend://status', ({ payload }) => {
+            showBackendStatus(payload);
+            if (payload === 'failed') {
+                showNotification('Backend failed to start - use Restart Backend in the tray', 'error');
+            } else if (payload === 'ready') {
+                loadConversations();
+            }
+        });
+        showBackendStatus(await invoke('backend_status'));
+    });
+}
+
+// ============================================
+// DATA ENCRYPTION (desktop app only)
+// ============================================
+// Nothing is opened and the backend does not start until the data key is
+// unlocked; when it is behind a passphrase, that is asked for here.
+
+function showUnlockPrompt(status) {
+    if (document.getElementById('unlock-modal')) return;
+    
+    const creating = status.state === 'uninitialized';
+    document.body.insertAdjacentHTML('beforeend', `
+        <div class="modal-overlay" id="unlock-modal">
+            <div class="modal-content">
+                <div class="modal-header">
+                    <h2>🔒 ${creating ? 'Protect Your Data' : 'Unlock FRIDAY'}</h2>
+                </div>
+                <div class="modal-body">
+                    <p style="color: var(--jarvis-text-dim); line-height: 1.6;">
+                        ${creating
+                            ? 'The OS credential store is unavailable, so your data will be encrypted with a passphrase. It cannot be recovered if you forget it.'
+                            : 'Enter your passphrase to decrypt your conversations and start FRIDAY.'}
+                    </p>
+                    <input type="password" id="unlock-passphrase" class="jarvis-input" placeholder="Passphrase">
+                    ${creating ? '<input type="password" id="unlock-confirm" class="jarvis-input" placeholder="Confirm passphrase">' : ''}
+                    <p id="unlock-error" style="color: var(--jarvis-error); margin-top: 0.75rem; min-height: 1.2em;"></p>
+                </div>
+                <div class="modal-footer">
+                    <button class="friday-btn" id="unlock-submit-btn">${creating ? 'Set Passphrase' : 'Unlock'}</button>
+                </div>
+            </div>
+        </div>
+    `);
+    
+    const passphrase = document.getElementById('unlock-passphrase');
+    const confirmation = document.getElementById('unlock-confirm');
+    const error = document.getElementById('unlock-error');
+    const submit = document.getElementById('unlock-submit-btn');
+    
+    const unlock = async () => {
+        if (confirmation && confirmation.value !== passphrase.value) {
+            error.textContent = 'Passphrases do not match';
+            return;
+        }
+        submit.disabled = true;
+        error.textContent = '';
+        try {
+            const result = await invoke('unlock_data', { passphrase: passphrase.value });
+            if (result.state === 'unlocked') {
+                dataUnlocked();
+            }
+        } catch (e) {
+            error.textContent = e;
+            passphrase.select();
+        } finally {
+            submit.disabled = false;
+        }
+    };
+    submit.addEventListener('click', unlock);
+    [passphrase, confirmation].forEach(input => input?.addEventListener('keydown', (e) => {
+        if (e.key === 'Enter') unlock();
+    }));
+    passphrase.focus();
+}
+
+function dataUnlocked() {
+    const modal = document.getElementById('unlock-modal');
+    if (!modal) return;
+    modal.remove();
+    loadConversations();
+    loadProjects();
+}
+
+// Switch between a passphrase and the OS credential store, or change the passphrase
+async function openDataProtection() {
+    const status = await invoke('data_lock_status');
+    const hasPassphrase = status.protection === 'passphrase';
+    
+    document.body.insertAdjacentHTML('beforeend', `
+        <div class="modal-overlay" id="protection-modal">
+            <div class="modal-content">
+                <div class="modal-header">
+                    <h2>🔑 Data Protection</h2>
+                    <button class="modal-close">×</button>
+                </div>
+                <div class="modal-body">
+                    <p style="color: var(--jarvis-text-dim); line-height: 1.6;">
+                        The data key is currently ${hasPassphrase ? 'protected by a passphrase' : 'kept in the OS credential store'}.
+                    </p>
+                    ${hasPassphrase ? '<input type="password" id="protection-current" class="jarvis-input" placeholder="Current passphrase">' : ''}
+                    <label style="display: flex; align-items: center; gap: 0.75rem; margin-top: 1rem; cursor: pointer;">
+                        <input type="checkbox" id="protection-use-os">
+                        <span>Keep the key in the OS credential store instead</span>
+                    </label>
+                    <div id="protection-new-fields">
+                        <input type="password" id="protection-new" class="jarvis-input" placeholder="New passphrase">
+                        <input type="password" id="protection-confirm" class="jarvis-input" placeholder="Confirm new passphrase">
+                    </div>
+                    <p id="protection-error" style="color: var(--jarvis-error); margin-top: 0.75rem; min-height: 1.2em;"></p>
+                </div>
+                <div class="modal-footer">
+                    <button class="friday-btn" id="protection-save-btn">Save</button>
+                    <button class="friday-btn-secondary" id="protection-cancel-btn">Cancel</button>
+                </div>
+            </div>
+        </div>
+    `);
+    
+    const modal = document.getElementById('protection-modal');
+    const useOs = document.getElementById('protection-use-os');
+    const newFields = document.getElementById('protection-new-fields');
+    const error = document.getElementById('protection-error');
+    
+    useOs.addEventListener('change', () => {
+        newFields.style.display = useOs.checked ? 'none' : '';
+    });
+    modal.querySelectorAll('.modal-close, #protection-cancel-btn').forEach(btn => {
+        btn.addEventListener('click', () => modal.remove());
+    });
+    document.getElementById('protection-save-btn').addEventListener('click', async () => {
+        const newPassphrase = document.getElementById('protection-new').value;
+        if (!useOs.checked && newPassphrase !== document.getElementById('protection-confirm').value) {
+            error.textContent = 'Passphrases do not match';
+            return;
+        }
+        try {
+            await invoke('set_data_passphrase', {
+                current: document.getElementById('protection-current')?.value ?? null,
+                new: useOs.checked ? null : newPassphrase
+            });
+            modal.remove();
+            showNotification('Data protection updated', 'success');
+        } catch (e) {
+            error.textContent = e;
+        }
+    });
+}
+
+if (tauriApi) {
+    tauriApi.then(async ({ event, invoke }) => {
+        await event.listen('encryption://status', ({ payload }) => {
+            if (payload.state === 'unlocked') {
+                dataUnlocked();
+            } else {
+                showUnlockPrompt(payload);
+            }
+        });
+        const status = await invoke('data_lock_status');
+        if (status.state !== 'unlocked') {
+            showUnlockPrompt(status);
+        }
+    });
+}
+
+// ============================================
+// SECOND LAUNCHES (desktop app only)
+// ============================================
+// `friday "question" --attach notes.txt` while FRIDAY is running lands here.
+// Rust only lets the webview read the attachment paths it was handed.
+
+if (tauriApi) {
+    tauriApi.then(({ event, invoke }) => event.listen('single-instance://launch', async ({ payload }) => {
+        const prompts = [];
+        for (const path of payload.attachments) {
+            const fileName = path.split(/[\\/]/).pop();
+            try {
+                const data = await invoke('read_launch_attachment', { path });
+                prompts.push(await uploadAttachment(fileName, data));
+            } catch (error) {
+                console.error('Error attaching launch file:', error);
+                showNotification(`Failed to attach ${fileName}: ${error.message || error}`, 'error');
+            }
+        }
+        
+        const message = [payload.message, ...prompts].filter(Boolean).join('\n\n');
+        if (!message) return;
+        
+        document.getElementById('chat-input').value = message;
+        if (state.isStreaming) {
+            showNotification('FRIDAY is still answering - press send when it is done', 'info');
+            return;
+        }
+        await handleSendMessage();
+    }));
+}
+
+// ============================================
+// NOTIFICATION ACTIONS (desktop app only)
+// ============================================
+// Rust tells the backend about snooze/dismiss; "Open" lands on the item.
+
+if (tauriApi) {
+    tauriApi.then(({ event }) => event.listen('notification://action', async ({ payload }) => {
+        if (payload.action !== 'open') return;
+        
+        if (payload.conversation_id) {
+            await loadConversation(payload.conversation_id);
+        } else {
+            showNotification(payload.body ? `${payload.title}: ${payload.body}` : payload.title, 'info', 5000);
+        }
+    }));
+}
+
+// ============================================
+// GLOBAL SHORTCUTS (desktop app only)
+// ============================================
+// Rust owns the hotkeys and shows/hides the window; these handlers do the
+// in-page half of each action.
+
+if (tauriApi) {
+    tauriApi.then(async ({ event }) => {
+        await event.listen('shortcut://toggle-window', () => {
+            document.getElementById('chat-input').focus();
+        });
+        await event.listen('shortcut://push-to-talk', ({ payload: talking }) => {
+            const listening = document.getElementById('voice-btn').classList.contains('listening');
+            if (talking !== listening) {
+                toggleVoiceInput();
+            }
+        });
+        await event.listen('shortcut://ask-clipboard', async ({ payload: text }) => {
+            if (!text || !text.trim()) {
+                showNotification('Clipboard has no text to ask about', 'warning');
+                return;
+            }
+            if (state.isStreaming) {
+                showNotification('FRIDAY is still answering - try again in a moment', 'info');
+                return;
+            }
+            addMessage('user', text);
+            await streamChatResponse(text);
+        });
+        await event.listen('shortcut://conflict', ({ payload }) => {
+            showNotification(`Shortcut ${payload.accelerator} is taken by another app: ${payload.error}`, 'warning', 5000);
+        });
+    });
+}
+
+// ============================================
+// DEEP LINKS (friday://, desktop app only)
+// ============================================
+// Rust validates the link and every route lands here; routines run in the
+// backend once confirmed and only report back.
+
+async function openDeepLink(link) {
+    switch (link.route) {
+        case 'ask':
+            // Any page can open a friday:// link, so the question waits for the user to send it
+            useSuggestion(link.q);
+            break;
+        case 'routine_run':
+            // Same reason: a page must not run routines without the user saying so
+            if (confirm(`A link wants to run the routine "${link.name}". Run it?`)) {
+                const { invoke } = await tauriApi;
+                await invoke('run_linked_routine', { name: link.name });
+            }
+            break;
+        case 'conversation':
+            await loadConversation(link.id);
+            break;
+        case 'project':
+            await loadProject(link.id);
+            break;
+    }
+}
+
+if (tauriApi) {
+    tauriApi.then(async ({ event, invoke }) => {
+        await event.listen('deep-link://open', ({ payload }) => openDeepLink(payload));
+        await event.listen('deep-link://routine', ({ payload }) => {
+            showNotification(
+                payload.success ? `▶️ Routine "${payload.name}" started` : `Routine "${payload.name}" failed: ${payload.error}`,
+                payload.success ? 'success' : 'error'
+            );
+        });
+        await event.listen('deep-link://error', ({ payload }) => showNotification(`Link ignored: ${payload}`, 'error'));
+
+        // Links that launched the app arrived before these listeners existed
+        const pending = await invoke('take_pending_deep_links');
+        for (const link of pending) {
+            await openDeepLink(link);
+        }
+    });
+}
+
 // ============================================
 // HAND TRACKING GESTURE EVENT LISTENERS
 // ============================================
@@ -0,0 +1,28 @@
+// ============================================
+// FRIDAY Markdown Rendering
+// Model output is rendered and sanitized in Rust (`render_markdown`), so
+// nothing a reply contains can run in the page. Only code highlighting
+// happens here.
+// ============================================
+
+import { invoke } from '@tauri-apps/api/tauri';
+import hljs from 'highlight.js';
+import 'highlight.js/styles/github-dark.css';
+
+// Streaming re-renders the same element per token; replies can overtake
+// each other, so only the latest render for an element is applied
+const latestRender = new WeakMap();
+
+export async function renderMarkdown(element, text) {
+    const render = (latestRender.get(element) || 0) + 1;
+    latestRender.set(element, render);
+
+    const html = await invoke('render_markdown', { text });
+    if (latestRender.get(element) !== render) {
+        return false;
+    }
+
+    element.innerHTML = html;
+    element.querySelectorAll('pre code').forEach(block => hljs.highlightElement(block));
+    return true;
+}
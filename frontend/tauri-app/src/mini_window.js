@@ -0,0 +1,67 @@
+// ============================================
+// FRIDAY Mini Assistant Window
+// Shows the listening state and last response published by the main window,
+// and forwards typed questions back to it over `mini://ask`.
+// ============================================
+
+import { invoke } from '@tauri-apps/api/tauri';
+import { listen } from '@tauri-apps/api/event';
+import { appWindow, WebviewWindow } from '@tauri-apps/api/window';
+
+const STATUS_LABELS = {
+    idle: 'Idle',
+    wake_word: 'Say "Hey FRIDAY"',
+    listening: 'Listening…',
+    thinking: 'Thinking…',
+    speaking: 'Speaking…'
+};
+
+const orb = document.getElementById('mini-orb');
+const statusText = document.getElementById('mini-status-text');
+const responseBox = document.getElementById('mini-response');
+const form = document.getElementById('mini-form');
+const input = document.getElementById('mini-text');
+
+function render(state) {
+    const listening = state.listening || 'idle';
+    orb.className = `mini-orb ${listening}`;
+    statusText.textContent = STATUS_LABELS[listening] || listening;
+
+    if (state.last_response) {
+        // Plain text on purpose: the main window renders the full markdown
+        responseBox.textContent = state.last_response;
+        responseBox.scrollTop = responseBox.scrollHeight;
+    }
+}
+
+form.addEventListener('submit', async (e) => {
+    e.preventDefault();
+    const text = input.value.trim();
+    if (!text) return;
+
+    try {
+        await invoke('mini_ask', { text });
+        input.value = '';
+        render({ listening: 'thinking' });
+    } catch (error) {
+        console.error('Mini ask failed:', error);
+    }
+});
+
+document.getElementById('mini-hide').addEventListener('click', () => appWindow.hide());
+
+document.getElementById('mini-open').addEventListener('click', async () => {
+    const main = WebviewWindow.getByLabel('main');
+    if (main) {
+        await main.show();
+        await main.setFocus();
+    }
+});
+
+document.addEventListener('keydown', (e) => {
+    if (e.key === 'Escape') appWindow.hide();
+});
+
+listen('mini://state', ({ payload }) => render(payload));
+invoke('get_mini_state').then(render).catch(error => console.error('Mini state unavailable:', error));
+input.focus();
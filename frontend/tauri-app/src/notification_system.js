@@ -60,15 +60,17 @@ class NotificationSystem {
                 <div style="display: flex; align-items: center; gap: 0.75rem;">
                     <div style="font-size: 1.5rem;">${icons[type]}</div>
                     <div style="flex: 1;">
-                        <div style="font-weight: 600;">${message}</div>
+                        <div class="notif-message" style="font-weight: 600;"></div>
                     </div>
                     <button 
-                        onclick="this.parentElement.parentElement.remove()" 
+                        class="notif-dismiss"
                         style="background: none; border: none; color: white; font-size: 1.2rem; cursor: pointer; opacity: 0.7; padding: 0 0.5rem;"
                         title="Dismiss"
                     >✕</button>
                 </div>
             `;
+            notif.querySelector('.notif-message').textContent = message;
+            notif.querySelector('.notif-dismiss').addEventListener('click', () => notif.remove());
             
             // Add progress bar
             if (duration > 0) {
@@ -163,9 +165,10 @@ class NotificationSystem {
                     border-radius: 50%;
                     animation: spin 0.8s linear infinite;
                 "></div>
-                <div style="font-weight: 600;">${message}</div>
+                <div class="notif-message" style="font-weight: 600;"></div>
             </div>
         `;
+        notif.querySelector('.notif-message').textContent = message;
         
         document.body.appendChild(notif);
         
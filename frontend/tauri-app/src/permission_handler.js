@@ -102,16 +102,16 @@ class PermissionHandler {
             <div class="modal-content" style="max-width: 600px;">
                 <div class="modal-header">
                     <h2>🎤 Microphone Permission</h2>
-                    <button class="modal-close" onclick="this.closest('.modal-overlay').remove()">×</button>
+                    <button class="modal-close" data-dismiss>×</button>
                 </div>
                 <div class="modal-body">
                     ${instructions}
                 </div>
                 <div class="modal-footer">
-                    <button class="friday-btn" onclick="window.permissionHandler.requestMicrophonePermission(); this.closest('.modal-overlay').remove();">
+                    <button class="friday-btn" data-retry>
                         🎤 Request Permission Again
                     </button>
-                    <button class="friday-btn-secondary" onclick="this.closest('.modal-overlay').remove();">
+                    <button class="friday-btn-secondary" data-dismiss>
                         Close
                     </button>
                 </div>
@@ -119,6 +119,14 @@ class PermissionHandler {
         `;
         
         document.body.appendChild(modal);
+        
+        modal.querySelectorAll('[data-dismiss]').forEach(btn => {
+            btn.addEventListener('click', () => modal.remove());
+        });
+        modal.querySelector('[data-retry]').addEventListener('click', () => {
+            this.requestMicrophonePermission();
+            modal.remove();
+        });
     }
 
     /**
@@ -3,6 +3,8 @@
 // ============================================
 // Plays audio as fast as possible while text streams in
 
+import { backendCall } from './backend_api.js';
+
 class StreamingTTSEngine {
     constructor() {
         this.audioQueue = [];
@@ -53,7 +55,6 @@ class StreamingTTSEngine {
      */
     async generateAndQueueAudio(text, chunkId) {
         try {
-            const API_BASE_URL = import.meta.env.VITE_API_URL || 'http://localhost:8000';
             const language = window.fridayState?.language || 'en-US';
             
             console.log(`🎤 Generating TTS for chunk ${chunkId}...`);
@@ -70,35 +71,19 @@ class StreamingTTSEngine {
             }
             
             // Prefer local TTS first if available
-            let response = await fetch(`${API_BASE_URL}/api/tts/local`, {
-                method: 'POST',
-                headers: { 'Content-Type': 'application/json' },
-                body: JSON.stringify({
-                    text: text,
-                    language_code: (language || 'en-US').split('-')[0]  // 'en-US' -> 'en'
-                })
+            let data = await backendCall('local_tts', {
+                text: text,
+                language_code: (language || 'en-US').split('-')[0]  // 'en-US' -> 'en'
             }).catch(() => null);
 
             // Fallback to cloud/edge TTS endpoint if local unavailable
-            if (!response || !response.ok) {
-                response = await fetch(`${API_BASE_URL}/api/chat/tts`, {
-                method: 'POST',
-                headers: { 'Content-Type': 'application/json' },
-                body: JSON.stringify({
+            if (!data?.success) {
+                data = await backendCall('tts', {
                     text: text,
-                    language: language
-                })
+                    language_code: language
                 });
             }
             
-            if (!response.ok) {
-                console.warn(`⚠️ TTS failed for chunk ${chunkId}, using fallback`);
-                this.useBrowserTTS(text, chunkId);
-                return;
-            }
-            
-            const data = await response.json();
-            
             if (data.success && data.audio) {
                 console.log(`✅ TTS ready for chunk ${chunkId}`);
 
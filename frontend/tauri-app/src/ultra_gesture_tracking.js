@@ -3,6 +3,8 @@
 // Works in low light, blur, bright - Professional grade
 // ============================================
 
+import { backendCall } from './backend_api.js';
+
 class UltraGestureTracker {
     constructor() {
         this.isActive = false;
@@ -105,9 +107,7 @@ class UltraGestureTracker {
 
             // Fetch multi-monitor virtual bounds
             try {
-                const API_BASE_URL = import.meta.env.VITE_API_URL || 'http://localhost:8000';
-                const resp = await fetch(`${API_BASE_URL}/api/display/monitors`);
-                const data = await resp.json();
+                const data = await backendCall('monitors');
                 if (data?.success) {
                     this.virtual = data.virtual; // {left, top, width, height}
                     this.monitors = data.monitors || [];
@@ -117,6 +117,9 @@ class UltraGestureTracker {
                 console.warn('Display info unavailable, using window size');
                 this.virtual = { left: 0, top: 0, width: window.innerWidth, height: window.innerHeight };
             }
+
+            // Desktop app: draw the cursor over other apps too
+            await this.showDesktopOverlay();
             
             // Load MediaPipe Hands with ENHANCED settings
             this.hands = new Hands({
@@ -434,6 +437,47 @@ class UltraGestureTracker {
         
         // Send cursor position to backend for OS-level control
         this.sendCursorUpdate(screenX, screenY); // fire-and-forget to keep loop hot
+        this.sendOverlayEvent('move');
+    }
+
+    // ============================================
+    // DESKTOP OVERLAY (Tauri only)
+    // Transparent click-through window per monitor, managed by Rust
+    // ============================================
+
+    async showDesktopOverlay() {
+        if (!window.__TAURI_IPC__) return;
+        try {
+            const { invoke } = await import('@tauri-apps/api/tauri');
+            this.overlayInvoke = invoke;
+            const monitors = await invoke('show_gesture_overlay');
+            console.log('🪟 Gesture overlay open on', monitors.length, 'monitor(s)');
+        } catch (error) {
+            this.overlayInvoke = null;
+            console.warn('Gesture overlay unavailable:', error);
+        }
+    }
+
+    hideDesktopOverlay() {
+        if (!this.overlayInvoke) return;
+        this.overlayInvoke('hide_gesture_overlay').catch(() => {});
+        this.overlayInvoke = null;
+    }
+
+    sendOverlayEvent(gesture, detail = null) {
+        if (!this.overlayInvoke) return;
+        // Hands missing is reported every frame; only the first one matters
+        if (gesture === 'lost' && this.overlayHandLost) return;
+        this.overlayHandLost = gesture === 'lost';
+
+        // Overlay geometry is in absolute desktop pixels, like /api/display/monitors
+        const event = {
+            gesture,
+            x: (this.virtual?.left || 0) + this.virtualCursor.x,
+            y: (this.virtual?.top || 0) + this.virtualCursor.y,
+            detail
+        };
+        this.overlayInvoke('gesture_overlay_event', { event }).catch(() => {});
     }
 
     renderHUD() {
@@ -568,15 +612,22 @@ class UltraGestureTracker {
         // PINCH: Thumb + Index finger
         const pinch = this.detectPinch(landmarks);
         if (pinch.active && !this.gestures.pinch.active) {
+            this.sendOverlayEvent('pinch');
             await this.executePinch(pinch);
         }
-        this.gestures.pinch = pinch;
         
         // GRAB: All fingers closed
         const grab = this.detectGrab(landmarks);
         if (grab.active && !this.gestures.grab.active) {
+            this.sendOverlayEvent('grab');
             await this.executeGrab(grab);
         }
+
+        const wasHeld = this.gestures.pinch.active || this.gestures.grab.active;
+        if (wasHeld && !pinch.active && !grab.active) {
+            this.sendOverlayEvent('release');
+        }
+        this.gestures.pinch = pinch;
         this.gestures.grab = grab;
         
         // SWIPE: Fast hand movement
@@ -713,19 +764,12 @@ class UltraGestureTracker {
             this.lastCursorUpdate = now;
             
             try {
-                const API_BASE_URL = import.meta.env.VITE_API_URL || 'http://localhost:8000';
                 const vw = (this.virtual?.width || window.innerWidth);
                 const vh = (this.virtual?.height || window.innerHeight);
-                fetch(`${API_BASE_URL}/api/gesture/cursor`, {
-                    method: 'POST',
-                    headers: { 'Content-Type': 'application/json' },
-                    body: JSON.stringify({
-                        x: x / vw,
-                        y: y / vh,
-                        smooth: true
-                    }),
-                    keepalive: true
-                });
+                backendCall('move_cursor', {
+                    x: x / vw,
+                    y: y / vh
+                }).catch(() => {});
             } catch (error) {
                 // Silent fail
             }
@@ -738,7 +782,6 @@ class UltraGestureTracker {
         // If snap overlay active, snap to highlighted cell and exit
         if (this.snapOverlayActive) {
             try {
-                const API_BASE_URL = import.meta.env.VITE_API_URL || 'http://localhost:8000';
                 const vw = (this.virtual?.width || window.innerWidth);
                 const vh = (this.virtual?.height || window.innerHeight);
                 const w = this.canvas?.width || window.innerWidth;
@@ -748,7 +791,7 @@ class UltraGestureTracker {
                 const cy = (this.virtualCursor.y / vh) * h;
                 const col = Math.min(cols - 1, Math.max(0, Math.floor((cx / w) * cols)));
                 const row = Math.min(rows - 1, Math.max(0, Math.floor((cy / h) * rows)));
-                await fetch(`${API_BASE_URL}/api/windows/snap_grid?cols=${cols}&rows=${rows}&col=${col}&row=${row}`, { method: 'POST' });
+                await backendCall('snap_grid', { cols, rows, col, row });
             } catch {}
             this.snapOverlayActive = false;
             return;
@@ -762,12 +805,7 @@ class UltraGestureTracker {
         
         // Execute click via backend
         try {
-            const API_BASE_URL = import.meta.env.VITE_API_URL || 'http://localhost:8000';
-            await fetch(`${API_BASE_URL}/api/gesture/click`, {
-                method: 'POST',
-                headers: { 'Content-Type': 'application/json' },
-                body: JSON.stringify({ button: 'left' })
-            });
+            await backendCall('click', { button: 'left' });
         } catch (error) {
             console.error('Click failed:', error);
         }
@@ -787,11 +825,7 @@ class UltraGestureTracker {
             
             // Start drag
             try {
-                const API_BASE_URL = import.meta.env.VITE_API_URL || 'http://localhost:8000';
-                await fetch(`${API_BASE_URL}/api/gesture/drag-start`, {
-                    method: 'POST',
-                    headers: { 'Content-Type': 'application/json' }
-                });
+                await backendCall('drag_start');
             } catch (error) {
                 console.error('Drag start failed:', error);
             }
@@ -800,32 +834,20 @@ class UltraGestureTracker {
 
     async executeSwipe(swipe) {
         console.log('👋 Swipe detected:', swipe.direction);
+        this.sendOverlayEvent('swipe', swipe.direction);
         
         // Execute swipe action
         try {
-            const API_BASE_URL = import.meta.env.VITE_API_URL || 'http://localhost:8000';
             // Map swipe to window snap for productivity
             const dir = swipe.direction;
             if (['left', 'right', 'up', 'down'].includes(dir)) {
-                await fetch(`${API_BASE_URL}/api/windows/snap`, {
-                    method: 'POST',
-                    headers: { 'Content-Type': 'application/json' },
-                    body: JSON.stringify({ direction: dir })
-                });
+                await backendCall('snap_window', { direction: dir });
             } else if (['up-left', 'up-right', 'down-left', 'down-right'].includes(dir)) {
                 const mapCorner = { 'up-left': 'tl', 'up-right': 'tr', 'down-left': 'bl', 'down-right': 'br' };
-                await fetch(`${API_BASE_URL}/api/windows/snap_corner`, {
-                    method: 'POST',
-                    headers: { 'Content-Type': 'application/json' },
-                    body: JSON.stringify({ corner: mapCorner[dir] })
-                });
+                await backendCall('snap_corner', { corner: mapCorner[dir] });
             } else {
                 // Fallback: send as gesture swipe to backend
-                await fetch(`${API_BASE_URL}/api/gesture/swipe`, {
-                    method: 'POST',
-                    headers: { 'Content-Type': 'application/json' },
-                    body: JSON.stringify({ direction: swipe.direction })
-                });
+                await backendCall('swipe', { direction: swipe.direction });
             }
 
             // App-specific bindings (simple defaults)
@@ -837,9 +859,7 @@ class UltraGestureTracker {
 
     async applyAppBindings(direction) {
         try {
-            const API_BASE_URL = import.meta.env.VITE_API_URL || 'http://localhost:8000';
-            const resp = await fetch(`${API_BASE_URL}/api/windows/active_window`);
-            const data = await resp.json();
+            const data = await backendCall('active_window');
             if (!data?.success) return;
             const exe = (data.exe || '').toLowerCase();
 
@@ -847,24 +867,24 @@ class UltraGestureTracker {
             const custom = this.loadAppBindings();
             const appCfg = custom[exe];
             if (appCfg && appCfg[direction]) {
-                await fetch(`${API_BASE_URL}/api/keyboard/send`, { method: 'POST', headers: { 'Content-Type': 'application/json' }, body: JSON.stringify({ combo: appCfg[direction] }) });
+                await backendCall('send_hotkey', { combo: appCfg[direction] });
                 return;
             }
 
             // VSCode: left/right = switch tabs, up = command palette, down = close tab
             if (exe.includes('code.exe')) {
-                if (direction === 'left') await fetch(`${API_BASE_URL}/api/keyboard/send`, { method: 'POST', headers: { 'Content-Type': 'application/json' }, body: JSON.stringify({ combo: 'ctrl+pageup' }) });
-                if (direction === 'right') await fetch(`${API_BASE_URL}/api/keyboard/send`, { method: 'POST', headers: { 'Content-Type': 'application/json' }, body: JSON.stringify({ combo: 'ctrl+pagedown' }) });
-                if (direction === 'up') await fetch(`${API_BASE_URL}/api/keyboard/send`, { method: 'POST', headers: { 'Content-Type': 'application/json' }, body: JSON.stringify({ combo: 'ctrl+shift+p' }) });
-                if (direction === 'down') await fetch(`${API_BASE_URL}/api/keyboard/send`, { method: 'POST', headers: { 'Content-Type': 'application/json' }, body: JSON.stringify({ combo: 'ctrl+w' }) });
+                if (direction === 'left') await backendCall('send_hotkey', { combo: 'ctrl+pageup' });
+                if (direction === 'right') await backendCall('send_hotkey', { combo: 'ctrl+pagedown' });
+                if (direction === 'up') await backendCall('send_hotkey', { combo: 'ctrl+shift+p' });
+                if (direction === 'down') await backendCall('send_hotkey', { combo: 'ctrl+w' });
             }
 
             // Chrome/Edge: left/right = switch tabs, up = new tab, down = close tab
             if (exe.includes('chrome') || exe.includes('msedge')) {
-                if (direction === 'left') await fetch(`${API_BASE_URL}/api/keyboard/send`, { method: 'POST', headers: { 'Content-Type': 'application/json' }, body: JSON.stringify({ combo: 'ctrl+shift+tab' }) });
-                if (direction === 'right') await fetch(`${API_BASE_URL}/api/keyboard/send`, { method: 'POST', headers: { 'Content-Type': 'application/json' }, body: JSON.stringify({ combo: 'ctrl+tab' }) });
-                if (direction === 'up') await fetch(`${API_BASE_URL}/api/keyboard/send`, { method: 'POST', headers: { 'Content-Type': 'application/json' }, body: JSON.stringify({ combo: 'ctrl+t' }) });
-                if (direction === 'down') await fetch(`${API_BASE_URL}/api/keyboard/send`, { method: 'POST', headers: { 'Content-Type': 'application/json' }, body: JSON.stringify({ combo: 'ctrl+w' }) });
+                if (direction === 'left') await backendCall('send_hotkey', { combo: 'ctrl+shift+tab' });
+                if (direction === 'right') await backendCall('send_hotkey', { combo: 'ctrl+tab' });
+                if (direction === 'up') await backendCall('send_hotkey', { combo: 'ctrl+t' });
+                if (direction === 'down') await backendCall('send_hotkey', { combo: 'ctrl+w' });
             }
         } catch {}
     }
@@ -881,9 +901,7 @@ class UltraGestureTracker {
     
     async configureAppBindingPrompt() {
         try {
-            const API_BASE_URL = import.meta.env.VITE_API_URL || 'http://localhost:8000';
-            const resp = await fetch(`${API_BASE_URL}/api/windows/active_window`);
-            const data = await resp.json();
+            const data = await backendCall('active_window');
             if (!data?.success) return;
             const exe = (data.exe || '').toLowerCase();
             const dir = prompt(`Bind gesture for ${exe}\nEnter direction (left/right/up/down/up-left/up-right/down-left/down-right):`);
@@ -900,9 +918,7 @@ class UltraGestureTracker {
 
     async updateAppProfile() {
         try {
-            const API_BASE_URL = import.meta.env.VITE_API_URL || 'http://localhost:8000';
-            const resp = await fetch(`${API_BASE_URL}/api/windows/active_window`);
-            const data = await resp.json();
+            const data = await backendCall('active_window');
             if (!data?.success) return;
             const exe = (data.exe || '').toLowerCase();
             // Simple profiles: adjust sensitivity per app
@@ -945,6 +961,7 @@ class UltraGestureTracker {
         if (this.cursorElement) {
             this.cursorElement.style.display = 'none';
         }
+        this.sendOverlayEvent('lost');
     }
 
     startProcessing() {
@@ -1000,6 +1017,7 @@ class UltraGestureTracker {
     stop() {
         this.isActive = false;
         this.hideVirtualCursor();
+        this.hideDesktopOverlay();
         
         if (this.videoElement && this.videoElement.srcObject) {
             this.videoElement.srcObject.getTracks().forEach(track => track.stop());
@@ -11,6 +11,13 @@ export default defineConfig({
     target: ['es2021', 'chrome100', 'safari13'],
     minify: !process.env.TAURI_DEBUG ? 'esbuild' : false,
     sourcemap: !!process.env.TAURI_DEBUG,
+    rollupOptions: {
+      input: {
+        main: 'index.html',
+        mini: 'mini.html',
+        overlay: 'overlay.html',
+      },
+    },
   },
 });
 